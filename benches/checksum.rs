@@ -0,0 +1,48 @@
+//! Batch check digit validation against validating each code on its own
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use gtin::util::{validate_batch, validate_gtin};
+
+/// Zero-padded codes of GTIN lengths, about a tenth of them with a correct check digit
+fn catalog(count: usize) -> (Vec<[u8; 14]>, Vec<u8>) {
+    let mut seed = 0x9e37_79b9u32;
+    let mut next = move || {
+        seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+        seed >> 16
+    };
+    let mut codes = Vec::with_capacity(count);
+    let mut lengths = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = [8u8, 12, 13, 14][next() as usize % 4];
+        let mut code = [0u8; 14];
+        for digit in &mut code[14 - usize::from(len)..] {
+            *digit = (next() % 10) as u8;
+        }
+        codes.push(code);
+        lengths.push(len);
+    }
+    (codes, lengths)
+}
+
+fn checksum(c: &mut Criterion) {
+    let (codes, lengths) = catalog(100_000);
+    let mut group = c.benchmark_group("checksum");
+    group.throughput(Throughput::Elements(codes.len() as u64));
+
+    group.bench_function("per_code", |b| {
+        b.iter(|| {
+            codes
+                .iter()
+                .zip(&lengths)
+                .filter(|(code, &len)| validate_gtin(&code[14 - usize::from(len)..]))
+                .count()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| validate_batch(black_box(&codes), black_box(&lengths)).count_valid())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, checksum);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! AIM symbology identifiers (ISO/IEC 15424), the `]Cm` prefix scanners can be set to
+//! send ahead of the data to say which kind of symbol was read
+
+use crate::GtinKind;
+
+/// Identifiers of the symbols that carry GTINs, with the AI `(01)` for GS1 carriers
+const GS1_CARRIERS: [&str; 4] = ["]C1", "]e0", "]d2", "]Q3"];
+
+/// Split a leading symbology identifier from scanned data
+pub fn strip_identifier(value: &str) -> (Option<&str>, &str) {
+    let trimmed = value.trim_start();
+    let mut chars = trimmed.chars();
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some(']'), Some(code), Some(modifier))
+            if code.is_ascii_alphabetic() && modifier.is_ascii_alphanumeric() =>
+        {
+            (Some(&trimmed[..3]), &trimmed[3..])
+        }
+        _ => (None, value),
+    }
+}
+
+/// `strip_identifier` for raw bytes, such as a scanner SDK's buffer
+pub fn strip_identifier_bytes(value: &[u8]) -> (Option<&str>, &[u8]) {
+    let trimmed = value.trim_ascii_start();
+    match trimmed {
+        [b']', code, modifier, data @ ..]
+            if code.is_ascii_alphabetic() && modifier.is_ascii_alphanumeric() =>
+        {
+            match core::str::from_utf8(&trimmed[..3]) {
+                Ok(identifier) => (Some(identifier), data),
+                Err(_) => (None, value),
+            }
+        }
+        _ => (None, value),
+    }
+}
+
+/// Whether the identifier names a GS1 data carrier, whose data starts with an AI
+pub fn is_gs1_carrier(identifier: &str) -> bool {
+    GS1_CARRIERS.contains(&identifier)
+}
+
+/// The kind an 8-digit code must be, when the identifier settles the UPC-E/EAN-8 question
+pub fn eight_digit_kind(identifier: &str) -> Option<GtinKind> {
+    match identifier {
+        "]E0" => Some(GtinKind::UpcE), // EAN-13, UPC-A or UPC-E; only UPC-E has 8 digits
+        "]E4" => Some(GtinKind::Ean8),
+        _ => None,
+    }
+}
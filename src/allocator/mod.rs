@@ -0,0 +1,202 @@
+//! Sequential issuance of GTINs under a GS1 Company Prefix, the way brand owners number
+//! their items: the item references after the prefix are handed out in order and the check
+//! digit is computed for each. `GtinRange` walks the whole block of a prefix the same way.
+
+use std::fmt::{Display, Formatter};
+
+use crate::util::calculate_checksum_digit;
+use crate::{GtinKind, GTIN};
+
+/// The shortest GS1 Company Prefix
+const MIN_PREFIX_LEN: usize = 4;
+
+/// How many codes of `kind` a prefix of `prefix_len` leading digits leaves room for: the
+/// item reference fills the digits between the prefix and the check digit. UPC-E is
+/// counted in its UPC-A expansion, and a prefix with no room left gives 1, the code itself.
+pub fn capacity(prefix_len: usize, kind: GtinKind) -> u64 {
+    let kind = match kind {
+        GtinKind::UpcE => GtinKind::UpcA,
+        kind => kind,
+    };
+    let item_reference_len = (kind.digit_count() - 1).saturating_sub(prefix_len);
+    10u64.pow(item_reference_len as u32)
+}
+
+/// Why an allocator could not be set up or issue a code
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AllocationError {
+    UnsupportedKind(GtinKind), // only GTIN-13 and GTIN-12 are allocated from a prefix
+    InvalidPrefix(String),     // not digits, or no room for an item reference
+    OutsidePrefix(GTIN),       // resuming from a code of another prefix or kind
+    Exhausted,
+}
+
+impl Display for AllocationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AllocationError::UnsupportedKind(kind) => {
+                write!(f, "Cannot allocate {:?} from a company prefix", kind)
+            }
+            AllocationError::InvalidPrefix(prefix) => {
+                write!(f, "Invalid company prefix: {:?}", prefix)
+            }
+            AllocationError::OutsidePrefix(gtin) => {
+                write!(f, "{} is not under the company prefix", gtin)
+            }
+            AllocationError::Exhausted => write!(f, "Every item reference has been issued"),
+        }
+    }
+}
+
+impl std::error::Error for AllocationError {}
+
+/// Issues the GTIN-13s or GTIN-12s (UPC-A) of a company prefix in order of item reference
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GtinAllocator {
+    kind: GtinKind,
+    prefix: Vec<u8>,
+    next: u64,     // the next item reference to issue
+    capacity: u64, // the number of item references the prefix leaves room for
+}
+
+impl GtinAllocator {
+    /// An allocator for `kind`, `GtinKind::Ean13` or `GtinKind::UpcA`, starting at item
+    /// reference zero. The prefix is the leading digits of the codes, so a U.P.C. Company
+    /// Prefix is given without the zero that makes it a GS1 Company Prefix for GTIN-12.
+    pub fn new(prefix: &str, kind: GtinKind) -> Result<Self, AllocationError> {
+        if !matches!(kind, GtinKind::Ean13 | GtinKind::UpcA) {
+            return Err(AllocationError::UnsupportedKind(kind));
+        }
+        let invalid = || AllocationError::InvalidPrefix(prefix.to_string());
+        if !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let capacity = capacity(prefix.len(), kind);
+        if prefix.len() < MIN_PREFIX_LEN || capacity == 1 {
+            return Err(invalid());
+        }
+        Ok(GtinAllocator {
+            kind,
+            prefix: prefix.bytes().map(|b| b - b'0').collect(),
+            next: 0,
+            capacity,
+        })
+    }
+
+    /// Continue after a code issued earlier, e.g. the last one recorded in a catalog
+    pub fn resume_after(mut self, last: &GTIN) -> Result<Self, AllocationError> {
+        let digits = last.digits();
+        if last.kind() != self.kind || !digits.starts_with(&self.prefix) {
+            return Err(AllocationError::OutsidePrefix(*last));
+        }
+        let item_reference = digits[self.prefix.len()..digits.len() - 1]
+            .iter()
+            .fold(0, |value, &digit| value * 10 + u64::from(digit));
+        self.next = item_reference + 1;
+        Ok(self)
+    }
+
+    pub fn kind(&self) -> GtinKind {
+        self.kind
+    }
+
+    /// How many codes the prefix allows in all
+    pub fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    /// How many codes are left to issue
+    pub fn remaining(&self) -> u64 {
+        self.capacity - self.next
+    }
+
+    /// Issue the next code
+    pub fn issue(&mut self) -> Result<GTIN, AllocationError> {
+        if self.next == self.capacity {
+            return Err(AllocationError::Exhausted);
+        }
+        let mut digits = [0u8; 13];
+        let len = self.kind.digit_count();
+        digits[..self.prefix.len()].copy_from_slice(&self.prefix);
+        let mut item_reference = self.next;
+        for digit in digits[self.prefix.len()..len - 1].iter_mut().rev() {
+            *digit = (item_reference % 10) as u8;
+            item_reference /= 10;
+        }
+        digits[len - 1] = calculate_checksum_digit(&digits[..len - 1]);
+        self.next += 1;
+
+        let gtin = match self.kind {
+            GtinKind::UpcA => GTIN::UpcA(digits[..12].try_into().expect("12 digits")),
+            _ => GTIN::Ean13(digits),
+        };
+        Ok(gtin)
+    }
+}
+
+impl GTIN {
+    /// How many item references come after this code's under its first `prefix_len`
+    /// digits, e.g. a GS1 Company Prefix, which cannot be told from the code itself. UPC-E
+    /// is counted in its UPC-A expansion.
+    pub fn remaining_capacity_after(&self, prefix_len: usize) -> u64 {
+        let upca;
+        let digits = match self.kind() {
+            GtinKind::UpcE => {
+                upca = self.upce_as_upca();
+                &upca[..]
+            }
+            _ => self.digits(),
+        };
+        let item_reference = digits
+            .get(prefix_len..digits.len() - 1)
+            .unwrap_or_default()
+            .iter()
+            .fold(0, |value, &digit| value * 10 + u64::from(digit));
+        capacity(prefix_len, self.kind()) - 1 - item_reference
+    }
+}
+
+/// Every GTIN of a company prefix in order of item reference, generated as it is iterated
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GtinRange {
+    allocator: GtinAllocator,
+}
+
+impl GtinRange {
+    /// The block of `prefix`, with the kinds and prefixes `GtinAllocator::new` accepts
+    pub fn for_prefix(prefix: &str, kind: GtinKind) -> Result<Self, AllocationError> {
+        GtinAllocator::new(prefix, kind).map(|allocator| GtinRange { allocator })
+    }
+
+    /// How many codes are left to iterate, which can exceed `usize` on 32-bit targets
+    pub fn remaining(&self) -> u64 {
+        self.allocator.remaining()
+    }
+}
+
+impl Iterator for GtinRange {
+    type Item = GTIN;
+
+    fn next(&mut self) -> Option<GTIN> {
+        self.allocator.issue().ok()
+    }
+
+    /// Skips by item reference rather than generating the codes in between
+    fn nth(&mut self, n: usize) -> Option<GTIN> {
+        let skip = u64::try_from(n).unwrap_or(u64::MAX).min(self.remaining());
+        self.allocator.next += skip;
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl core::iter::FusedIterator for GtinRange {}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,120 @@
+use super::{capacity, AllocationError, GtinAllocator, GtinRange};
+use crate::{GtinKind, GTIN};
+
+#[test]
+fn issue_in_order() {
+    let mut allocator = GtinAllocator::new("4006381", GtinKind::Ean13).unwrap();
+    assert_eq!(allocator.capacity(), 100_000);
+    assert_eq!(allocator.remaining(), 100_000);
+
+    let issued: Vec<GTIN> = (0..3).map(|_| allocator.issue().unwrap()).collect();
+    assert_eq!(
+        issued,
+        vec![
+            GTIN::try_from("4006381000000").unwrap(),
+            GTIN::try_from("4006381000017").unwrap(),
+            GTIN::try_from("4006381000024").unwrap(),
+        ]
+    );
+    assert_eq!(allocator.remaining(), 99_997);
+
+    let mut allocator = GtinAllocator::new("071720", GtinKind::UpcA).unwrap();
+    assert_eq!(allocator.capacity(), 100_000);
+    let gtin = allocator.issue().unwrap();
+    assert_eq!(gtin, GTIN::UpcA([0, 7, 1, 7, 2, 0, 0, 0, 0, 0, 0, 7]));
+    assert!(GTIN::try_from("071720000007").is_ok());
+}
+
+#[test]
+fn resume_and_exhaust() {
+    let last = GTIN::try_from("071720539774").unwrap();
+    let mut allocator = GtinAllocator::new("071720", GtinKind::UpcA)
+        .unwrap()
+        .resume_after(&last)
+        .unwrap();
+    assert_eq!(allocator.remaining(), 100_000 - 53978);
+    assert_eq!(allocator.issue().ok(), GTIN::try_from("071720539781").ok());
+
+    let mut allocator = GtinAllocator::new("40063813339", GtinKind::Ean13).unwrap();
+    assert_eq!(allocator.capacity(), 10);
+    let last = GTIN::try_from("4006381333931").unwrap();
+    let mut allocator_after = allocator.clone().resume_after(&last).unwrap();
+    assert_eq!(allocator_after.remaining(), 6);
+    for _ in 0..10 {
+        allocator.issue().unwrap();
+    }
+    assert_eq!(allocator.remaining(), 0);
+    assert_eq!(allocator.issue(), Err(AllocationError::Exhausted));
+    assert!(allocator_after.issue().is_ok());
+}
+
+#[test]
+fn allocation_errors() {
+    assert_eq!(
+        GtinAllocator::new("4006381", GtinKind::Ean8),
+        Err(AllocationError::UnsupportedKind(GtinKind::Ean8))
+    );
+    for prefix in ["400", "4006-381", "4006381333931", "071720539774"] {
+        assert_eq!(
+            GtinAllocator::new(prefix, GtinKind::Ean13)
+                .and_then(|_| GtinAllocator::new(prefix, GtinKind::UpcA)),
+            Err(AllocationError::InvalidPrefix(prefix.to_string())),
+            "{}",
+            prefix
+        );
+    }
+
+    let allocator = GtinAllocator::new("4006381", GtinKind::Ean13).unwrap();
+    for other in ["8595701530526", "071720539774"] {
+        let other = GTIN::try_from(other).unwrap();
+        assert_eq!(
+            allocator.clone().resume_after(&other),
+            Err(AllocationError::OutsidePrefix(other))
+        );
+    }
+    assert_eq!(
+        AllocationError::InvalidPrefix("400".to_string()).to_string(),
+        "Invalid company prefix: \"400\""
+    );
+}
+
+#[test]
+fn range_for_prefix() {
+    let range = GtinRange::for_prefix("40063813339", GtinKind::Ean13).unwrap();
+    assert_eq!(range.size_hint(), (10, Some(10)));
+    let block: Vec<GTIN> = range.collect();
+    assert_eq!(block.len(), 10);
+    assert_eq!(block[3], GTIN::try_from("4006381333931").unwrap());
+    for (gtin, next) in block.iter().zip(&block[1..]) {
+        assert_eq!(gtin.successor(11).as_ref(), Some(next));
+    }
+
+    // Lazily over a large block
+    let mut range = GtinRange::for_prefix("0717", GtinKind::UpcA).unwrap();
+    assert_eq!(range.remaining(), 10_000_000);
+    assert_eq!(range.nth(53977), GTIN::try_from("071700539770").ok());
+    assert_eq!(range.remaining(), 10_000_000 - 53978);
+}
+
+#[test]
+fn prefix_capacity() {
+    assert_eq!(capacity(7, GtinKind::Ean13), 100_000);
+    assert_eq!(capacity(6, GtinKind::UpcA), 100_000);
+    assert_eq!(capacity(6, GtinKind::UpcE), 100_000);
+    assert_eq!(capacity(8, GtinKind::Gtin14), 100_000);
+    assert_eq!(capacity(12, GtinKind::Ean13), 1);
+    assert_eq!(capacity(20, GtinKind::Ean8), 1);
+
+    let gtin = GTIN::try_from("4006381333931").unwrap();
+    assert_eq!(gtin.remaining_capacity_after(7), 100_000 - 33394);
+    assert_eq!(gtin.remaining_capacity_after(11), 6);
+    assert_eq!(gtin.remaining_capacity_after(12), 0);
+    assert_eq!(gtin.remaining_capacity_after(13), 0);
+
+    // UPC-E 10000052 expands to UPC-A 100000000052
+    let gtin = GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]);
+    assert_eq!(gtin.remaining_capacity_after(6), 100_000 - 6);
+
+    let allocator = GtinAllocator::new("4006381", GtinKind::Ean13).unwrap();
+    assert_eq!(allocator.capacity(), capacity(7, GtinKind::Ean13));
+}
@@ -0,0 +1,126 @@
+//! Arrow columns of GTINs, for analytics pipelines on product data (and Parquet files,
+//! which the parquet crate reads and writes as Arrow arrays). A column is either
+//! FixedSizeBinary(14), holding the ASCII digits of each `canonical_key`, or dictionary
+//! encoded Utf8 holding the digits as written, which keeps the kind and stores each
+//! distinct GTIN once.
+
+use arrow_array::cast::AsArray;
+use arrow_array::types::Int32Type;
+use arrow_array::{Array, BooleanArray, DictionaryArray, FixedSizeBinaryArray};
+use arrow_schema::{ArrowError, DataType};
+
+use crate::util::validate_batch;
+use crate::{GtinError, GTIN};
+
+/// Width of the FixedSizeBinary form
+const WIDTH: usize = 14;
+
+/// The ASCII digits of the canonical key, UPC-E expanded
+fn canonical_ascii(gtin: &GTIN) -> [u8; WIDTH] {
+    let mut key = gtin.canonical_key();
+    let mut ascii = [b'0'; WIDTH];
+    for byte in ascii.iter_mut().rev() {
+        *byte = b'0' + (key % 10) as u8;
+        key /= 10;
+    }
+    ascii
+}
+
+/// A FixedSizeBinary(14) array of canonical keys. Reading it back gives the 14-digit
+/// form of each GTIN; use the dictionary form to keep kinds.
+pub fn to_fixed_size_binary(gtins: &[GTIN]) -> FixedSizeBinaryArray {
+    let values = gtins.iter().map(|gtin| Some(canonical_ascii(gtin)));
+    FixedSizeBinaryArray::try_from_sparse_iter_with_size(values, WIDTH as i32)
+        .expect("values are 14 bytes")
+}
+
+/// A dictionary encoded Utf8 array of the digits as written
+pub fn to_dictionary(gtins: &[GTIN]) -> DictionaryArray<Int32Type> {
+    let digits: Vec<String> = gtins.iter().map(|&gtin| String::from(gtin)).collect();
+    digits.iter().map(String::as_str).collect()
+}
+
+/// Each row parsed, None for nulls. Dictionaries have their values parsed once.
+fn parse_rows(array: &dyn Array) -> Result<Vec<Option<Result<GTIN, GtinError>>>, ArrowError> {
+    let rows = match array.data_type() {
+        DataType::FixedSizeBinary(14) => array
+            .as_fixed_size_binary()
+            .iter()
+            .map(|value| value.map(GTIN::try_from))
+            .collect(),
+        DataType::Utf8 => array
+            .as_string::<i32>()
+            .iter()
+            .map(|value| value.map(GTIN::try_from))
+            .collect(),
+        DataType::LargeUtf8 => array
+            .as_string::<i64>()
+            .iter()
+            .map(|value| value.map(GTIN::try_from))
+            .collect(),
+        DataType::Dictionary(key, _) if **key == DataType::Int32 => {
+            let dictionary = array.as_dictionary::<Int32Type>();
+            let values = parse_rows(dictionary.values())?;
+            dictionary
+                .keys()
+                .iter()
+                .map(|key| key.and_then(|key| values[key as usize].clone()))
+                .collect()
+        }
+        other => {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "Unsupported GTIN column type: {}",
+                other
+            )))
+        }
+    };
+    Ok(rows)
+}
+
+/// Read a FixedSizeBinary(14), Utf8, LargeUtf8 or Int32-keyed dictionary column, with
+/// None for nulls. Fails on the first invalid GTIN, naming its row.
+pub fn from_array(array: &dyn Array) -> Result<Vec<Option<GTIN>>, ArrowError> {
+    parse_rows(array)?
+        .into_iter()
+        .enumerate()
+        .map(|(row, value)| {
+            value
+                .transpose()
+                .map_err(|e| ArrowError::InvalidArgumentError(format!("Row {}: {}", row, e)))
+        })
+        .collect()
+}
+
+/// Whether each row of a column `from_array` reads is a valid GTIN, null for nulls. The
+/// check digits of a FixedSizeBinary(14) column are validated as one batch, without
+/// parsing rows; a row of anything but ASCII digits is invalid.
+pub fn validate(array: &dyn Array) -> Result<BooleanArray, ArrowError> {
+    if array.data_type() != &DataType::FixedSizeBinary(WIDTH as i32) {
+        let rows = parse_rows(array)?;
+        return Ok(rows.into_iter().map(|row| row.map(|r| r.is_ok())).collect());
+    }
+
+    let array = array.as_fixed_size_binary();
+    let mut codes = Vec::with_capacity(array.len());
+    let mut lengths = Vec::with_capacity(array.len());
+    for i in 0..array.len() {
+        let value = array.value(i);
+        let mut code = [0; WIDTH];
+        for (digit, &byte) in code.iter_mut().zip(value) {
+            *digit = byte.wrapping_sub(b'0');
+        }
+        codes.push(code);
+        // A length over 14 is never valid
+        let digits = value.iter().all(u8::is_ascii_digit);
+        lengths.push(if digits { WIDTH as u8 } else { u8::MAX });
+    }
+    let mask = validate_batch(&codes, &lengths);
+    Ok(mask
+        .iter()
+        .enumerate()
+        .map(|(i, valid)| array.is_valid(i).then_some(valid))
+        .collect())
+}
+
+#[cfg(test)]
+pub mod tests;
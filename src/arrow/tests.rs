@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use arrow_array::types::Int32Type;
+use arrow_array::{
+    Array, BooleanArray, DictionaryArray, FixedSizeBinaryArray, Int32Array, LargeStringArray,
+    StringArray,
+};
+
+use super::{from_array, to_dictionary, to_fixed_size_binary, validate};
+use crate::GTIN;
+
+fn gtins() -> Vec<GTIN> {
+    vec![
+        GTIN::try_from("071720539774").unwrap(),
+        GTIN::try_from("4006381333931").unwrap(),
+        GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]),
+        GTIN::try_from("071720539774").unwrap(),
+        GTIN::try_from("10071720539771").unwrap(),
+    ]
+}
+
+#[test]
+fn fixed_size_binary_round_trip() {
+    let gtins = gtins();
+    let array = to_fixed_size_binary(&gtins);
+    assert_eq!(array.value_length(), 14);
+    assert_eq!(array.value(0), b"00071720539774");
+    assert_eq!(array.value(2), b"00100000000052");
+
+    let read = from_array(&array).unwrap();
+    for (gtin, read) in gtins.iter().zip(read) {
+        assert!(read.unwrap().same_product(gtin));
+    }
+    assert_eq!(to_fixed_size_binary(&[]).len(), 0);
+}
+
+#[test]
+fn dictionary_round_trip() {
+    let gtins = gtins();
+    let array = to_dictionary(&gtins);
+    assert_eq!(array.len(), 5);
+    assert_eq!(array.values().len(), 4); // the repeated UPC-A is stored once
+
+    let read: Vec<GTIN> = from_array(&array).unwrap().into_iter().flatten().collect();
+    assert_eq!(read, gtins);
+}
+
+#[test]
+fn from_string_arrays() {
+    let array = StringArray::from(vec![Some("071720539774"), None, Some("0 71720 53977 4")]);
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(
+        from_array(&array).unwrap(),
+        vec![Some(gtin), None, Some(gtin)]
+    );
+
+    let array = LargeStringArray::from(vec!["071720539774", "071720539775"]);
+    let error = from_array(&array).unwrap_err();
+    assert!(error.to_string().contains("Row 1: "), "{}", error);
+
+    let error = from_array(&Int32Array::from(vec![1])).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Unsupported GTIN column type: Int32"));
+}
+
+#[test]
+fn validate_columns() {
+    let values = vec![
+        Some(&b"00071720539774"[..]),
+        None,
+        Some(b"00071720539775"),
+        Some(b"0007172053977x"),
+        Some(b"10071720539771"),
+        Some(b"00000000000000"),
+    ];
+    let array =
+        FixedSizeBinaryArray::try_from_sparse_iter_with_size(values.into_iter(), 14).unwrap();
+    let expected = BooleanArray::from(vec![
+        Some(true),
+        None,
+        Some(false),
+        Some(false),
+        Some(true),
+        Some(GTIN::try_from(&b"00000000000000"[..]).is_ok()),
+    ]);
+    assert_eq!(validate(&array).unwrap(), expected);
+
+    // The batch kernel agrees with parsing each row
+    let parsed: BooleanArray = from_rows(&array);
+    assert_eq!(validate(&array).unwrap(), parsed);
+
+    let keys = Int32Array::from(vec![Some(0), Some(1), None, Some(0)]);
+    let values = Arc::new(StringArray::from(vec!["071720539774", "N/A"]));
+    let array = DictionaryArray::<Int32Type>::try_new(keys, values).unwrap();
+    assert_eq!(
+        validate(&array).unwrap(),
+        BooleanArray::from(vec![Some(true), Some(false), None, Some(true)])
+    );
+}
+
+fn from_rows(array: &FixedSizeBinaryArray) -> BooleanArray {
+    array
+        .iter()
+        .map(|value| value.map(|value| GTIN::try_from(value).is_ok()))
+        .collect()
+}
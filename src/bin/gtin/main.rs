@@ -0,0 +1,539 @@
+//! Command-line front end for ops use: `gtin audit` validates GTIN feeds, one code per
+//! line, and reports the problems as JSON. `batch` gives a result for every line of files
+//! or stdin, as JSON or CSV, for data-quality pipelines. `validate`, `convert`, `info` and `check-digit`
+//! work on codes given as arguments, or read one per line from stdin when there are none.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use std::{env, fs, io, thread};
+
+use gtin::util::calculate_checksum_digit;
+use gtin::{GtinInfo, GtinKind, Pipeline, GTIN};
+use serde::Serialize;
+
+const USAGE: &str = "Usage:
+  gtin audit [--config PIPELINE.toml] FILE...
+  gtin audit [--config PIPELINE.toml] --watch DIR [--reports DIR] [--interval SECONDS]
+  gtin batch [--config PIPELINE.toml] [--format json|csv] [FILE...]
+  gtin validate [CODE...]
+  gtin convert --to ean13|gtin14|upca|upce [CODE...]
+  gtin info [CODE...]
+  gtin check-digit [PAYLOAD...]
+
+Without --watch, audit prints one JSON report per file and exits with status 1 if any
+line is invalid. With --watch, it polls DIR for new files and writes a report for each
+to the reports directory (DIR/reports by default).
+
+batch reads FILEs, or stdin when there are none or for -, and writes a row for every
+line with a summary, as one JSON document (the default) or as CSV with the summary on
+stderr. It exits with status 1 if any line is invalid.
+
+validate, convert, info and check-digit read codes from stdin when none are given, print
+one line per code, and exit with status 1 if any code was rejected. info prints JSON.";
+
+/// Outcome of auditing one file
+#[derive(Debug, PartialEq, Serialize)]
+struct Report {
+    file: String,
+    lines: usize,
+    valid: usize,
+    problems: Vec<Problem>,
+}
+
+/// What the watch mode reports for a file it could not audit, e.g. one that is not UTF-8
+#[derive(Debug, PartialEq, Serialize)]
+struct Failure {
+    file: String,
+    error: String,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+struct Problem {
+    line: usize, // 1-based
+    input: String,
+    error: String,
+}
+
+/// Result of one line in batch mode
+#[derive(Debug, PartialEq, Serialize)]
+struct Row {
+    file: String,
+    line: usize, // 1-based
+    input: String,
+    valid: bool,
+    kind: Option<GtinKind>,
+    digits: Option<String>, // in the pipeline's canonical form
+    error: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Default, Serialize)]
+struct Summary {
+    lines: usize,
+    valid: usize,
+    invalid: usize,
+    kinds: BTreeMap<GtinKind, usize>,
+}
+
+impl Summary {
+    fn of(rows: &[Row]) -> Self {
+        let mut summary = Summary::default();
+        for row in rows {
+            summary.lines += 1;
+            match row.kind {
+                Some(kind) => {
+                    summary.valid += 1;
+                    *summary.kinds.entry(kind).or_default() += 1;
+                }
+                None => summary.invalid += 1,
+            }
+        }
+        summary
+    }
+}
+
+/// What batch mode writes as JSON
+#[derive(Debug, Serialize)]
+struct BatchReport<'a> {
+    rows: &'a [Row],
+    summary: &'a Summary,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Format {
+    Json,
+    Csv,
+}
+
+#[derive(Debug, PartialEq)]
+struct BatchOptions {
+    config: Option<PathBuf>,
+    format: Format,
+    files: Vec<PathBuf>,
+}
+
+#[derive(Debug, PartialEq)]
+struct AuditOptions {
+    config: Option<PathBuf>,
+    watch: Option<PathBuf>,
+    reports: Option<PathBuf>,
+    interval: Duration,
+    files: Vec<PathBuf>,
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("gtin: {}\n\n{}", message, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    match args.first().map(String::as_str) {
+        Some("audit") => audit(parse_audit_options(&args[1..])?),
+        Some("batch") => batch(parse_batch_options(&args[1..])?),
+        Some("validate") => each_code(&args[1..], validate),
+        Some("convert") => match args.get(1..3) {
+            Some([flag, target]) if flag == "--to" => {
+                let kind = parse_target(target)?;
+                each_code(&args[3..], |code| convert(code, kind))
+            }
+            _ => Err("convert requires --to".to_string()),
+        },
+        Some("info") => each_code(&args[1..], info),
+        Some("check-digit") => each_code(&args[1..], |payload| {
+            check_digit(payload).map(|digit| digit.to_string())
+        }),
+        Some(command) => Err(format!("unknown command: {}", command)),
+        None => Err("missing command".to_string()),
+    }
+}
+
+/// Run `command` on each code, or on each line of stdin when there are none, printing
+/// what it returns and reporting what it rejects on stderr
+fn each_code<F>(codes: &[String], command: F) -> Result<ExitCode, String>
+where
+    F: Fn(&str) -> Result<String, String>,
+{
+    if let Some(option) = codes.iter().find(|code| code.starts_with("--")) {
+        return Err(format!("unknown option: {}", option));
+    }
+
+    let mut clean = true;
+    let mut run = |code: &str| match command(code.trim()) {
+        Ok(output) => println!("{}", output),
+        Err(e) => {
+            clean = false;
+            eprintln!("{}: {}", code.trim(), e);
+        }
+    };
+    if codes.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line.map_err(|e| format!("cannot read stdin: {}", e))?;
+            if !line.trim().is_empty() {
+                run(&line);
+            }
+        }
+    } else {
+        codes.iter().for_each(|code| run(code));
+    }
+    Ok(if clean {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// The code and what it parsed as, e.g. `0 71720 53977 4: UPC-A: 071720539774`
+fn validate(code: &str) -> Result<String, String> {
+    let gtin = GTIN::try_from(code).map_err(|e| e.to_string())?;
+    Ok(format!("{}: {}", code, gtin))
+}
+
+fn parse_target(target: &str) -> Result<GtinKind, String> {
+    match target {
+        "ean13" => Ok(GtinKind::Ean13),
+        "gtin14" => Ok(GtinKind::Gtin14),
+        "upca" => Ok(GtinKind::UpcA),
+        "upce" => Ok(GtinKind::UpcE),
+        _ => Err(format!("unknown conversion target: {}", target)),
+    }
+}
+
+/// The digits of the code as `kind`
+fn convert(code: &str, kind: GtinKind) -> Result<String, String> {
+    let gtin = GTIN::try_from(code).map_err(|e| e.to_string())?;
+    let converted = gtin.convert_to(kind).map_err(|e| e.to_string())?;
+    Ok(String::from(converted))
+}
+
+/// Kind, country, number system and check digit as JSON
+fn info(code: &str) -> Result<String, String> {
+    let info = GtinInfo::classify(code);
+    match info.error {
+        Some(e) => Err(e),
+        None => serde_json::to_string(&info).map_err(|e| e.to_string()),
+    }
+}
+
+/// The check digit completing a payload of 7, 11, 12 or 13 digits
+fn check_digit(payload: &str) -> Result<u8, String> {
+    let digits = payload
+        .chars()
+        .map(|c| match c.to_digit(10) {
+            Some(digit) => Ok(digit as u8),
+            None => Err(format!("invalid digit: {:?}", c)),
+        })
+        .collect::<Result<Vec<u8>, String>>()?;
+    match digits.len() {
+        7 | 11 | 12 | 13 => Ok(calculate_checksum_digit(&digits)),
+        len => Err(format!(
+            "a payload has 7, 11, 12 or 13 digits, found {}",
+            len
+        )),
+    }
+}
+
+fn parse_audit_options(args: &[String]) -> Result<AuditOptions, String> {
+    let mut options = AuditOptions {
+        config: None,
+        watch: None,
+        reports: None,
+        interval: Duration::from_secs(5),
+        files: vec![],
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .map(PathBuf::from)
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--config" => options.config = Some(value()?),
+            "--watch" => options.watch = Some(value()?),
+            "--reports" => options.reports = Some(value()?),
+            "--interval" => {
+                let seconds = value()?;
+                let seconds = seconds
+                    .to_str()
+                    .and_then(|s| s.parse().ok())
+                    .ok_or_else(|| format!("invalid interval: {}", seconds.display()))?;
+                options.interval = Duration::from_secs(seconds);
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option: {}", flag)),
+            file => options.files.push(PathBuf::from(file)),
+        }
+    }
+
+    match (&options.watch, options.files.is_empty()) {
+        (Some(_), false) => Err("--watch does not take files".to_string()),
+        (None, true) => Err("nothing to audit".to_string()),
+        _ => Ok(options),
+    }
+}
+
+/// The pipeline configured in the file, or one that also accepts quoted codes
+fn load_pipeline(config: Option<&Path>) -> Result<Pipeline, String> {
+    match config {
+        Some(path) => {
+            let config = fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+            Pipeline::from_toml(&config)
+        }
+        None => Ok(Pipeline::new().with_strip_quotes(true)),
+    }
+}
+
+fn audit(options: AuditOptions) -> Result<ExitCode, String> {
+    let pipeline = load_pipeline(options.config.as_deref())?;
+
+    if let Some(dir) = &options.watch {
+        let reports = options
+            .reports
+            .clone()
+            .unwrap_or_else(|| dir.join("reports"));
+        fs::create_dir_all(&reports)
+            .map_err(|e| format!("cannot create {}: {}", reports.display(), e))?;
+        let mut watcher = Watcher::default();
+        loop {
+            // The folder may be briefly unavailable, e.g. on a network share, so keep polling
+            match watcher.poll(&pipeline, dir, &reports) {
+                Ok(written) => {
+                    for path in written {
+                        println!("{}", path.display());
+                    }
+                }
+                Err(e) => eprintln!("gtin: cannot watch {}: {}", dir.display(), e),
+            }
+            thread::sleep(options.interval);
+        }
+    }
+
+    let mut clean = true;
+    for path in &options.files {
+        let report = audit_file(&pipeline, path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        clean &= report.problems.is_empty();
+        println!(
+            "{}",
+            serde_json::to_string(&report).map_err(|e| e.to_string())?
+        );
+    }
+    Ok(if clean {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// Audit a file line by line, without reading it into memory first
+fn audit_file(pipeline: &Pipeline, path: &Path) -> io::Result<Report> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut report = Report {
+        file: path.display().to_string(),
+        lines: 0,
+        valid: 0,
+        problems: vec![],
+    };
+
+    // The pipeline reads each line just before giving its result, so `input` holds the
+    // line the result is for
+    let input = RefCell::new(String::new());
+    let mut read_error = None;
+    let lines = reader
+        .lines()
+        .map_while(|line| line.map_err(|e| read_error = Some(e)).ok())
+        .inspect(|line| input.borrow_mut().clone_from(line));
+
+    for (i, result) in pipeline.run(lines).enumerate() {
+        report.lines += 1;
+        match result {
+            Ok(_) => report.valid += 1,
+            Err(e) => report.problems.push(Problem {
+                line: i + 1,
+                input: input.borrow().clone(),
+                error: e.to_string(),
+            }),
+        }
+    }
+    match read_error {
+        Some(e) => Err(e),
+        None => Ok(report),
+    }
+}
+
+fn parse_batch_options(args: &[String]) -> Result<BatchOptions, String> {
+    let mut options = BatchOptions {
+        config: None,
+        format: Format::Json,
+        files: vec![],
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("{} requires a value", arg))
+        };
+        match arg.as_str() {
+            "--config" => options.config = Some(PathBuf::from(value()?)),
+            "--format" => {
+                options.format = match value()?.as_str() {
+                    "json" => Format::Json,
+                    "csv" => Format::Csv,
+                    format => return Err(format!("unknown format: {}", format)),
+                }
+            }
+            flag if flag.starts_with("--") => return Err(format!("unknown option: {}", flag)),
+            file => options.files.push(PathBuf::from(file)),
+        }
+    }
+    Ok(options)
+}
+
+fn batch(options: BatchOptions) -> Result<ExitCode, String> {
+    let pipeline = load_pipeline(options.config.as_deref())?;
+    let files = if options.files.is_empty() {
+        vec![PathBuf::from("-")]
+    } else {
+        options.files
+    };
+
+    let mut rows = vec![];
+    for path in &files {
+        let contents = if path == Path::new("-") {
+            let mut contents = String::new();
+            io::stdin().read_to_string(&mut contents).map(|_| contents)
+        } else {
+            fs::read_to_string(path)
+        };
+        let contents = contents.map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        rows.extend(batch_rows(
+            &pipeline,
+            &path.display().to_string(),
+            &contents,
+        ));
+    }
+
+    let summary = Summary::of(&rows);
+    write_batch(options.format, &rows, &summary, io::stdout().lock())
+        .map_err(|e| format!("cannot write results: {}", e))?;
+    if options.format == Format::Csv {
+        eprintln!(
+            "{} lines, {} valid, {} invalid",
+            summary.lines, summary.valid, summary.invalid
+        );
+    }
+    Ok(if summary.invalid == 0 {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn batch_rows(pipeline: &Pipeline, file: &str, contents: &str) -> Vec<Row> {
+    let results = pipeline.run(contents.lines());
+    contents
+        .lines()
+        .zip(results)
+        .enumerate()
+        .map(|(i, (input, result))| Row {
+            file: file.to_string(),
+            line: i + 1,
+            input: input.to_string(),
+            valid: result.is_ok(),
+            kind: result.as_ref().ok().map(GTIN::kind),
+            digits: result.as_ref().ok().map(|&gtin| String::from(gtin)),
+            error: result.err().map(|e| e.to_string()),
+        })
+        .collect()
+}
+
+/// The rows and summary as one JSON document, or the rows alone as CSV with a header
+fn write_batch<W: Write>(
+    format: Format,
+    rows: &[Row],
+    summary: &Summary,
+    mut out: W,
+) -> io::Result<()> {
+    match format {
+        Format::Json => {
+            let report = BatchReport { rows, summary };
+            serde_json::to_writer(&mut out, &report)?;
+            writeln!(out)
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for row in rows {
+                writer.serialize(row)?;
+            }
+            writer.flush()
+        }
+    }
+}
+
+/// Tracks a drop folder between polls. A file is audited once its size has stayed the
+/// same for a whole poll interval, so files still being copied in are left alone.
+#[derive(Debug, Default)]
+struct Watcher {
+    sizes: HashMap<PathBuf, u64>,
+    done: HashSet<PathBuf>,
+}
+
+impl Watcher {
+    /// Audit the files that have settled since the last poll, returning their reports' paths.
+    /// A file that cannot be read gets a report of the error and is not retried.
+    fn poll(
+        &mut self,
+        pipeline: &Pipeline,
+        dir: &Path,
+        reports: &Path,
+    ) -> io::Result<Vec<PathBuf>> {
+        let mut written = vec![];
+        for entry in fs::read_dir(dir)? {
+            // Entries can vanish between listing and reading; they are seen again next poll
+            let Ok((path, metadata)) = entry.and_then(|e| Ok((e.path(), e.metadata()?))) else {
+                continue;
+            };
+            if !metadata.is_file() || self.done.contains(&path) {
+                continue;
+            }
+
+            let size = metadata.len();
+            if self.sizes.insert(path.clone(), size) != Some(size) {
+                continue; // new or still growing
+            }
+
+            let report = match audit_file(pipeline, &path) {
+                Ok(report) => serde_json::to_string_pretty(&report)?,
+                Err(e) => serde_json::to_string_pretty(&Failure {
+                    file: path.display().to_string(),
+                    error: e.to_string(),
+                })?,
+            };
+            let mut name = path.file_name().unwrap_or_default().to_os_string();
+            name.push(".json");
+            let report_path = reports.join(name);
+            fs::write(&report_path, report)?;
+
+            self.sizes.remove(&path);
+            self.done.insert(path);
+            written.push(report_path);
+        }
+        written.sort();
+        Ok(written)
+    }
+}
+
+#[cfg(test)]
+mod tests;
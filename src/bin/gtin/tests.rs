@@ -0,0 +1,180 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use gtin::Pipeline;
+
+use super::{
+    audit_file, batch_rows, check_digit, convert, info, parse_audit_options, parse_batch_options,
+    parse_target, validate, write_batch, Format, Problem, Summary, Watcher,
+};
+
+/// A fresh directory, unique per process, as test runs can overlap
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("{}_{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn parse_options() {
+    let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+
+    let options = parse_audit_options(&args("--watch in --interval 1")).unwrap();
+    assert_eq!(options.watch, Some(PathBuf::from("in")));
+    assert_eq!(options.interval, Duration::from_secs(1));
+
+    assert!(parse_audit_options(&args("--watch in feed.txt")).is_err());
+    assert!(parse_audit_options(&args("--interval soon feed.txt")).is_err());
+    assert!(parse_audit_options(&[]).is_err());
+}
+
+#[test]
+fn audit_lines() {
+    let dir = temp_dir("gtin_cli_audit_lines");
+    let path = dir.join("feed.txt");
+    fs::write(&path, "071720539774\n071720539775\n\"8595701530526\"\n").unwrap();
+
+    let report = audit_file(&Pipeline::new().with_strip_quotes(true), &path).unwrap();
+    assert_eq!(report.lines, 3);
+    assert_eq!(report.valid, 2);
+    assert_eq!(
+        report.problems,
+        vec![Problem {
+            line: 2,
+            input: "071720539775".to_string(),
+            error: "Invalid GTIN checksum".to_string(),
+        }]
+    );
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_waits_for_files_to_settle() {
+    let dir = temp_dir("gtin_cli_watch");
+    let reports = dir.join("reports");
+    fs::create_dir_all(&reports).unwrap();
+    let pipeline = Pipeline::new();
+    let mut watcher = Watcher::default();
+
+    fs::write(dir.join("a.txt"), "071720539774\n").unwrap();
+    assert!(watcher.poll(&pipeline, &dir, &reports).unwrap().is_empty());
+
+    fs::write(dir.join("a.txt"), "071720539774\n8595701530526\n").unwrap(); // still arriving
+    assert!(watcher.poll(&pipeline, &dir, &reports).unwrap().is_empty());
+
+    let written = watcher.poll(&pipeline, &dir, &reports).unwrap();
+    assert_eq!(written, vec![reports.join("a.txt.json")]);
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&written[0]).unwrap()).unwrap();
+    assert_eq!(report["valid"], 2);
+
+    // Audited files are not reported again
+    assert!(watcher.poll(&pipeline, &dir, &reports).unwrap().is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn watch_reports_unreadable_files() {
+    let dir = temp_dir("gtin_cli_watch_unreadable");
+    let reports = dir.join("reports");
+    fs::create_dir_all(&reports).unwrap();
+    let pipeline = Pipeline::new();
+    let mut watcher = Watcher::default();
+
+    fs::write(dir.join("a.txt"), b"071720539774\n\xff\xfe\n").unwrap(); // not UTF-8
+    fs::write(dir.join("b.txt"), "071720539774\n").unwrap();
+    assert!(watcher.poll(&pipeline, &dir, &reports).unwrap().is_empty());
+
+    // The unreadable file gets an error report, and the other is still audited
+    let written = watcher.poll(&pipeline, &dir, &reports).unwrap();
+    assert_eq!(
+        written,
+        vec![reports.join("a.txt.json"), reports.join("b.txt.json")]
+    );
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&written[0]).unwrap()).unwrap();
+    assert!(report["error"].as_str().unwrap().contains("UTF-8"));
+
+    assert!(watcher.poll(&pipeline, &dir, &reports).unwrap().is_empty());
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn code_commands() {
+    assert_eq!(
+        validate("0 71720 53977 4"),
+        Ok("0 71720 53977 4: UPC-A: 071720539774".to_string())
+    );
+    assert_eq!(
+        validate("071720539775"),
+        Err("Invalid GTIN checksum".to_string())
+    );
+
+    let to = |code: &str, target: &str| convert(code, parse_target(target).unwrap());
+    assert_eq!(to("071720539774", "ean13"), Ok("0071720539774".to_string()));
+    assert_eq!(
+        to("071720539774", "gtin14"),
+        Ok("00071720539774".to_string())
+    );
+    assert_eq!(to("00100000000052", "upce"), Ok("10000502".to_string()));
+    assert_eq!(to("]E010000052", "upca"), Ok("100000000052".to_string()));
+    assert!(to("071720539774", "upce").is_err());
+    assert!(to("96385074", "ean13").is_err());
+    assert_eq!(
+        to("96385074", "upca"),
+        Err("Ean8 cannot be represented as UpcA".to_string())
+    );
+    assert!(parse_target("isbn").is_err());
+
+    let json: serde_json::Value = serde_json::from_str(&info("4006381333931").unwrap()).unwrap();
+    assert_eq!(json["kind"], "Ean13");
+    assert_eq!(json["country_code"], "DE");
+    assert_eq!(json["check_digit"], 1);
+    assert!(info("4006381333932").is_err());
+
+    assert_eq!(check_digit("400638133393"), Ok(1));
+    assert_eq!(check_digit("07172053977"), Ok(4));
+    assert!(check_digit("4006381333").is_err());
+    assert!(check_digit("40063813339x").is_err());
+}
+
+#[test]
+fn batch_output() {
+    let args = |s: &str| -> Vec<String> { s.split(' ').map(String::from).collect() };
+    let options = parse_batch_options(&args("--format csv a.txt -")).unwrap();
+    assert_eq!(options.format, Format::Csv);
+    assert_eq!(
+        options.files,
+        vec![PathBuf::from("a.txt"), PathBuf::from("-")]
+    );
+    assert!(parse_batch_options(&args("--format xml")).is_err());
+
+    let pipeline = Pipeline::new().with_strip_quotes(true);
+    let rows = batch_rows(
+        &pipeline,
+        "-",
+        "071720539774\n071720539775\n\"8595701530526\"\n",
+    );
+    let summary = Summary::of(&rows);
+    assert_eq!((summary.lines, summary.valid, summary.invalid), (3, 2, 1));
+
+    let mut json = vec![];
+    write_batch(Format::Json, &rows, &summary, &mut json).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    assert_eq!(json["rows"][1]["error"], "Invalid GTIN checksum");
+    assert_eq!(json["rows"][2]["digits"], "8595701530526");
+    assert_eq!(json["summary"]["kinds"]["Ean13"], 1);
+
+    let mut csv = vec![];
+    write_batch(Format::Csv, &rows, &summary, &mut csv).unwrap();
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        "file,line,input,valid,kind,digits,error
+-,1,071720539774,true,UpcA,071720539774,
+-,2,071720539775,false,,,Invalid GTIN checksum
+-,3,\"\"\"8595701530526\"\"\",true,Ean13,8595701530526,
+"
+    );
+}
@@ -0,0 +1,212 @@
+//! An LRU cache keyed by GTIN, for product-lookup services that keep recently used
+//! metadata in memory. Entries are keyed by `GTIN::canonical_key`, so a UPC-A and its
+//! EAN-13 and GTIN-14 forms share one entry, and no key strings are stored.
+
+use std::collections::HashMap;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::sync::Mutex;
+
+use crate::{GtinKey, GTIN};
+
+const NONE: usize = usize::MAX; // end of the recency list
+
+/// Hashes canonical keys with a single multiply, as they are already well spread integers
+#[derive(Default)]
+struct KeyHasher(u64);
+
+impl Hasher for KeyHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.0 = (self.0 << 8 | b as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        }
+    }
+
+    fn write_u64(&mut self, key: u64) {
+        self.0 = key.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    }
+}
+
+#[derive(Debug)]
+struct Entry<V> {
+    key: GtinKey,
+    value: V,
+    newer: usize,
+    older: usize,
+}
+
+/// A least-recently-used cache holding at most `capacity` values
+pub struct GtinCache<V> {
+    index: HashMap<GtinKey, usize, BuildHasherDefault<KeyHasher>>,
+    entries: Vec<Entry<V>>, // a slab linked from newest to oldest
+    newest: usize,
+    oldest: usize,
+    capacity: usize,
+}
+
+impl<V> GtinCache<V> {
+    pub fn new(capacity: usize) -> Self {
+        assert!(capacity > 0, "cache capacity must be positive");
+        GtinCache {
+            index: HashMap::with_capacity_and_hasher(capacity, Default::default()),
+            entries: Vec::with_capacity(capacity),
+            newest: NONE,
+            oldest: NONE,
+            capacity,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Look up a value, marking it as the most recently used
+    pub fn get(&mut self, gtin: &GTIN) -> Option<&V> {
+        let slot = *self.index.get(&gtin.canonical_key())?;
+        self.touch(slot);
+        Some(&self.entries[slot].value)
+    }
+
+    /// Whether a value is cached, without changing its recency
+    pub fn contains(&self, gtin: &GTIN) -> bool {
+        self.index.contains_key(&gtin.canonical_key())
+    }
+
+    /// Store a value, returning the one it replaced. When the cache is full the least
+    /// recently used value is evicted.
+    pub fn insert(&mut self, gtin: &GTIN, value: V) -> Option<V> {
+        let key = gtin.canonical_key();
+        if let Some(&slot) = self.index.get(&key) {
+            self.touch(slot);
+            return Some(std::mem::replace(&mut self.entries[slot].value, value));
+        }
+
+        let slot = if self.entries.len() < self.capacity {
+            self.entries.push(Entry {
+                key,
+                value,
+                newer: NONE,
+                older: NONE,
+            });
+            self.entries.len() - 1
+        } else {
+            // Reuse the oldest entry's slot
+            let slot = self.oldest;
+            self.unlink(slot);
+            self.index.remove(&self.entries[slot].key);
+            self.entries[slot].key = key;
+            self.entries[slot].value = value;
+            slot
+        };
+        self.index.insert(key, slot);
+        self.push_newest(slot);
+        None
+    }
+
+    /// The cached value, computing and storing it first if it is missing
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&mut self, gtin: &GTIN, f: F) -> &V {
+        if !self.contains(gtin) {
+            self.insert(gtin, f());
+        }
+        match self.get(gtin) {
+            Some(value) => value,
+            None => unreachable!("value was just inserted"),
+        }
+    }
+
+    fn touch(&mut self, slot: usize) {
+        if self.newest != slot {
+            self.unlink(slot);
+            self.push_newest(slot);
+        }
+    }
+
+    fn unlink(&mut self, slot: usize) {
+        let Entry { newer, older, .. } = self.entries[slot];
+        match newer {
+            NONE => self.newest = older,
+            newer => self.entries[newer].older = older,
+        }
+        match older {
+            NONE => self.oldest = newer,
+            older => self.entries[older].newer = newer,
+        }
+    }
+
+    fn push_newest(&mut self, slot: usize) {
+        self.entries[slot].newer = NONE;
+        self.entries[slot].older = self.newest;
+        match self.newest {
+            NONE => self.oldest = slot,
+            newest => self.entries[newest].newer = slot,
+        }
+        self.newest = slot;
+    }
+}
+
+/// A thread-safe cache split into independently locked shards, so concurrent lookups of
+/// different GTINs rarely contend. Each shard evicts on its own.
+pub struct ShardedGtinCache<V> {
+    shards: Vec<Mutex<GtinCache<V>>>,
+}
+
+impl<V: Clone> ShardedGtinCache<V> {
+    /// A cache of `capacity` values in total, spread over `shards` shards
+    pub fn new(capacity: usize, shards: usize) -> Self {
+        assert!(shards > 0, "cache must have at least one shard");
+        let per_shard = capacity.div_ceil(shards).max(1);
+        ShardedGtinCache {
+            shards: (0..shards)
+                .map(|_| Mutex::new(GtinCache::new(per_shard)))
+                .collect(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.shards.iter().map(|shard| lock(shard).len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// A copy of the cached value, marking it as the most recently used in its shard
+    pub fn get(&self, gtin: &GTIN) -> Option<V> {
+        lock(self.shard(gtin)).get(gtin).cloned()
+    }
+
+    pub fn insert(&self, gtin: &GTIN, value: V) -> Option<V> {
+        lock(self.shard(gtin)).insert(gtin, value)
+    }
+
+    pub fn get_or_insert_with<F: FnOnce() -> V>(&self, gtin: &GTIN, f: F) -> V {
+        lock(self.shard(gtin)).get_or_insert_with(gtin, f).clone()
+    }
+
+    fn shard(&self, gtin: &GTIN) -> &Mutex<GtinCache<V>> {
+        let mut hasher = KeyHasher::default();
+        hasher.write_u64(gtin.canonical_key());
+        // The high bits are the best mixed
+        &self.shards[((hasher.finish() >> 32) % self.shards.len() as u64) as usize]
+    }
+}
+
+/// A poisoned shard only means another thread panicked mid-operation; the links are
+/// updated before any user code runs, so the shard is still consistent
+fn lock<V>(shard: &Mutex<GtinCache<V>>) -> std::sync::MutexGuard<'_, GtinCache<V>> {
+    shard.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+pub mod tests;
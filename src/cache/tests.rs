@@ -0,0 +1,58 @@
+use std::sync::Arc;
+use std::thread;
+
+use super::{GtinCache, ShardedGtinCache};
+use crate::GTIN;
+
+fn gtin(n: u64) -> GTIN {
+    let digits: Vec<u8> = format!("{:011}", n).bytes().map(|b| b - b'0').collect();
+    let check = crate::util::calculate_checksum_digit(&digits);
+    GTIN::from_digits(crate::GtinKind::UpcA, digits.into_iter().chain([check])).unwrap()
+}
+
+#[test]
+fn evict_least_recently_used() {
+    let mut cache = GtinCache::new(2);
+    assert_eq!(cache.insert(&gtin(1), "one"), None);
+    cache.insert(&gtin(2), "two");
+    assert_eq!(cache.get(&gtin(1)), Some(&"one")); // 2 is now the oldest
+    cache.insert(&gtin(3), "three");
+
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&gtin(2)));
+    assert_eq!(cache.get(&gtin(3)), Some(&"three"));
+    assert_eq!(cache.insert(&gtin(1), "uno"), Some("one"));
+    cache.insert(&gtin(4), "four");
+    assert!(!cache.contains(&gtin(3)));
+    assert_eq!(cache.get_or_insert_with(&gtin(5), || "five"), &"five");
+    assert!(!cache.contains(&gtin(1)));
+}
+
+#[test]
+fn equivalent_forms_share_an_entry() {
+    let mut cache = GtinCache::new(4);
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    cache.insert(&upca, 1);
+    assert_eq!(cache.get(&upca.as_ean13().unwrap()), Some(&1));
+}
+
+#[test]
+fn sharded_across_threads() {
+    let cache = Arc::new(ShardedGtinCache::new(1000, 8));
+    let handles: Vec<_> = (0..4)
+        .map(|t| {
+            let cache = Arc::clone(&cache);
+            thread::spawn(move || {
+                for n in 0..100 {
+                    cache.get_or_insert_with(&gtin(t * 100 + n), || n);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    assert_eq!(cache.len(), 400);
+    assert_eq!(cache.get(&gtin(205)), Some(5));
+}
@@ -0,0 +1,68 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::encode::{Symbology, SYMBOLOGIES};
+use crate::PREFIX_DATA_VERSION;
+
+/// What this build of the library was compiled with
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Capabilities {
+    pub crate_version: &'static str,
+    pub prefix_data_version: &'static str,
+    pub gcp_table: bool, // GS1 Company Prefix length table
+    pub serde: bool,
+    pub encoders: &'static [Symbology],
+    pub svg: bool,
+    pub toml: bool, // pipeline configuration
+    pub image: bool,
+    pub metrics: bool,
+    pub wasm: bool,
+    pub differential: bool,
+    pub conformance: bool,
+    pub rayon: bool, // parallel batch helpers
+    pub csv: bool,
+    pub utoipa: bool, // OpenAPI schemas
+    pub rkyv: bool,
+    pub arbitrary: bool, // fuzzing input generation
+    pub rand: bool,
+    pub sqlx: bool,
+    pub diesel: bool,
+    pub rusqlite: bool,
+    pub redis: bool,
+    pub arrow: bool,
+    pub uniffi: bool, // Kotlin and Swift bindings
+    pub arrayvec: bool,
+}
+
+/// Report the optional features and data compiled into this build, so hosts can check
+/// it matches their deployment expectations at startup
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        prefix_data_version: PREFIX_DATA_VERSION,
+        gcp_table: false,
+        serde: cfg!(feature = "serde"),
+        encoders: SYMBOLOGIES,
+        svg: cfg!(feature = "svg"),
+        toml: cfg!(feature = "toml"),
+        image: cfg!(feature = "image"),
+        metrics: cfg!(feature = "metrics"),
+        wasm: cfg!(feature = "wasm"),
+        differential: cfg!(feature = "differential"),
+        conformance: cfg!(feature = "conformance"),
+        rayon: cfg!(feature = "rayon"),
+        csv: cfg!(feature = "csv"),
+        utoipa: cfg!(feature = "utoipa"),
+        rkyv: cfg!(feature = "rkyv"),
+        arbitrary: cfg!(feature = "arbitrary"),
+        rand: cfg!(feature = "rand"),
+        sqlx: cfg!(feature = "sqlx"),
+        diesel: cfg!(feature = "diesel"),
+        rusqlite: cfg!(feature = "rusqlite"),
+        redis: cfg!(feature = "redis"),
+        arrow: cfg!(feature = "arrow"),
+        uniffi: cfg!(feature = "uniffi"),
+        arrayvec: cfg!(feature = "arrayvec"),
+    }
+}
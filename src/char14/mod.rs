@@ -0,0 +1,74 @@
+//! Fixed-width CHAR(14) database columns. The padding policy is explicit on both write
+//! and read, so a value written one way is never silently read another.
+
+use crate::split::to_gtin;
+use crate::{GtinError, GTIN};
+
+/// How a GTIN shorter than 14 digits fills a CHAR(14) buffer
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Padding {
+    Zeros,          // the GTIN-14 form, with UPC-E expanded; the kind is not kept
+    TrailingSpaces, // left-aligned, as CHAR columns pad on insert
+    LeadingSpaces,  // right-aligned
+}
+
+impl GTIN {
+    pub fn write_char14(&self, buffer: &mut [u8; 14], padding: Padding) {
+        let mut key = self.canonical_key();
+        let digits = self.digits();
+        match padding {
+            Padding::Zeros => {
+                for byte in buffer.iter_mut().rev() {
+                    *byte = b'0' + (key % 10) as u8;
+                    key /= 10;
+                }
+            }
+            Padding::TrailingSpaces => {
+                buffer.fill(b' ');
+                for (byte, &d) in buffer.iter_mut().zip(digits) {
+                    *byte = b'0' + d;
+                }
+            }
+            Padding::LeadingSpaces => {
+                buffer.fill(b' ');
+                for (byte, &d) in buffer.iter_mut().rev().zip(digits.iter().rev()) {
+                    *byte = b'0' + d;
+                }
+            }
+        }
+    }
+
+    /// Read a buffer written with `padding`. Any other byte where padding should be, or
+    /// anything but digits in the value, is `GtinError::InvalidPadding`.
+    pub fn read_char14(buffer: &[u8; 14], padding: Padding) -> Result<GTIN, GtinError> {
+        let value = match padding {
+            Padding::Zeros => &buffer[..],
+            Padding::TrailingSpaces => {
+                let len = buffer.iter().position(|&b| b == b' ').unwrap_or(14);
+                if buffer[len..].iter().any(|&b| b != b' ') {
+                    return Err(GtinError::InvalidPadding);
+                }
+                &buffer[..len]
+            }
+            Padding::LeadingSpaces => {
+                let start = buffer.iter().rposition(|&b| b == b' ').map_or(0, |i| i + 1);
+                if buffer[..start].iter().any(|&b| b != b' ') {
+                    return Err(GtinError::InvalidPadding);
+                }
+                &buffer[start..]
+            }
+        };
+        if !value.iter().all(u8::is_ascii_digit) {
+            return Err(GtinError::InvalidPadding);
+        }
+
+        let digits: Vec<u8> = value.iter().map(|b| b - b'0').collect();
+        match digits.len() {
+            8 | 12 | 13 | 14 => to_gtin(&digits).ok_or(GtinError::InvalidChecksum),
+            len => Err(GtinError::UnsupportedLength(len)),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,52 @@
+use super::Padding;
+use crate::{GtinError, GTIN};
+
+#[test]
+fn char14_round_trip() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let mut buffer = [0; 14];
+
+    upca.write_char14(&mut buffer, Padding::Zeros);
+    assert_eq!(&buffer, b"00071720539774");
+    assert_eq!(
+        GTIN::read_char14(&buffer, Padding::Zeros),
+        Ok(GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]))
+    );
+
+    upca.write_char14(&mut buffer, Padding::TrailingSpaces);
+    assert_eq!(&buffer, b"071720539774  ");
+    assert_eq!(
+        GTIN::read_char14(&buffer, Padding::TrailingSpaces),
+        Ok(upca)
+    );
+
+    upca.write_char14(&mut buffer, Padding::LeadingSpaces);
+    assert_eq!(&buffer, b"  071720539774");
+    assert_eq!(GTIN::read_char14(&buffer, Padding::LeadingSpaces), Ok(upca));
+}
+
+#[test]
+fn char14_errors() {
+    let read = |bytes: &[u8; 14], padding| GTIN::read_char14(bytes, padding);
+
+    assert_eq!(
+        read(b"  071720539774", Padding::TrailingSpaces),
+        Err(GtinError::InvalidPadding)
+    );
+    assert_eq!(
+        read(b"071720539774  ", Padding::Zeros),
+        Err(GtinError::InvalidPadding)
+    );
+    assert_eq!(
+        read(b"0717205 39774 ", Padding::TrailingSpaces),
+        Err(GtinError::InvalidPadding)
+    );
+    assert_eq!(
+        read(b"  071720539775", Padding::LeadingSpaces),
+        Err(GtinError::InvalidChecksum)
+    );
+    assert_eq!(
+        read(b"              ", Padding::LeadingSpaces),
+        Err(GtinError::UnsupportedLength(0))
+    );
+}
@@ -0,0 +1,179 @@
+//! Columnar storage for large GTIN sets, such as the caches batch jobs keep between runs.
+//! Each GTIN is packed into a 52-bit word: a kind nibble above its 48-bit digit value.
+
+use std::fmt::{Display, Formatter};
+
+use crate::{GtinKind, GTIN};
+
+/// Identifies the serialized form, followed by a version byte and three reserved bytes
+const MAGIC: &[u8; 4] = b"GTNC";
+pub const FORMAT_VERSION: u8 = 1;
+const HEADER_BYTES: usize = 16; // magic, version, reserved, little-endian u64 count
+const RECORD_BYTES: usize = 7; // a packed word, little-endian
+const VALUE_BITS: u32 = 48;
+
+/// Why bytes could not be read as a column
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ColumnError {
+    Magic,
+    Version(u8), // written by a newer or unknown version of the format
+    Length { expected: usize, found: usize },
+    Record(usize), // the record at this index is not a valid GTIN
+}
+
+impl Display for ColumnError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ColumnError::Magic => write!(f, "Not a GTIN column"),
+            ColumnError::Version(v) => write!(f, "Unsupported GTIN column version {}", v),
+            ColumnError::Length { expected, found } => {
+                write!(f, "Expected {} bytes, found {}", expected, found)
+            }
+            ColumnError::Record(i) => write!(f, "Invalid GTIN in record {}", i),
+        }
+    }
+}
+
+impl std::error::Error for ColumnError {}
+
+/// A compact, ordered collection of GTINs that keeps each code's kind
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GtinColumn {
+    packed: Vec<u64>,
+}
+
+impl GtinColumn {
+    pub fn new() -> Self {
+        GtinColumn::default()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        GtinColumn {
+            packed: Vec::with_capacity(capacity),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.packed.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packed.is_empty()
+    }
+
+    pub fn push(&mut self, gtin: &GTIN) {
+        self.packed.push(pack(gtin));
+    }
+
+    pub fn get(&self, index: usize) -> Option<GTIN> {
+        self.packed.get(index).map(|&word| unpack(word))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = GTIN> + '_ {
+        self.packed.iter().map(|&word| unpack(word))
+    }
+
+    /// Serialize with a versioned header, as fixed-size records that can be read in place
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_BYTES + self.len() * RECORD_BYTES);
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[FORMAT_VERSION, 0, 0, 0]);
+        bytes.extend_from_slice(&(self.len() as u64).to_le_bytes());
+        for word in &self.packed {
+            bytes.extend_from_slice(&word.to_le_bytes()[..RECORD_BYTES]);
+        }
+        bytes
+    }
+
+    /// Read bytes written by `to_bytes`, validating every record
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ColumnError> {
+        if bytes.len() < HEADER_BYTES || &bytes[..4] != MAGIC {
+            return Err(ColumnError::Magic);
+        }
+        if bytes[4] != FORMAT_VERSION {
+            return Err(ColumnError::Version(bytes[4]));
+        }
+        let mut count = [0; 8];
+        count.copy_from_slice(&bytes[8..HEADER_BYTES]);
+        let expected = usize::try_from(u64::from_le_bytes(count))
+            .ok()
+            .and_then(|count| count.checked_mul(RECORD_BYTES))
+            .and_then(|records| records.checked_add(HEADER_BYTES))
+            .unwrap_or(usize::MAX);
+        if bytes.len() != expected {
+            return Err(ColumnError::Length {
+                expected,
+                found: bytes.len(),
+            });
+        }
+
+        let packed = bytes[HEADER_BYTES..]
+            .chunks_exact(RECORD_BYTES)
+            .enumerate()
+            .map(|(i, record)| {
+                let mut word = [0; 8];
+                word[..RECORD_BYTES].copy_from_slice(record);
+                let word = u64::from_le_bytes(word);
+                try_unpack(word).map(|_| word).ok_or(ColumnError::Record(i))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(GtinColumn { packed })
+    }
+}
+
+impl FromIterator<GTIN> for GtinColumn {
+    fn from_iter<I: IntoIterator<Item = GTIN>>(iter: I) -> Self {
+        GtinColumn {
+            packed: iter.into_iter().map(|gtin| pack(&gtin)).collect(),
+        }
+    }
+}
+
+fn kind_nibble(kind: GtinKind) -> u64 {
+    match kind {
+        GtinKind::UpcE => 0,
+        GtinKind::UpcA => 1,
+        GtinKind::Ean8 => 2,
+        GtinKind::Ean13 => 3,
+        GtinKind::Gtin14 => 4,
+    }
+}
+
+/// A kind nibble above the 48-bit value of the digits, also the compact serde form and the
+/// Diesel BigInt one
+pub(crate) fn pack(gtin: &GTIN) -> u64 {
+    let value = gtin.digits().iter().fold(0, |acc, &d| acc * 10 + d as u64);
+    kind_nibble(gtin.kind()) << VALUE_BITS | value
+}
+
+pub(crate) fn try_unpack(word: u64) -> Option<GTIN> {
+    let kind = match word >> VALUE_BITS {
+        0 => GtinKind::UpcE,
+        1 => GtinKind::UpcA,
+        2 => GtinKind::Ean8,
+        3 => GtinKind::Ean13,
+        4 => GtinKind::Gtin14,
+        _ => return None,
+    };
+    let mut value = word & ((1 << VALUE_BITS) - 1);
+    let mut digits = [0; 14];
+    let count = kind.digit_count();
+    for digit in digits[..count].iter_mut().rev() {
+        *digit = (value % 10) as u8;
+        value /= 10;
+    }
+    if value != 0 {
+        return None; // more digits than the kind has
+    }
+    GTIN::from_digits(kind, digits[..count].iter().copied()).ok()
+}
+
+fn unpack(word: u64) -> GTIN {
+    match try_unpack(word) {
+        Some(gtin) => gtin,
+        None => unreachable!("columns only hold packed GTINs"),
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
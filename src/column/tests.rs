@@ -0,0 +1,54 @@
+use super::{ColumnError, GtinColumn};
+use crate::GTIN;
+
+fn sample() -> GtinColumn {
+    [
+        "071720539774",
+        "8595701530526",
+        "00123457",
+        "]E004182635",
+        "10071720539771",
+    ]
+    .iter()
+    .map(|s| GTIN::try_from(*s).unwrap())
+    .collect()
+}
+
+#[test]
+fn bytes_round_trip() {
+    let column = sample();
+    let bytes = column.to_bytes();
+    assert_eq!(bytes.len(), 16 + 5 * 7);
+    assert_eq!(&bytes[..5], b"GTNC\x01");
+
+    let read = GtinColumn::from_bytes(&bytes).unwrap();
+    assert_eq!(read, column);
+    assert_eq!(read.get(3), Some(GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5])));
+    assert_eq!(read.iter().count(), 5);
+}
+
+#[test]
+fn bytes_errors() {
+    let bytes = sample().to_bytes();
+
+    assert_eq!(GtinColumn::from_bytes(b"GTIN"), Err(ColumnError::Magic));
+
+    let mut newer = bytes.clone();
+    newer[4] = 2;
+    assert_eq!(GtinColumn::from_bytes(&newer), Err(ColumnError::Version(2)));
+
+    assert_eq!(
+        GtinColumn::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(ColumnError::Length {
+            expected: 51,
+            found: 50
+        })
+    );
+
+    let mut corrupt = bytes;
+    corrupt[16] ^= 1; // first record's check digit no longer matches
+    assert_eq!(
+        GtinColumn::from_bytes(&corrupt),
+        Err(ColumnError::Record(0))
+    );
+}
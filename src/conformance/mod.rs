@@ -0,0 +1,163 @@
+//! Conformance vectors for other implementations of this crate's parsing (a JS frontend,
+//! SQL functions): inputs with what this crate makes of them, generated from the parser
+//! and the prefix tables, and exported as JSON lines.
+
+use serde::Serialize;
+
+use crate::schema::{gtin_error_schema, kind_name, number_system_name};
+use crate::util::{calculate_checksum_digit, digits_to_string, upca_digits};
+use crate::{GtinError, GTIN, PREFIX_DATA_VERSION};
+
+/// An input and the result this crate gives for it
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Vector {
+    pub input: String,
+    #[serde(flatten)]
+    pub expected: Expected,
+}
+
+/// Names are the ones `schema()` uses
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum Expected {
+    Valid {
+        kind: &'static str,
+        canonical: String, // zero-padded 14 digits, UPC-E expanded first
+        country: Option<&'static str>,
+        number_system: &'static str,
+    },
+    Invalid {
+        error: &'static str,
+    },
+}
+
+impl Expected {
+    fn of(result: Result<GTIN, GtinError>) -> Self {
+        match result {
+            Ok(gtin) => Expected::Valid {
+                kind: kind_name(gtin.kind()),
+                canonical: format!("{:014}", gtin.canonical_key()),
+                country: gtin.country_code(),
+                number_system: number_system_name(gtin.number_system()),
+            },
+            Err(e) => Expected::Invalid {
+                error: gtin_error_schema(&e).code,
+            },
+        }
+    }
+}
+
+/// The body with its check digit appended
+fn with_check_digit(body: &[u8]) -> Vec<u8> {
+    let mut digits = body.to_vec();
+    digits.push(calculate_checksum_digit(body));
+    digits
+}
+
+/// The same digits with a wrong check digit
+fn with_wrong_check_digit(digits: &[u8]) -> Vec<u8> {
+    let mut wrong = digits.to_vec();
+    if let Some(last) = wrong.last_mut() {
+        *last = (*last + 1) % 10;
+    }
+    wrong
+}
+
+/// Inputs covering every 3-digit prefix, every length, each kind and the parser's
+/// heuristics, with invalid check digits alongside
+fn inputs() -> Vec<String> {
+    let mut valid: Vec<Vec<u8>> = vec![];
+
+    // Every GS1 prefix, for the country and number system tables
+    for prefix in 0..1000u32 {
+        let mut body = vec![
+            (prefix / 100) as u8,
+            (prefix / 10 % 10) as u8,
+            (prefix % 10) as u8,
+        ];
+        body.extend([5, 0, 0, 0, 1, 2, 3, 4, 5]);
+        valid.push(with_check_digit(&body));
+    }
+    // Each leading digit of UPC-A, EAN-8 (or UPC-E) and GTIN-14
+    for first in 0..10 {
+        valid.push(with_check_digit(&[first, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7]));
+        valid.push(with_check_digit(&[first, 2, 0, 1, 3, 4, 8]));
+        let gtin14 = [first, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7];
+        valid.push(with_check_digit(&gtin14));
+    }
+
+    let mut inputs: Vec<String> = vec![];
+    for digits in &valid {
+        inputs.push(digits_to_string(digits));
+        inputs.push(digits_to_string(&with_wrong_check_digit(digits)));
+    }
+
+    // UPC-E for both number systems and each zero-suppression rule, with and without the
+    // identifier that settles the EAN-8 reading
+    for number_system in 0..2 {
+        for last in 0..10 {
+            let body = [1, 2, 3, 4, 5, last];
+            let upca = upca_digits(number_system, &body);
+            let mut upce = vec![number_system];
+            upce.extend(body);
+            upce.push(upca[11]);
+            inputs.push(digits_to_string(&upce));
+            inputs.push(format!("]E0{}", digits_to_string(&upce)));
+            inputs.push(format!("]E4{}", digits_to_string(&upce)));
+        }
+    }
+
+    // UPC-A stored as a number, without its leading zero
+    inputs.push("71720539774".to_string());
+    inputs.push("71720539775".to_string());
+
+    // Every length up to 18 digits with a correct check digit
+    for len in 1..=18 {
+        inputs.push(digits_to_string(&with_check_digit(&vec![1; len - 1])));
+    }
+
+    // Separators, whitespace, identifiers and element strings
+    for input in [
+        "",
+        "0 71720 53977 4",
+        "0717-2053-9774",
+        "  071720539774\n",
+        "]E0071720539774",
+        "]C10100071720539774",
+        "(01)00071720539774",
+        "UPC 071720539774",
+    ] {
+        inputs.push(input.to_string());
+    }
+    inputs
+}
+
+/// Every vector, in a fixed order
+pub fn vectors() -> Vec<Vector> {
+    inputs()
+        .into_iter()
+        .map(|input| Vector {
+            expected: Expected::of(crate::parse_str(&input)),
+            input,
+        })
+        .collect()
+}
+
+/// The vectors as JSON lines, after a header line with the versions they were made with
+pub fn to_json_lines(vectors: &[Vector]) -> String {
+    let header = serde_json::json!({
+        "crate_version": env!("CARGO_PKG_VERSION"),
+        "prefix_data_version": PREFIX_DATA_VERSION,
+        "vectors": vectors.len(),
+    });
+    let mut lines = header.to_string();
+    for vector in vectors {
+        lines.push('\n');
+        lines.push_str(&serde_json::to_string(vector).expect("vectors serialize to JSON"));
+    }
+    lines.push('\n');
+    lines
+}
+
+#[cfg(test)]
+pub mod tests;
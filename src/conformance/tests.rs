@@ -0,0 +1,57 @@
+use super::{to_json_lines, vectors, Expected};
+
+#[test]
+fn generate_vectors() {
+    let vectors = vectors();
+    let expected = |input: &str| {
+        vectors
+            .iter()
+            .find(|vector| vector.input == input)
+            .map(|vector| vector.expected.clone())
+    };
+
+    assert_eq!(
+        expected("0 71720 53977 4"),
+        Some(Expected::Valid {
+            kind: "UpcA",
+            canonical: "00071720539774".to_string(),
+            country: Some("US"),
+            number_system: "General",
+        })
+    );
+    assert_eq!(
+        expected("71720539775"),
+        Some(Expected::Invalid {
+            error: "InvalidChecksum"
+        })
+    );
+    assert_eq!(
+        expected("1111111111"), // 10 digits with a correct check digit
+        Some(Expected::Invalid {
+            error: "UnsupportedLength"
+        })
+    );
+
+    // Every prefix is covered with a valid and an invalid EAN-13
+    let ean13 = vectors
+        .iter()
+        .filter(|vector| matches!(vector.expected, Expected::Valid { kind: "Ean13", .. }))
+        .count();
+    assert!(ean13 >= 1000);
+    assert_eq!(vectors, super::vectors()); // deterministic
+}
+
+#[test]
+fn export_json_lines() {
+    let vectors = vectors();
+    let text = to_json_lines(&vectors);
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), vectors.len() + 1);
+
+    let header: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!(header["vectors"], vectors.len());
+    let first: serde_json::Value = serde_json::from_str(lines[1]).unwrap();
+    assert_eq!(first["input"], "0005000123452");
+    assert_eq!(first["result"], "valid");
+    assert_eq!(first["number_system"], "General");
+}
@@ -0,0 +1,124 @@
+//! Candidate corrections for codes that fail the check digit, covering the two errors
+//! the mod-10 check is designed to catch: a single wrong digit and swapped neighbours.
+
+use crate::aim::strip_identifier;
+use crate::split;
+use crate::util::extract_digits;
+use crate::GTIN;
+
+/// Lengths a GTIN can be typed at, the same ones parsing accepts
+const LENGTHS: [usize; 5] = [8, 11, 12, 13, 14];
+
+/// Characters people type between digit groups, which are not mistakes
+const SEPARATORS: [char; 3] = [' ', '-', '.'];
+
+/// What is wrong with a typed code. Positions count digits from 0 on the left.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Diagnosis {
+    Valid(GTIN),
+    WrongLength(usize),                         // digit count no GTIN has
+    WrongDigit { position: usize, gtin: GTIN }, // a character that is not a digit stands in for one
+    Transposition(Vec<(usize, GTIN)>), // the digit at one of these positions swapped with the next
+    Unknown,                           // a digit is wrong, but the check digit cannot say which
+}
+
+impl GTIN {
+    /// The valid GTINs one adjacent transposition or one digit substitution away from the
+    /// input, transpositions first. Empty if the input is already valid or its length is
+    /// not a GTIN length.
+    pub fn suggest_corrections(value: &str) -> Vec<GTIN> {
+        let digits = extract_digits(value);
+        if GTIN::try_from(value).is_ok() || !LENGTHS.contains(&digits.len()) {
+            return vec![];
+        }
+
+        let mut suggestions: Vec<GTIN> = vec![];
+        let mut add = |candidate: &[u8]| {
+            if let Some(gtin) = to_gtin(candidate) {
+                if !suggestions.contains(&gtin) {
+                    suggestions.push(gtin);
+                }
+            }
+        };
+
+        let mut candidate = digits.clone();
+        for i in 0..digits.len() - 1 {
+            if digits[i] != digits[i + 1] {
+                candidate.swap(i, i + 1);
+                add(&candidate);
+                candidate.swap(i, i + 1);
+            }
+        }
+        for i in 0..digits.len() {
+            for d in (0..10).filter(|&d| d != digits[i]) {
+                candidate[i] = d;
+                add(&candidate);
+            }
+            candidate[i] = digits[i];
+        }
+        suggestions
+    }
+}
+
+/// Classify why a typed code is not a valid GTIN, for telling the person what to fix
+pub fn diagnose(input: &str) -> Diagnosis {
+    if let Ok(gtin) = GTIN::try_from(input) {
+        return Diagnosis::Valid(gtin);
+    }
+    let (_, data) = strip_identifier(input);
+    let digits = extract_digits(data);
+
+    // One stray character where a digit should be: only one digit there validates
+    let mut stray = data
+        .trim()
+        .chars()
+        .filter(|c| !SEPARATORS.contains(c))
+        .enumerate()
+        .filter(|(_, c)| !c.is_ascii_digit());
+    if let (Some((position, _)), None) = (stray.next(), stray.next()) {
+        if LENGTHS.contains(&(digits.len() + 1)) {
+            let mut candidate = digits.clone();
+            candidate.insert(position, 0);
+            let gtin = (0..10).find_map(|d| {
+                candidate[position] = d;
+                to_gtin(&candidate)
+            });
+            if let Some(gtin) = gtin {
+                return Diagnosis::WrongDigit { position, gtin };
+            }
+        }
+    }
+
+    if !LENGTHS.contains(&digits.len()) {
+        return Diagnosis::WrongLength(digits.len());
+    }
+
+    // The check digit rarely singles out one swap, so every swap that fits is reported
+    let transpositions: Vec<(usize, GTIN)> = (0..digits.len() - 1)
+        .filter(|&position| digits[position] != digits[position + 1])
+        .filter_map(|position| {
+            let mut candidate = digits.clone();
+            candidate.swap(position, position + 1);
+            to_gtin(&candidate).map(|gtin| (position, gtin))
+        })
+        .collect();
+    if transpositions.is_empty() {
+        Diagnosis::Unknown
+    } else {
+        Diagnosis::Transposition(transpositions)
+    }
+}
+
+/// The GTIN these digits read as, taking 11 digits as a UPC-A missing its leading zero
+/// like parsing does
+fn to_gtin(digits: &[u8]) -> Option<GTIN> {
+    if digits.len() == 11 {
+        let mut upca = vec![0];
+        upca.extend_from_slice(digits);
+        return split::to_gtin(&upca);
+    }
+    split::to_gtin(digits)
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,58 @@
+use super::{diagnose, Diagnosis};
+use crate::GTIN;
+
+#[test]
+fn suggest_corrections() {
+    // 071720539774 with the 3 and 9 swapped. The swap shifts the weighted sum by 12, so
+    // other swaps that shift it back, like the 7 and 1, are suggested too.
+    let suggestions = GTIN::suggest_corrections("071720593774");
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert!(suggestions[..3].contains(&upca));
+    assert!(suggestions.iter().all(|g| g.digits().len() == 12));
+    // Plus one substitution per position
+    assert!(suggestions.len() >= 12);
+
+    let suggestions = GTIN::suggest_corrections("071720539775");
+    assert!(suggestions.contains(&upca));
+    assert_eq!(suggestions.len(), 12); // no transposition fixes this one
+
+    assert!(GTIN::suggest_corrections("071720539774").is_empty());
+    assert!(GTIN::suggest_corrections("0717205397").is_empty());
+
+    // Valid UPC-E, even though the digits fail the flat check
+    assert!(GTIN::suggest_corrections("04182635").is_empty());
+
+    // 11 digits are a UPC-A missing its leading zero
+    assert!(GTIN::suggest_corrections("71720539774").is_empty());
+    assert!(GTIN::suggest_corrections("71720539775").contains(&upca));
+}
+
+#[test]
+fn diagnose_input() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let ean13 = GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+
+    let cases = vec![
+        ("0 71720 53977 4", Diagnosis::Valid(upca)),
+        ("0717205397", Diagnosis::WrongLength(10)),
+        (
+            "0 71720 5x977 4",
+            Diagnosis::WrongDigit {
+                position: 7,
+                gtin: upca,
+            },
+        ),
+        (
+            "8595701350526",
+            Diagnosis::Transposition(vec![
+                (3, GTIN::Ean13([8, 5, 9, 7, 5, 0, 1, 3, 5, 0, 5, 2, 6])),
+                (4, GTIN::Ean13([8, 5, 9, 5, 0, 7, 1, 3, 5, 0, 5, 2, 6])),
+                (7, ean13),
+            ]),
+        ),
+        ("071720539775", Diagnosis::Unknown),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(diagnose(input), expected, "{}", input);
+    }
+}
@@ -0,0 +1,200 @@
+//! Validate the GTIN column of a CSV file, reporting the rows that fail and optionally
+//! writing a copy with the valid values normalized by a `Pipeline`.
+
+use std::cell::RefCell;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Write};
+use std::path::Path;
+
+use crate::pipeline::{Pipeline, Rejection};
+use crate::util::Digits;
+
+/// Which column holds the GTINs
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ColumnSelector {
+    Index(usize), // 0-based
+    Name(String), // matched against the header row
+}
+
+/// A row whose GTIN did not make it through the pipeline
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct InvalidRow {
+    pub line: u64, // 1-based, as an editor shows it
+    pub value: String,
+    pub rejection: Rejection,
+}
+
+/// What a run found
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CsvReport {
+    pub rows: usize, // data rows, without the header
+    pub valid: usize,
+    pub changed: usize, // valid values that normalization rewrote
+    pub invalid: Vec<InvalidRow>,
+}
+
+/// Why a file could not be processed at all
+#[derive(Debug)]
+pub enum CsvError {
+    Csv(::csv::Error), // malformed CSV or I/O
+    ColumnNotFound(String),
+}
+
+impl Display for CsvError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CsvError::Csv(e) => write!(f, "{}", e),
+            CsvError::ColumnNotFound(name) => write!(f, "No column named {:?}", name),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+impl From<::csv::Error> for CsvError {
+    fn from(e: ::csv::Error) -> Self {
+        CsvError::Csv(e)
+    }
+}
+
+/// Checks one column of CSV files. Build one with `new` and the `with_*` methods.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct CsvValidator {
+    column: ColumnSelector,
+    delimiter: u8,
+    has_headers: bool,
+    pipeline: Pipeline,
+}
+
+impl CsvValidator {
+    /// Comma separated with a header row, parsing with the default pipeline
+    pub fn new(column: ColumnSelector) -> Self {
+        CsvValidator {
+            column,
+            delimiter: b',',
+            has_headers: true,
+            pipeline: Pipeline::new(),
+        }
+    }
+
+    pub fn with_delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn with_headers(mut self, has_headers: bool) -> Self {
+        self.has_headers = has_headers;
+        self
+    }
+
+    /// The pipeline values go through, which also decides their normalized form
+    pub fn with_pipeline(mut self, pipeline: Pipeline) -> Self {
+        self.pipeline = pipeline;
+        self
+    }
+
+    pub fn validate<R: Read>(&self, reader: R) -> Result<CsvReport, CsvError> {
+        self.run(reader, None::<&mut ::csv::Writer<std::io::Sink>>)
+    }
+
+    pub fn validate_file<P: AsRef<Path>>(&self, path: P) -> Result<CsvReport, CsvError> {
+        self.validate(std::fs::File::open(path).map_err(::csv::Error::from)?)
+    }
+
+    /// Validate, and write every row to `writer` with valid values normalized and invalid
+    /// ones left as they were
+    pub fn correct<R: Read, W: Write>(&self, reader: R, writer: W) -> Result<CsvReport, CsvError> {
+        let mut writer = ::csv::WriterBuilder::new()
+            .delimiter(self.delimiter)
+            .flexible(true)
+            .from_writer(writer);
+        let report = self.run(reader, Some(&mut writer))?;
+        writer.flush().map_err(::csv::Error::from)?;
+        Ok(report)
+    }
+
+    fn run<R: Read, W: Write>(
+        &self,
+        reader: R,
+        mut writer: Option<&mut ::csv::Writer<W>>,
+    ) -> Result<CsvReport, CsvError> {
+        let mut reader = ::csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.has_headers)
+            .flexible(true)
+            .from_reader(reader);
+
+        let index = match &self.column {
+            ColumnSelector::Index(index) => *index,
+            ColumnSelector::Name(name) => reader
+                .headers()?
+                .iter()
+                .position(|header| header.trim() == name)
+                .ok_or_else(|| CsvError::ColumnNotFound(name.clone()))?,
+        };
+        if let (Some(writer), true) = (writer.as_mut(), self.has_headers) {
+            writer.write_record(reader.headers()?)?;
+        }
+
+        // The pipeline pulls one value at a time, so `current` holds the row of each result
+        let mut report = CsvReport::default();
+        let current = RefCell::new(::csv::StringRecord::new());
+        let mut read_error = None;
+        let values = std::iter::from_fn(|| {
+            let mut record = current.borrow_mut();
+            match reader.read_record(&mut record) {
+                Ok(true) => Some(record.get(index).unwrap_or("").to_string()),
+                Ok(false) => None,
+                Err(e) => {
+                    read_error = Some(e);
+                    None
+                }
+            }
+        });
+        for result in self.pipeline.run(values) {
+            let record = current.borrow();
+            report.rows += 1;
+            let value = record.get(index).unwrap_or("");
+
+            let normalized = match result {
+                Ok(gtin) => {
+                    report.valid += 1;
+                    let normalized = Digits(gtin.digits()).to_string();
+                    report.changed += usize::from(normalized != value);
+                    Some(normalized)
+                }
+                Err(rejection) => {
+                    report.invalid.push(InvalidRow {
+                        line: record.position().map_or(0, |position| position.line()),
+                        value: value.to_string(),
+                        rejection,
+                    });
+                    None
+                }
+            };
+
+            if let Some(writer) = writer.as_mut() {
+                match normalized {
+                    Some(normalized) if index < record.len() => {
+                        let fields = record.iter().enumerate().map(|(i, field)| {
+                            if i == index {
+                                normalized.as_str()
+                            } else {
+                                field
+                            }
+                        });
+                        writer.write_record(fields)?;
+                    }
+                    _ => writer.write_record(&*record)?,
+                }
+            }
+        }
+        match read_error {
+            Some(e) => Err(e.into()),
+            None => Ok(report),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
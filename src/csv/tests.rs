@@ -0,0 +1,88 @@
+use super::{ColumnSelector, CsvError, CsvValidator, InvalidRow};
+use crate::pipeline::{Canonical, Pipeline, Rejection};
+use crate::GtinError;
+
+const PRODUCTS: &str = "sku,gtin,name
+A1,071720539774,Widget
+A2,0 71720 53977 4,\"Widget, boxed\"
+A3,071720539775,Gadget
+A4,,Nothing
+A5
+";
+
+#[test]
+fn validate_column_by_name() {
+    let report = CsvValidator::new(ColumnSelector::Name("gtin".to_string()))
+        .validate(PRODUCTS.as_bytes())
+        .unwrap();
+    assert_eq!(report.rows, 5);
+    assert_eq!(report.valid, 2);
+    assert_eq!(report.changed, 1);
+    assert_eq!(
+        report.invalid,
+        vec![
+            InvalidRow {
+                line: 4,
+                value: "071720539775".to_string(),
+                rejection: Rejection::Invalid(GtinError::InvalidChecksum),
+            },
+            InvalidRow {
+                line: 5,
+                value: "".to_string(),
+                rejection: Rejection::Empty,
+            },
+            InvalidRow {
+                line: 6,
+                value: "".to_string(),
+                rejection: Rejection::Empty,
+            },
+        ]
+    );
+
+    let missing =
+        CsvValidator::new(ColumnSelector::Name("ean".to_string())).validate(PRODUCTS.as_bytes());
+    assert!(matches!(missing, Err(CsvError::ColumnNotFound(name)) if name == "ean"));
+}
+
+#[test]
+fn correct_column() {
+    let mut output = vec![];
+    let report = CsvValidator::new(ColumnSelector::Index(1))
+        .with_pipeline(
+            Pipeline::new()
+                .with_canonical(Canonical::Gtin14)
+                .with_dedup(true),
+        )
+        .correct(PRODUCTS.as_bytes(), &mut output)
+        .unwrap();
+    assert_eq!(report.valid, 1);
+    assert_eq!(report.changed, 1);
+    assert_eq!(report.invalid.len(), 4);
+    assert!(matches!(
+        report.invalid[0].rejection,
+        Rejection::Duplicate(_)
+    ));
+
+    assert_eq!(
+        String::from_utf8(output).unwrap(),
+        "sku,gtin,name
+A1,00071720539774,Widget
+A2,0 71720 53977 4,\"Widget, boxed\"
+A3,071720539775,Gadget
+A4,,Nothing
+A5
+"
+    );
+}
+
+#[test]
+fn headerless_semicolons() {
+    let report = CsvValidator::new(ColumnSelector::Index(0))
+        .with_delimiter(b';')
+        .with_headers(false)
+        .validate("4006381333931;1\n4006381333932;2\n".as_bytes())
+        .unwrap();
+    assert_eq!(report.rows, 2);
+    assert_eq!(report.valid, 1);
+    assert_eq!(report.invalid[0].line, 2);
+}
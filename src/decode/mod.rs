@@ -0,0 +1,116 @@
+//! Recover a GTIN from a scanned EAN-13 or UPC-A symbol, given either its 95 modules or
+//! the widths of its 59 bars and spaces. Scans may run in either direction.
+
+use std::fmt::{Display, Formatter};
+
+use crate::encode::{digit_pattern, left_parities, Parity, CENTER_GUARD, END_GUARD, START_GUARD};
+use crate::{GtinError, GtinKind, GTIN};
+
+/// Modules in an EAN-13 or UPC-A symbol
+pub const MODULES: usize = 95;
+/// Bars and spaces in an EAN-13 or UPC-A symbol, starting and ending with a bar
+pub const ELEMENTS: usize = 59;
+
+/// Reasons a scan could not be decoded
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum DecodeError {
+    Length(usize),    // wrong number of modules or elements
+    Guard,            // start, center or end guard not found
+    Character(usize), // position of a digit matching no pattern, from the left
+    Parity,           // left-half parities select no first digit
+    InvalidChecksum,
+}
+
+impl Display for DecodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::Length(len) => write!(f, "Unexpected scan length: {}", len),
+            DecodeError::Guard => write!(f, "Guard pattern not found"),
+            DecodeError::Character(position) => {
+                write!(f, "Unreadable symbol character at position {}", position)
+            }
+            DecodeError::Parity => write!(f, "Invalid left-half parity pattern"),
+            DecodeError::InvalidChecksum => write!(f, "Invalid GTIN checksum"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Decode a 95-module pattern, `true` for a bar. A leading 0 recovered from the parities
+/// gives a UPC-A, anything else an EAN-13.
+pub fn decode_modules(modules: &[bool]) -> Result<GTIN, DecodeError> {
+    if modules.len() != MODULES {
+        return Err(DecodeError::Length(modules.len()));
+    }
+
+    decode_forward(modules).or_else(|error| {
+        // A backwards scan reads the right half's R patterns as reversed G patterns, so
+        // only retry when the forward read could not make sense of the characters
+        let reversed: Vec<bool> = modules.iter().rev().copied().collect();
+        decode_forward(&reversed).map_err(|_| error)
+    })
+}
+
+/// Decode the widths of the bars and spaces, in modules, starting with the first bar of
+/// the start guard
+pub fn decode_widths(widths: &[u8]) -> Result<GTIN, DecodeError> {
+    if widths.len() != ELEMENTS {
+        return Err(DecodeError::Length(widths.len()));
+    }
+
+    let modules: Vec<bool> = widths
+        .iter()
+        .enumerate()
+        .flat_map(|(i, &width)| std::iter::repeat_n(i % 2 == 0, width as usize))
+        .collect();
+    decode_modules(&modules)
+}
+
+fn decode_forward(modules: &[bool]) -> Result<GTIN, DecodeError> {
+    if modules[..3] != START_GUARD || modules[45..50] != CENTER_GUARD || modules[92..] != END_GUARD
+    {
+        return Err(DecodeError::Guard);
+    }
+
+    let mut digits = [0; 13];
+    let mut parities = [Parity::L; 6];
+    for i in 0..6 {
+        let start = 3 + i * 7;
+        let (digit, parity) = decode_character(&modules[start..start + 7], &[Parity::L, Parity::G])
+            .ok_or(DecodeError::Character(i))?;
+        digits[i + 1] = digit;
+        parities[i] = parity;
+    }
+    for i in 0..6 {
+        let start = 50 + i * 7;
+        let (digit, _) = decode_character(&modules[start..start + 7], &[Parity::R])
+            .ok_or(DecodeError::Character(i + 6))?;
+        digits[i + 7] = digit;
+    }
+
+    digits[0] = (0..10)
+        .find(|&first| left_parities(first) == parities)
+        .ok_or(DecodeError::Parity)?;
+
+    let result = if digits[0] == 0 {
+        GTIN::from_digits(GtinKind::UpcA, digits[1..].iter().copied())
+    } else {
+        GTIN::from_digits(GtinKind::Ean13, digits)
+    };
+    result.map_err(|error| match error {
+        GtinError::InvalidChecksum => DecodeError::InvalidChecksum,
+        _ => unreachable!("decoded digits are in range and correctly counted"),
+    })
+}
+
+fn decode_character(modules: &[bool], parities: &[Parity]) -> Option<(u8, Parity)> {
+    parities.iter().find_map(|&parity| {
+        (0..10)
+            .find(|&digit| digit_pattern(digit, parity)[..] == *modules)
+            .map(|digit| (digit, parity))
+    })
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,55 @@
+use super::{decode_modules, decode_widths, DecodeError};
+use crate::encode::{encode, encode_ean13};
+use crate::GTIN;
+
+fn modules(gtin: &GTIN) -> Vec<bool> {
+    encode(gtin).unwrap().modules().to_vec()
+}
+
+#[test]
+fn decode_symbols() {
+    for input in ["8595701 530526", "6901234 567892", "0 71720 53977 4"] {
+        let gtin = GTIN::try_from(input).unwrap();
+        let modules = modules(&gtin);
+        assert_eq!(decode_modules(&modules), Ok(gtin), "{}", input);
+
+        let reversed: Vec<bool> = modules.iter().rev().copied().collect();
+        assert_eq!(decode_modules(&reversed), Ok(gtin), "{} reversed", input);
+    }
+}
+
+#[test]
+fn decode_element_widths() {
+    let gtin = GTIN::try_from("8595701 530526").unwrap();
+    let mut widths = vec![];
+    let mut previous = None;
+    for &bar in &modules(&gtin) {
+        if previous == Some(bar) {
+            *widths.last_mut().unwrap() += 1;
+        } else {
+            widths.push(1);
+        }
+        previous = Some(bar);
+    }
+    assert_eq!(decode_widths(&widths), Ok(gtin));
+    assert_eq!(decode_widths(&widths[1..]), Err(DecodeError::Length(58)));
+}
+
+#[test]
+fn decode_errors() {
+    let gtin = GTIN::try_from("8595701 530526").unwrap();
+
+    let mut damaged = modules(&gtin);
+    damaged[10] = !damaged[10]; // inside the second digit
+    assert_eq!(decode_modules(&damaged), Err(DecodeError::Character(1)));
+
+    let mut damaged = modules(&gtin);
+    damaged[0] = false;
+    assert_eq!(decode_modules(&damaged), Err(DecodeError::Guard));
+
+    let wrong_check = encode_ean13(&[8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 7]);
+    assert_eq!(
+        decode_modules(wrong_check.modules()),
+        Err(DecodeError::InvalidChecksum)
+    );
+}
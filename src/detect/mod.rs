@@ -0,0 +1,117 @@
+//! Auto-detection that reports how sure it is, for callers that would rather send a
+//! doubtful code to human review than accept the variant the parser picked.
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::aim::{eight_digit_kind, strip_identifier};
+use crate::util::{extract_digits, validate_upce};
+use crate::{GtinError, GtinKind, GTIN};
+
+/// How much the parser had to guess
+#[derive(Debug, PartialEq, Eq, Clone, Copy, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Confidence {
+    Ambiguous, // a different product is also a valid reading
+    Likely,    // digits were restored or padding interpreted, same product either way
+    Certain,
+}
+
+/// The parser's choice, with the other valid readings of the same input
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct Detected {
+    pub gtin: GTIN,
+    pub confidence: Confidence,
+    pub alternatives: Vec<GTIN>,
+}
+
+/// The readings of an 8-digit code
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct EightDigitCandidates {
+    pub ean8: Option<GTIN>,
+    pub upce: Option<GTIN>, // only for number systems 0 and 1
+}
+
+impl GTIN {
+    /// Parse like `GTIN::try_from`, also reporting any other way the digits could be read
+    pub fn detect(value: &str) -> Result<Detected, GtinError> {
+        let gtin = GTIN::try_from(value)?;
+        let (identifier, data) = strip_identifier(value);
+        let digits = gtin.digits();
+        let unpadded = |zeros: usize, kind| {
+            digits[..zeros]
+                .iter()
+                .all(|&d| d == 0)
+                .then(|| GTIN::from_digits(kind, digits[zeros..].iter().copied()).ok())
+                .flatten()
+        };
+
+        let (confidence, alternatives) = match gtin.kind() {
+            // A symbology identifier says which symbol was scanned
+            GtinKind::Ean8 | GtinKind::UpcE if identifier.and_then(eight_digit_kind).is_some() => {
+                (Confidence::Certain, vec![])
+            }
+            // Otherwise the digits may be valid as both
+            GtinKind::Ean8 | GtinKind::UpcE => {
+                let candidates = GTIN::parse_eight_digit(value);
+                let other = match gtin.kind() {
+                    GtinKind::Ean8 => candidates.upce,
+                    _ => candidates.ean8,
+                };
+                match other {
+                    Some(other) => (Confidence::Ambiguous, vec![other]),
+                    None => (Confidence::Certain, vec![]),
+                }
+            }
+            GtinKind::UpcA if extract_digits(data).len() == 11 => (Confidence::Likely, vec![]),
+            GtinKind::Ean13 => match unpadded(1, GtinKind::UpcA) {
+                Some(upca) => (Confidence::Likely, vec![upca]),
+                None => (Confidence::Certain, vec![]),
+            },
+            GtinKind::Gtin14 => {
+                let alternatives: Vec<GTIN> = [(1, GtinKind::Ean13), (2, GtinKind::UpcA)]
+                    .into_iter()
+                    .filter_map(|(zeros, kind)| unpadded(zeros, kind))
+                    .collect();
+                if alternatives.is_empty() {
+                    (Confidence::Certain, alternatives)
+                } else {
+                    (Confidence::Likely, alternatives)
+                }
+            }
+            GtinKind::UpcA => (Confidence::Certain, vec![]),
+        };
+
+        Ok(Detected {
+            gtin,
+            confidence,
+            alternatives,
+        })
+    }
+
+    /// Both valid readings of an 8-digit code, for callers that settle the EAN-8/UPC-E
+    /// question with their own context. A symbology identifier that names the symbol
+    /// rules out the other reading; input that is not 8 valid digits has neither.
+    pub fn parse_eight_digit(value: &str) -> EightDigitCandidates {
+        let (identifier, data) = strip_identifier(value);
+        let digits = extract_digits(data);
+        let kind = identifier.and_then(eight_digit_kind);
+        let allowed = |candidate: GtinKind| kind.is_none_or(|kind| kind == candidate);
+
+        // UPC-E is checked through its UPC-A expansion, EAN-8 with the flat mod-10 check
+        let upce = <[u8; 8]>::try_from(digits.as_slice())
+            .ok()
+            .filter(|d| allowed(GtinKind::UpcE) && d[0] <= 1 && validate_upce(d))
+            .map(GTIN::UpcE);
+        let ean8 = match allowed(GtinKind::Ean8) {
+            true => GTIN::from_digits(GtinKind::Ean8, digits.iter().copied()).ok(),
+            false => None,
+        };
+        EightDigitCandidates { ean8, upce }
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,85 @@
+use super::{Confidence, Detected, EightDigitCandidates};
+use crate::GTIN;
+
+#[test]
+fn detect_confidence() {
+    let cases = vec![
+        ("0 71720 53977 4", Confidence::Certain, 0),
+        ("71720 53977 4", Confidence::Likely, 0), // leading zero restored
+        ("0 071720 539774", Confidence::Likely, 1),
+        ("00 071720 539774", Confidence::Likely, 2),
+        ("8595701 530526", Confidence::Certain, 0),
+        ("1 0859570 15305 3", Confidence::Certain, 0),
+        ("1000 0052", Confidence::Ambiguous, 1),
+        ("0000 0055", Confidence::Ambiguous, 1),
+        ("5201 3485", Confidence::Certain, 0), // no UPC-E reading
+        ("0520 1341", Confidence::Certain, 0),
+        ("]E4 5201 3485", Confidence::Certain, 0),
+    ];
+
+    for (input, confidence, alternatives) in cases {
+        let detected = GTIN::detect(input).unwrap();
+        assert_eq!(detected.confidence, confidence, "{}", input);
+        assert_eq!(detected.alternatives.len(), alternatives, "{}", input);
+        assert_eq!(Ok(detected.gtin), GTIN::try_from(input), "{}", input);
+    }
+}
+
+#[test]
+fn detect_alternatives() {
+    let detected = GTIN::detect("1000 0052").unwrap();
+    assert_eq!(
+        detected,
+        Detected {
+            gtin: GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]),
+            confidence: Confidence::Ambiguous,
+            alternatives: vec![GTIN::Ean8([1, 0, 0, 0, 0, 0, 5, 2])],
+        }
+    );
+
+    let detected = GTIN::detect("00 071720 539774").unwrap();
+    assert_eq!(
+        detected.alternatives,
+        vec![
+            GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
+            GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
+        ]
+    );
+
+    assert!(GTIN::detect("071720539775").is_err());
+}
+
+#[test]
+fn eight_digit_candidates() {
+    let both = |d: [u8; 8]| EightDigitCandidates {
+        ean8: Some(GTIN::Ean8(d)),
+        upce: Some(GTIN::UpcE(d)),
+    };
+    let none = EightDigitCandidates {
+        ean8: None,
+        upce: None,
+    };
+
+    assert_eq!(
+        GTIN::parse_eight_digit("0000 0055"),
+        both([0, 0, 0, 0, 0, 0, 5, 5])
+    );
+    assert_eq!(GTIN::parse_eight_digit("0520 1341").upce, None); // wrong UPC-E check digit
+    assert_eq!(
+        GTIN::parse_eight_digit("0418 2635"),
+        EightDigitCandidates {
+            ean8: None, // wrong EAN-8 check digit
+            upce: Some(GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5])),
+        }
+    );
+    assert_eq!(
+        GTIN::parse_eight_digit("5201 3485"),
+        EightDigitCandidates {
+            ean8: Some(GTIN::Ean8([5, 2, 0, 1, 3, 4, 8, 5])),
+            upce: None, // number system 5
+        }
+    );
+    assert_eq!(GTIN::parse_eight_digit("]E0 0520 1341").ean8, None);
+    assert_eq!(GTIN::parse_eight_digit("0520 1342"), none);
+    assert_eq!(GTIN::parse_eight_digit("071720539774"), none);
+}
@@ -0,0 +1,140 @@
+//! Differential conformance suite: compares this crate against a deliberately naive
+//! reference implementation over deterministically generated inputs.
+
+use std::fmt::{Display, Formatter};
+
+use crate::util::expand_upce_to_upca;
+use crate::GTIN;
+
+pub mod reference;
+
+/// A small xorshift generator so runs are reproducible from a seed without extra dependencies
+#[derive(Debug, Clone)]
+pub struct Generator {
+    state: u64,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        // xorshift must never be seeded with zero
+        Generator {
+            state: seed ^ 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x
+    }
+
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next_u64() % bound
+    }
+
+    /// Random digits of a random length, with a correct check digit about half of the time
+    pub fn digits(&mut self) -> Vec<u8> {
+        let len = 6 + self.below(10) as usize;
+        let mut digits: Vec<u8> = (0..len).map(|_| self.below(10) as u8).collect();
+        if self.below(2) == 0 {
+            let body = &digits[..len - 1];
+            digits[len - 1] = reference::check_digit(body);
+        }
+        digits
+    }
+
+    /// A random input string with separators sprinkled between the digits
+    pub fn input(&mut self) -> String {
+        const SEPARATORS: [char; 4] = [' ', '-', '.', '_'];
+        const IDENTIFIERS: [&str; 4] = ["]E0", "]E4", "]C1", "]e0"];
+        let mut input = String::new();
+        if self.below(8) == 0 {
+            input.push_str(IDENTIFIERS[self.below(4) as usize]);
+        }
+        for digit in self.digits() {
+            if self.below(5) == 0 {
+                input.push(SEPARATORS[self.below(4) as usize]);
+            }
+            input.push((b'0' + digit) as char);
+        }
+        input
+    }
+}
+
+/// A disagreement between the crate and the reference implementation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub check: &'static str,
+    pub input: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} mismatch for {:?}: reference gave {}, crate gave {}",
+            self.check, self.input, self.expected, self.actual
+        )
+    }
+}
+
+fn compare<T: PartialEq + std::fmt::Debug>(
+    check: &'static str,
+    input: &str,
+    expected: T,
+    actual: T,
+) -> Result<(), Mismatch> {
+    if expected == actual {
+        Ok(())
+    } else {
+        Err(Mismatch {
+            check,
+            input: input.to_string(),
+            expected: format!("{:?}", expected),
+            actual: format!("{:?}", actual),
+        })
+    }
+}
+
+/// Check parsing, UPC-E expansion and EAN-13 conversion for a single input
+pub fn check_input(input: &str) -> Result<(), Mismatch> {
+    let parsed = GTIN::try_from(input).ok();
+    compare("parse", input, reference::parse(input), parsed)?;
+
+    if let Some(gtin) = parsed {
+        compare(
+            "as_ean13",
+            input,
+            reference::as_ean13(&gtin),
+            gtin.as_ean13().ok().map(|g| g.digits().to_vec()),
+        )?;
+    }
+
+    let digits = crate::util::extract_digits(input);
+    if (6..=8).contains(&digits.len()) {
+        compare(
+            "expand_upce",
+            input,
+            reference::expand_upce(&digits),
+            expand_upce_to_upca(&digits)
+                .ok()
+                .map(|g| g.digits().to_vec()),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Run `iterations` generated inputs from `seed`, stopping at the first mismatch
+pub fn run(seed: u64, iterations: usize) -> Result<(), Mismatch> {
+    let mut generator = Generator::new(seed);
+    (0..iterations).try_for_each(|_| check_input(&generator.input()))
+}
+
+#[cfg(test)]
+pub mod tests;
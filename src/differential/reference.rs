@@ -0,0 +1,125 @@
+//! The reference implementation. It favours being obviously correct over being fast:
+//! everything goes through strings and spelled-out tables.
+
+use crate::{GtinKind, GTIN};
+
+/// Mod-10 check digit with weights spelled out from the left
+pub fn check_digit(body: &[u8]) -> u8 {
+    let mut sum = 0u32;
+    for (i, &digit) in body.iter().enumerate() {
+        // The digit next to the check digit always has weight 3
+        let distance_from_check = body.len() - i;
+        let weight = if distance_from_check % 2 == 1 { 3 } else { 1 };
+        sum += digit as u32 * weight;
+    }
+    let remainder = sum % 10;
+    if remainder == 0 {
+        0
+    } else {
+        (10 - remainder) as u8
+    }
+}
+
+pub fn parse(input: &str) -> Option<GTIN> {
+    // Symbology identifiers are a ']', a letter and a modifier character
+    let trimmed = input.trim_start();
+    let (identifier, data) = match trimmed.as_bytes() {
+        [b']', code, modifier, ..]
+            if code.is_ascii_alphabetic() && modifier.is_ascii_alphanumeric() =>
+        {
+            (&trimmed[..3], &trimmed[3..])
+        }
+        _ => ("", input),
+    };
+
+    let mut text: String = data.chars().filter(|c| c.is_ascii_digit()).collect();
+    if ["]C1", "]e0", "]d2", "]Q3"].contains(&identifier)
+        && text.len() == 16
+        && text.starts_with("01")
+    {
+        text = text[2..].to_string();
+    }
+    let mut digits: Vec<u8> = text.bytes().map(|b| b - b'0').collect();
+    if digits.len() < 8 || digits.len() > 14 {
+        return None;
+    }
+    if identifier == "]E0" && digits.len() == 8 {
+        return match upce_check_matches(&digits) {
+            true => Some(GTIN::UpcE(digits.try_into().ok()?)),
+            false => None,
+        };
+    }
+    let (body, check) = digits.split_at(digits.len() - 1);
+    if check_digit(body) != check[0] {
+        return match digits.len() == 8 && identifier != "]E4" && upce_check_matches(&digits) {
+            true => Some(GTIN::UpcE(digits.try_into().ok()?)),
+            false => None,
+        };
+    }
+
+    match digits.len() {
+        8 if identifier == "]E4" => Some(GTIN::Ean8(digits.try_into().ok()?)),
+        8 if text.starts_with('0') || !upce_check_matches(&digits) => {
+            Some(GTIN::Ean8(digits.try_into().ok()?))
+        }
+        8 => Some(GTIN::UpcE(digits.try_into().ok()?)),
+        11 => {
+            digits.insert(0, 0);
+            Some(GTIN::UpcA(digits.try_into().ok()?))
+        }
+        12 => Some(GTIN::UpcA(digits.try_into().ok()?)),
+        13 => Some(GTIN::Ean13(digits.try_into().ok()?)),
+        14 => Some(GTIN::Gtin14(digits.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+pub fn as_ean13(gtin: &GTIN) -> Option<Vec<u8>> {
+    let digits = gtin.digits();
+    match gtin.kind() {
+        GtinKind::Ean13 => Some(digits.to_vec()),
+        GtinKind::UpcA => {
+            let mut padded = vec![0];
+            padded.extend_from_slice(digits);
+            Some(padded)
+        }
+        GtinKind::UpcE => {
+            let mut padded = vec![0];
+            padded.extend(expand_upce(digits)?);
+            Some(padded)
+        }
+        GtinKind::Gtin14 if digits[0] == 0 => Some(digits[1..].to_vec()),
+        _ => None,
+    }
+}
+
+/// Whether the UPC-E check digit is the one of the expanded UPC-A
+fn upce_check_matches(upce: &[u8]) -> bool {
+    match expand_upce(upce) {
+        Some(upca) => upca[11] == upce[7],
+        None => false,
+    }
+}
+
+/// UPC-E to UPC-A using the textbook table from the GS1 General Specifications
+pub fn expand_upce(upce: &[u8]) -> Option<Vec<u8>> {
+    let (ns, middle) = match upce.len() {
+        6 | 7 => (0, &upce[..6]),
+        8 if upce[0] <= 1 => (upce[0], &upce[1..7]),
+        _ => return None,
+    };
+    let body: String = middle.iter().map(|d| (b'0' + d) as char).collect();
+    let x: Vec<&str> = (0..6).map(|i| &body[i..i + 1]).collect();
+
+    let expanded = match x[5] {
+        "0" | "1" | "2" => format!("0{}{}{}0000{}{}{}", x[0], x[1], x[5], x[2], x[3], x[4]),
+        "3" => format!("0{}{}{}00000{}{}", x[0], x[1], x[2], x[3], x[4]),
+        "4" => format!("0{}{}{}{}00000{}", x[0], x[1], x[2], x[3], x[4]),
+        _ => format!("0{}{}{}{}{}0000{}", x[0], x[1], x[2], x[3], x[4], x[5]),
+    };
+
+    let mut digits: Vec<u8> = expanded.bytes().map(|b| b - b'0').collect();
+    digits[0] = ns;
+    digits.push(check_digit(&digits));
+    Some(digits)
+}
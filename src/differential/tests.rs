@@ -0,0 +1,28 @@
+use super::{check_input, run, Generator};
+
+#[test]
+fn generator_is_deterministic() {
+    let mut a = Generator::new(42);
+    let mut b = Generator::new(42);
+    for _ in 0..100 {
+        assert_eq!(a.input(), b.input());
+    }
+}
+
+#[test]
+fn known_inputs_agree() {
+    for input in ["0 71720 53977 4", "71720 53977 4", "04182635", "5201 3485"] {
+        if let Err(mismatch) = check_input(input) {
+            panic!("{}", mismatch);
+        }
+    }
+}
+
+#[test]
+fn differential_against_reference() {
+    for seed in 0..8 {
+        if let Err(mismatch) = run(seed, 10_000) {
+            panic!("seed {}: {}", seed, mismatch);
+        }
+    }
+}
@@ -0,0 +1,450 @@
+//! GS1 DataBar Omnidirectional, Truncated and Limited (ISO/IEC 24724). All three carry
+//! a GTIN-14 as element widths; Truncated is Omnidirectional printed at reduced height.
+//! Symbols start with a space, and element widths are in modules.
+
+use super::Pattern;
+use crate::util::calculate_checksum_digit;
+use crate::GTIN;
+
+/// Elements in an Omnidirectional or Truncated symbol (96 modules)
+pub const OMNIDIRECTIONAL_ELEMENTS: usize = 46;
+/// Elements in a Limited symbol (79 modules, including the trailing space)
+pub const LIMITED_ELEMENTS: usize = 47;
+
+/// DataBar variants that encode a GTIN alone
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DataBar {
+    Omnidirectional,
+    Truncated,
+    Limited, // indicator digit 0 or 1 only
+}
+
+impl DataBar {
+    /// Minimum symbol height in modules
+    pub fn min_height(self) -> u32 {
+        match self {
+            DataBar::Omnidirectional => 33,
+            DataBar::Truncated => 13,
+            DataBar::Limited => 10,
+        }
+    }
+}
+
+// Omnidirectional data character groups: 0-4 outside, 5-8 inside (Tables 1 and 2)
+const OMNI_G_SUM: [u32; 9] = [0, 161, 961, 2015, 2715, 0, 336, 1036, 1516];
+const OMNI_T: [u32; 9] = [1, 10, 34, 70, 126, 4, 20, 48, 81];
+const OMNI_ODD_MODULES: [u8; 9] = [12, 10, 8, 6, 4, 5, 7, 9, 11];
+const OMNI_EVEN_MODULES: [u8; 9] = [4, 6, 8, 10, 12, 10, 8, 6, 4];
+const OMNI_WIDEST_ODD: [u8; 9] = [8, 6, 4, 3, 1, 2, 4, 6, 8];
+
+const OMNI_FINDERS: [[u8; 5]; 9] = [
+    [3, 8, 2, 1, 1],
+    [3, 5, 5, 1, 1],
+    [3, 3, 7, 1, 1],
+    [3, 1, 9, 1, 1],
+    [2, 7, 4, 1, 1],
+    [2, 5, 6, 1, 1],
+    [2, 3, 8, 1, 1],
+    [1, 5, 7, 1, 1],
+    [1, 3, 9, 1, 1],
+];
+
+const OMNI_CHECKSUM_WEIGHTS: [[u32; 8]; 4] = [
+    [1, 3, 9, 27, 2, 6, 18, 54],
+    [4, 12, 36, 29, 8, 24, 72, 58],
+    [16, 48, 65, 37, 32, 17, 51, 74],
+    [64, 34, 23, 69, 49, 68, 46, 59],
+];
+
+// Limited data character groups (Table 6)
+const LIMITED_G_SUM: [u32; 7] = [0, 183064, 820064, 1000776, 1491021, 1979845, 1996939];
+const LIMITED_T_EVEN: [u32; 7] = [28, 728, 6454, 203, 2408, 1, 16632];
+const LIMITED_ODD_MODULES: [u8; 7] = [17, 13, 9, 15, 11, 19, 7];
+const LIMITED_WIDEST_ODD: [u8; 7] = [6, 5, 3, 5, 4, 8, 1];
+
+const LIMITED_CHECKSUM_WEIGHTS: [[u32; 14]; 2] = [
+    [1, 3, 9, 27, 81, 65, 17, 51, 64, 14, 42, 37, 22, 66],
+    [20, 60, 2, 6, 18, 54, 73, 41, 34, 13, 39, 28, 84, 74],
+];
+
+/// Limited check character patterns, indexed by checksum (Annex C)
+#[rustfmt::skip]
+const LIMITED_CHECK_CHARACTERS: [[u8; 14]; 89] = [
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1], [1, 1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 2, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 1, 3, 3, 1, 1, 1], [1, 1, 1, 1, 1, 1, 1, 2, 1, 1, 3, 2, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1, 1], [1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 3, 1, 1, 1],
+    [1, 1, 1, 1, 1, 2, 1, 1, 1, 1, 3, 2, 1, 1], [1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 3, 1, 1, 1],
+    [1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 3, 1, 1, 1], [1, 1, 1, 1, 1, 3, 1, 1, 1, 1, 3, 1, 1, 1],
+    [1, 1, 1, 2, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1], [1, 1, 1, 2, 1, 1, 1, 1, 1, 2, 3, 1, 1, 1],
+    [1, 1, 1, 2, 1, 1, 1, 2, 1, 1, 3, 1, 1, 1], [1, 1, 1, 2, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1],
+    [1, 1, 1, 3, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1], [1, 2, 1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1],
+    [1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 1, 1], [1, 2, 1, 1, 1, 1, 1, 2, 1, 1, 3, 1, 1, 1],
+    [1, 2, 1, 1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 1], [1, 2, 1, 2, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1],
+    [1, 3, 1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 1], [1, 1, 1, 1, 1, 1, 1, 1, 2, 1, 2, 3, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1], [1, 1, 1, 1, 1, 1, 1, 1, 2, 3, 2, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 2, 2, 1, 1], [1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 2, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 2, 1, 1, 1], [1, 1, 1, 1, 1, 2, 1, 1, 2, 1, 2, 2, 1, 1],
+    [1, 1, 1, 1, 1, 2, 1, 1, 2, 2, 2, 1, 1, 1], [1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 2, 1, 1, 1],
+    [1, 1, 1, 1, 1, 3, 1, 1, 2, 1, 2, 1, 1, 1], [1, 1, 1, 2, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1],
+    [1, 1, 1, 2, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1], [1, 1, 1, 2, 1, 1, 1, 2, 2, 1, 2, 1, 1, 1],
+    [1, 1, 1, 2, 1, 2, 1, 1, 2, 1, 2, 1, 1, 1], [1, 1, 1, 3, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1],
+    [1, 2, 1, 1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1], [1, 2, 1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1, 1],
+    [1, 2, 1, 1, 1, 1, 1, 2, 2, 1, 2, 1, 1, 1], [1, 2, 1, 1, 1, 2, 1, 1, 2, 1, 2, 1, 1, 1],
+    [1, 2, 1, 2, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1], [1, 3, 1, 1, 1, 1, 1, 1, 2, 1, 2, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 1, 3, 1, 1, 3, 1, 1], [1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 2, 1, 1],
+    [1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 1, 2, 1, 1], [1, 1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1],
+    [1, 2, 1, 1, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1], [1, 1, 1, 1, 1, 1, 2, 1, 1, 1, 2, 3, 1, 1],
+    [1, 1, 1, 1, 1, 1, 2, 1, 1, 2, 2, 2, 1, 1], [1, 1, 1, 1, 1, 1, 2, 1, 1, 3, 2, 1, 1, 1],
+    [1, 1, 1, 1, 1, 1, 2, 2, 1, 1, 2, 2, 1, 1], [1, 1, 1, 2, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1],
+    [1, 1, 1, 2, 1, 1, 2, 1, 1, 2, 2, 1, 1, 1], [1, 1, 1, 2, 1, 1, 2, 2, 1, 1, 2, 1, 1, 1],
+    [1, 1, 1, 2, 1, 2, 2, 1, 1, 1, 2, 1, 1, 1], [1, 1, 1, 3, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1],
+    [1, 2, 1, 1, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1], [1, 2, 1, 1, 1, 1, 2, 1, 1, 2, 2, 1, 1, 1],
+    [1, 2, 1, 2, 1, 1, 2, 1, 1, 1, 2, 1, 1, 1], [1, 1, 1, 1, 2, 1, 1, 1, 1, 1, 2, 3, 1, 1],
+    [1, 1, 1, 1, 2, 1, 1, 1, 1, 2, 2, 2, 1, 1], [1, 1, 1, 1, 2, 1, 1, 1, 1, 3, 2, 1, 1, 1],
+    [1, 1, 1, 1, 2, 1, 1, 2, 1, 1, 2, 2, 1, 1], [1, 1, 1, 1, 2, 1, 1, 2, 1, 2, 2, 1, 1, 1],
+    [1, 1, 1, 1, 2, 2, 1, 1, 1, 1, 2, 2, 1, 1], [1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 2, 2, 1, 1],
+    [1, 2, 1, 1, 2, 1, 1, 1, 1, 2, 2, 1, 1, 1], [1, 2, 1, 1, 2, 1, 1, 2, 1, 1, 2, 1, 1, 1],
+    [1, 2, 1, 1, 2, 2, 1, 1, 1, 1, 2, 1, 1, 1], [1, 2, 1, 2, 2, 1, 1, 1, 1, 1, 2, 1, 1, 1],
+    [1, 3, 1, 1, 2, 1, 1, 1, 1, 1, 2, 1, 1, 1], [1, 1, 2, 1, 1, 1, 1, 1, 1, 1, 2, 3, 1, 1],
+    [1, 1, 2, 1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1], [1, 1, 2, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1, 1],
+    [1, 1, 2, 1, 1, 1, 1, 2, 1, 1, 2, 2, 1, 1], [1, 1, 2, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1, 1],
+    [1, 1, 2, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1, 1], [1, 1, 2, 1, 1, 2, 1, 1, 1, 1, 2, 2, 1, 1],
+    [1, 1, 2, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1, 1], [1, 1, 2, 2, 1, 1, 1, 1, 1, 1, 2, 2, 1, 1],
+    [2, 1, 1, 1, 1, 1, 1, 1, 1, 2, 2, 2, 1, 1], [2, 1, 1, 1, 1, 1, 1, 1, 1, 3, 2, 1, 1, 1],
+    [2, 1, 1, 1, 1, 1, 1, 2, 1, 1, 2, 2, 1, 1], [2, 1, 1, 1, 1, 1, 1, 2, 1, 2, 2, 1, 1, 1],
+    [2, 1, 1, 1, 1, 1, 1, 3, 1, 1, 2, 1, 1, 1], [2, 1, 1, 1, 1, 2, 1, 1, 1, 2, 2, 1, 1, 1],
+    [2, 1, 1, 1, 1, 2, 1, 2, 1, 1, 2, 1, 1, 1], [2, 1, 1, 2, 1, 1, 1, 1, 1, 2, 2, 1, 1, 1],
+    [2, 1, 1, 1, 1, 1, 1, 1, 2, 2, 1, 2, 1, 1],
+];
+
+/// Element widths of a DataBar symbol for the GTIN, or `None` for a Limited symbol
+/// whose indicator digit is not 0 or 1
+pub fn databar_widths(gtin: &GTIN, variant: DataBar) -> Option<Vec<u8>> {
+    let value = gtin.canonical_key() / 10; // the check digit is not encoded
+    match variant {
+        DataBar::Omnidirectional | DataBar::Truncated => {
+            Some(omnidirectional_widths(value).to_vec())
+        }
+        DataBar::Limited => limited_widths(value).map(|widths| widths.to_vec()),
+    }
+}
+
+/// Encode a DataBar symbol for the GTIN
+pub fn encode_databar(gtin: &GTIN, variant: DataBar) -> Option<Pattern> {
+    let mut pattern = Pattern::default();
+    for (i, &width) in databar_widths(gtin, variant)?.iter().enumerate() {
+        pattern.push(&vec![i % 2 == 1; width as usize], false);
+    }
+    Some(pattern)
+}
+
+/// Recover the GTIN-14 from the element widths of an Omnidirectional, Truncated or
+/// Limited symbol, verifying the check character and finder patterns
+pub fn decode_databar(widths: &[u8]) -> Option<GTIN> {
+    let value = match widths.len() {
+        OMNIDIRECTIONAL_ELEMENTS => {
+            let value = omnidirectional_value(widths)?;
+            (omnidirectional_widths(value)[..] == *widths).then_some(value)?
+        }
+        LIMITED_ELEMENTS => {
+            let value = limited_value(widths)?;
+            (limited_widths(value)?[..] == *widths).then_some(value)?
+        }
+        _ => return None,
+    };
+
+    let mut digits = [0; 14];
+    let mut remaining = value;
+    for digit in digits[..13].iter_mut().rev() {
+        *digit = (remaining % 10) as u8;
+        remaining /= 10;
+    }
+    digits[13] = calculate_checksum_digit(&digits[..13]);
+    Some(GTIN::Gtin14(digits))
+}
+
+fn omnidirectional_widths(value: u64) -> [u8; OMNIDIRECTIONAL_ELEMENTS] {
+    let (left, right) = (value / 4537077, value % 4537077);
+    let characters = [left / 1597, left % 1597, right / 1597, right % 1597];
+
+    let mut data = [[0; 8]; 4];
+    for (i, &character) in characters.iter().enumerate() {
+        let outside = i % 2 == 0; // characters 1 and 3 are next to the finders
+        let character = character as u32;
+        let group = omnidirectional_group(character, outside);
+        let v = character - OMNI_G_SUM[group];
+        let (div, rem) = (v / OMNI_T[group], v % OMNI_T[group]);
+        let (odd, even) = if outside { (div, rem) } else { (rem, div) };
+        interleave(
+            &mut data[i],
+            (odd, OMNI_ODD_MODULES[group]),
+            (even, OMNI_EVEN_MODULES[group]),
+            OMNI_WIDEST_ODD[group],
+            !outside,
+        );
+    }
+
+    let mut checksum = 0;
+    for (widths, weights) in data.iter().zip(OMNI_CHECKSUM_WEIGHTS) {
+        for (&width, weight) in widths.iter().zip(weights) {
+            checksum += width as u32 * weight;
+        }
+    }
+    // 79 values are spread over 81 finder pairs, skipping the two that read ambiguously
+    let mut checksum = checksum % 79;
+    if checksum >= 8 {
+        checksum += 1;
+    }
+    if checksum >= 72 {
+        checksum += 1;
+    }
+    let (left_finder, right_finder) = (
+        OMNI_FINDERS[checksum as usize / 9],
+        OMNI_FINDERS[checksum as usize % 9],
+    );
+
+    let mut widths = [1; OMNIDIRECTIONAL_ELEMENTS];
+    for i in 0..8 {
+        widths[i + 2] = data[0][i];
+        widths[i + 15] = data[1][7 - i];
+        widths[i + 23] = data[3][i];
+        widths[i + 36] = data[2][7 - i];
+    }
+    for i in 0..5 {
+        widths[i + 10] = left_finder[i];
+        widths[i + 31] = right_finder[4 - i];
+    }
+    widths
+}
+
+fn omnidirectional_value(widths: &[u8]) -> Option<u64> {
+    let mut data = [[0; 8]; 4];
+    for i in 0..8 {
+        data[0][i] = widths[i + 2];
+        data[1][7 - i] = widths[i + 15];
+        data[3][i] = widths[i + 23];
+        data[2][7 - i] = widths[i + 36];
+    }
+
+    let mut characters = [0u64; 4];
+    for (i, character) in characters.iter_mut().enumerate() {
+        let outside = i % 2 == 0;
+        let (odd, even) = deinterleave(&data[i]);
+        let odd_modules: u8 = odd.iter().sum();
+        let groups = if outside { 0..5 } else { 5..9 };
+        let group = groups
+            .into_iter()
+            .find(|&g| OMNI_ODD_MODULES[g] == odd_modules)?;
+        let widest = OMNI_WIDEST_ODD[group];
+        let odd = element_value(&odd, widest, !outside);
+        let even = element_value(&even, 9 - widest, outside);
+        let (div, rem) = if outside { (odd, even) } else { (even, odd) };
+        *character = (OMNI_G_SUM[group] + div * OMNI_T[group] + rem) as u64;
+    }
+
+    let left = characters[0] * 1597 + characters[1];
+    let right = characters[2] * 1597 + characters[3];
+    let value = left * 4537077 + right;
+    (value < 10_000_000_000_000).then_some(value)
+}
+
+fn omnidirectional_group(character: u32, outside: bool) -> usize {
+    let groups = if outside { 0..5 } else { 5..9 };
+    groups
+        .rev()
+        .find(|&g| character >= OMNI_G_SUM[g])
+        .unwrap_or(0)
+}
+
+fn limited_widths(value: u64) -> Option<[u8; LIMITED_ELEMENTS]> {
+    if value >= 2_000_000_000_000 {
+        return None;
+    }
+
+    let mut data = [[0; 14]; 2];
+    for (widths, character) in data.iter_mut().zip([value / 2013571, value % 2013571]) {
+        let character = character as u32;
+        let group = (0..7)
+            .rev()
+            .find(|&g| character >= LIMITED_G_SUM[g])
+            .unwrap_or(0);
+        let v = character - LIMITED_G_SUM[group];
+        let odd_modules = LIMITED_ODD_MODULES[group];
+        interleave(
+            widths,
+            (v / LIMITED_T_EVEN[group], odd_modules),
+            (v % LIMITED_T_EVEN[group], 26 - odd_modules),
+            LIMITED_WIDEST_ODD[group],
+            false,
+        );
+    }
+
+    let mut checksum = 0;
+    for (widths, weights) in data.iter().zip(LIMITED_CHECKSUM_WEIGHTS) {
+        for (&width, weight) in widths.iter().zip(weights) {
+            checksum += width as u32 * weight;
+        }
+    }
+    let check = LIMITED_CHECK_CHARACTERS[(checksum % 89) as usize];
+
+    let mut widths = [1; LIMITED_ELEMENTS];
+    widths[2..16].copy_from_slice(&data[0]);
+    widths[16..30].copy_from_slice(&check);
+    widths[30..44].copy_from_slice(&data[1]);
+    widths[46] = 5; // trailing space
+    Some(widths)
+}
+
+fn limited_value(widths: &[u8]) -> Option<u64> {
+    let mut characters = [0u64; 2];
+    for (character, start) in characters.iter_mut().zip([2, 30]) {
+        let (odd, even) = deinterleave(&widths[start..start + 14]);
+        let odd_modules: u8 = odd.iter().sum();
+        let group = (0..7).find(|&g| LIMITED_ODD_MODULES[g] == odd_modules)?;
+        let widest = LIMITED_WIDEST_ODD[group];
+        let odd = element_value(&odd, widest, false);
+        let even = element_value(&even, 9 - widest, true);
+        *character = (LIMITED_G_SUM[group] + odd * LIMITED_T_EVEN[group] + even) as u64;
+    }
+    Some(characters[0] * 2013571 + characters[1])
+}
+
+/// Fill a character's widths, alternating odd and even elements
+fn interleave(
+    widths: &mut [u8],
+    (odd_value, odd_modules): (u32, u8),
+    (even_value, even_modules): (u32, u8),
+    widest_odd: u8,
+    odd_no_narrow: bool,
+) {
+    let elements = widths.len() / 2;
+    let odd = element_widths(odd_value, odd_modules, elements, widest_odd, odd_no_narrow);
+    let even = element_widths(
+        even_value,
+        even_modules,
+        elements,
+        9 - widest_odd,
+        !odd_no_narrow,
+    );
+    for i in 0..elements {
+        widths[2 * i] = odd[i];
+        widths[2 * i + 1] = even[i];
+    }
+}
+
+fn deinterleave(widths: &[u8]) -> (Vec<u8>, Vec<u8>) {
+    let odd = widths.iter().step_by(2).copied().collect();
+    let even = widths.iter().skip(1).step_by(2).copied().collect();
+    (odd, even)
+}
+
+/// Number of combinations of `modules` left among the elements after `bar` that start
+/// with an element `width` wide, less those the width limits rule out (ISO/IEC 24724 Annex B)
+fn combinations_after(
+    modules: i64,
+    width: i64,
+    remaining: i64,
+    max_width: i64,
+    no_narrow: bool,
+    narrow_seen: bool,
+) -> i64 {
+    let mut count = combinations(modules - width - 1, remaining - 1);
+    if no_narrow && !narrow_seen && modules - width - remaining >= remaining {
+        count -= combinations(modules - width - remaining - 1, remaining - 1);
+    }
+    if remaining > 1 {
+        let mut too_wide = 0;
+        let mut widest = modules - width - (remaining - 1);
+        while widest > max_width {
+            too_wide += combinations(modules - width - widest - 1, remaining - 2);
+            widest -= 1;
+        }
+        count -= too_wide * remaining;
+    } else if modules - width > max_width {
+        count -= 1;
+    }
+    count
+}
+
+/// The `value`th pattern of `elements` widths summing to `modules`
+fn element_widths(
+    value: u32,
+    modules: u8,
+    elements: usize,
+    max_width: u8,
+    no_narrow: bool,
+) -> Vec<u8> {
+    let mut widths = Vec::with_capacity(elements);
+    let mut value = value as i64;
+    let mut modules = modules as i64;
+    let mut narrow_seen = false;
+    for bar in 0..elements - 1 {
+        let remaining = (elements - bar - 1) as i64;
+        let mut width = 1;
+        loop {
+            let count = combinations_after(
+                modules,
+                width,
+                remaining,
+                max_width as i64,
+                no_narrow,
+                narrow_seen || width == 1,
+            );
+            if value < count {
+                break;
+            }
+            value -= count;
+            width += 1;
+        }
+        narrow_seen |= width == 1;
+        modules -= width;
+        widths.push(width as u8);
+    }
+    widths.push(modules as u8);
+    widths
+}
+
+/// The inverse of `element_widths`
+fn element_value(widths: &[u8], max_width: u8, no_narrow: bool) -> u32 {
+    let mut modules: i64 = widths.iter().map(|&w| w as i64).sum();
+    let mut value = 0;
+    let mut narrow_seen = false;
+    for (bar, &width) in widths[..widths.len() - 1].iter().enumerate() {
+        let remaining = (widths.len() - bar - 1) as i64;
+        for smaller in 1..width as i64 {
+            value += combinations_after(
+                modules,
+                smaller,
+                remaining,
+                max_width as i64,
+                no_narrow,
+                narrow_seen || smaller == 1,
+            );
+        }
+        narrow_seen |= width == 1;
+        modules -= width as i64;
+    }
+    value as u32
+}
+
+/// Binomial coefficient, computed as in the reference `combins` so that out of range
+/// arguments give the same (unused) results
+fn combinations(n: i64, r: i64) -> i64 {
+    let (min_denominator, max_denominator) = if n - r > r { (r, n - r) } else { (n - r, r) };
+    let mut value = 1;
+    let mut j = 1;
+    let mut i = n;
+    while i > max_denominator {
+        value *= i;
+        if j <= min_denominator {
+            value /= j;
+            j += 1;
+        }
+        i -= 1;
+    }
+    while j <= min_denominator {
+        value /= j;
+        j += 1;
+    }
+    value
+}
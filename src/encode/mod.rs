@@ -0,0 +1,306 @@
+//! Bar/space module patterns for the EAN/UPC symbologies.
+//!
+//! A pattern is a sequence of modules (`true` for a bar, `false` for a space) together
+//! with a mask of the modules that belong to guard patterns, since those bars extend
+//! below the others when the symbol is printed.
+
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::util::expand_upce_to_upca;
+use crate::{GtinKind, GTIN};
+
+pub mod databar;
+
+/// Barcode symbologies the crate can produce module patterns for
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub enum Symbology {
+    Ean13,
+    Ean8,
+    UpcA,
+    UpcE,
+    Itf14,
+    DataBarOmnidirectional,
+    DataBarTruncated,
+    DataBarLimited,
+}
+
+/// Symbologies this build can encode, through `encode` or `databar::encode_databar`
+pub const SYMBOLOGIES: &[Symbology] = &[
+    Symbology::Ean13,
+    Symbology::Ean8,
+    Symbology::UpcA,
+    Symbology::UpcE,
+    Symbology::Itf14,
+    Symbology::DataBarOmnidirectional,
+    Symbology::DataBarTruncated,
+    Symbology::DataBarLimited,
+];
+
+/// Number sets used to encode a single symbol character
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Parity {
+    L, // odd parity, left half
+    G, // even parity, left half
+    R, // right half
+}
+
+const L_PATTERNS: [[u8; 7]; 10] = [
+    [0, 0, 0, 1, 1, 0, 1],
+    [0, 0, 1, 1, 0, 0, 1],
+    [0, 0, 1, 0, 0, 1, 1],
+    [0, 1, 1, 1, 1, 0, 1],
+    [0, 1, 0, 0, 0, 1, 1],
+    [0, 1, 1, 0, 0, 0, 1],
+    [0, 1, 0, 1, 1, 1, 1],
+    [0, 1, 1, 1, 0, 1, 1],
+    [0, 1, 1, 0, 1, 1, 1],
+    [0, 0, 0, 1, 0, 1, 1],
+];
+
+/// Left-hand parities of EAN-13, selected by the (unencoded) first digit
+const FIRST_DIGIT_PARITIES: [[Parity; 6]; 10] = {
+    use Parity::{G, L};
+    [
+        [L, L, L, L, L, L],
+        [L, L, G, L, G, G],
+        [L, L, G, G, L, G],
+        [L, L, G, G, G, L],
+        [L, G, L, L, G, G],
+        [L, G, G, L, L, G],
+        [L, G, G, G, L, L],
+        [L, G, L, G, L, G],
+        [L, G, L, G, G, L],
+        [L, G, G, L, G, L],
+    ]
+};
+
+/// Parities of the six UPC-E data digits in number system 0, selected by the check digit.
+/// Number system 1 uses their complement.
+const UPCE_PARITIES: [[Parity; 6]; 10] = {
+    use Parity::{G, L};
+    [
+        [G, G, G, L, L, L],
+        [G, G, L, G, L, L],
+        [G, G, L, L, G, L],
+        [G, G, L, L, L, G],
+        [G, L, G, G, L, L],
+        [G, L, L, G, G, L],
+        [G, L, L, L, G, G],
+        [G, L, G, L, G, L],
+        [G, L, G, L, L, G],
+        [G, L, L, G, L, G],
+    ]
+};
+
+pub const START_GUARD: [bool; 3] = [true, false, true];
+pub const CENTER_GUARD: [bool; 5] = [false, true, false, true, false];
+pub const END_GUARD: [bool; 3] = [true, false, true];
+pub const UPCE_END_GUARD: [bool; 6] = [false, true, false, true, false, true];
+
+/// The 7-module pattern of a digit in the given number set
+pub fn digit_pattern(digit: u8, parity: Parity) -> [bool; 7] {
+    let l = L_PATTERNS[digit as usize % 10];
+    let mut pattern = [false; 7];
+    for (i, module) in pattern.iter_mut().enumerate() {
+        *module = match parity {
+            Parity::L => l[i] == 1,
+            Parity::R => l[i] == 0,     // R is the complement of L
+            Parity::G => l[6 - i] == 0, // G is R reversed
+        };
+    }
+    pattern
+}
+
+/// Parities of the six left-hand digits of an EAN-13 whose first digit is `first_digit`
+pub fn left_parities(first_digit: u8) -> [Parity; 6] {
+    FIRST_DIGIT_PARITIES[first_digit as usize % 10]
+}
+
+/// An encoded symbol: its modules and which of them belong to guard bars
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Pattern {
+    modules: Vec<bool>,
+    guards: Vec<bool>,
+}
+
+impl Pattern {
+    fn push(&mut self, modules: &[bool], guard: bool) {
+        self.modules.extend_from_slice(modules);
+        self.guards
+            .extend(std::iter::repeat_n(guard, modules.len()));
+    }
+
+    fn push_digit(&mut self, digit: u8, parity: Parity, guard: bool) {
+        self.push(&digit_pattern(digit, parity), guard);
+    }
+
+    /// Modules from left to right, `true` for a bar
+    pub fn modules(&self) -> &[bool] {
+        &self.modules
+    }
+
+    /// Whether each module belongs to a guard pattern (or, for UPC-A, to the outer digits
+    /// whose bars are extended like guards)
+    pub fn guards(&self) -> &[bool] {
+        &self.guards
+    }
+
+    pub fn len(&self) -> usize {
+        self.modules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.is_empty()
+    }
+
+    /// The pattern as a string of `1` (bar) and `0` (space)
+    pub fn to_bit_string(&self) -> String {
+        self.modules
+            .iter()
+            .map(|&bar| if bar { '1' } else { '0' })
+            .collect()
+    }
+}
+
+/// Encode an EAN-13 symbol (95 modules)
+pub fn encode_ean13(digits: &[u8; 13]) -> Pattern {
+    encode_ean13_like(digits, false)
+}
+
+/// Encode a UPC-A symbol (95 modules). It is an EAN-13 with an implied leading zero,
+/// but the bars of the first and last digits are extended like guards.
+pub fn encode_upca(digits: &[u8; 12]) -> Pattern {
+    let mut ean13 = [0; 13];
+    ean13[1..].copy_from_slice(digits);
+    encode_ean13_like(&ean13, true)
+}
+
+fn encode_ean13_like(digits: &[u8; 13], upca: bool) -> Pattern {
+    let mut pattern = Pattern::default();
+    pattern.push(&START_GUARD, true);
+    for (i, (&digit, parity)) in digits[1..7]
+        .iter()
+        .zip(left_parities(digits[0]))
+        .enumerate()
+    {
+        pattern.push_digit(digit, parity, upca && i == 0);
+    }
+    pattern.push(&CENTER_GUARD, true);
+    for (i, &digit) in digits[7..].iter().enumerate() {
+        pattern.push_digit(digit, Parity::R, upca && i == 5);
+    }
+    pattern.push(&END_GUARD, true);
+    pattern
+}
+
+/// Encode a UPC-E symbol (51 modules). The number system and check digit are not
+/// printed as bars but carried by the parities of the six data digits, so only number
+/// systems 0 and 1 can be encoded.
+pub fn encode_upce(digits: &[u8; 8]) -> Option<Pattern> {
+    let number_system = digits[0];
+
+    // The check digit belongs to the expanded UPC-A, which fails for other number systems
+    let upca = expand_upce_to_upca(digits).ok()?;
+    let check_digit = upca.digits()[11];
+
+    let parities = UPCE_PARITIES[check_digit as usize];
+    let parities = parities.map(|parity| match (number_system, parity) {
+        (0, parity) => parity,
+        (_, Parity::L) => Parity::G,
+        (_, _) => Parity::L,
+    });
+
+    let mut pattern = Pattern::default();
+    pattern.push(&START_GUARD, true);
+    for (&digit, parity) in digits[1..7].iter().zip(parities) {
+        pattern.push_digit(digit, parity, false);
+    }
+    pattern.push(&UPCE_END_GUARD, true);
+    Some(pattern)
+}
+
+/// Encode an EAN-8 symbol (67 modules)
+pub fn encode_ean8(digits: &[u8; 8]) -> Pattern {
+    let mut pattern = Pattern::default();
+    pattern.push(&START_GUARD, true);
+    for &digit in &digits[..4] {
+        pattern.push_digit(digit, Parity::L, false);
+    }
+    pattern.push(&CENTER_GUARD, true);
+    for &digit in &digits[4..] {
+        pattern.push_digit(digit, Parity::R, false);
+    }
+    pattern.push(&END_GUARD, true);
+    pattern
+}
+
+/// Wide (`true`) and narrow elements of each digit in Interleaved 2 of 5
+const ITF_PATTERNS: [[bool; 5]; 10] = {
+    const N: bool = false;
+    const W: bool = true;
+    [
+        [N, N, W, W, N],
+        [W, N, N, N, W],
+        [N, W, N, N, W],
+        [W, W, N, N, N],
+        [N, N, W, N, W],
+        [W, N, W, N, N],
+        [N, W, W, N, N],
+        [N, N, N, W, W],
+        [W, N, N, W, N],
+        [N, W, N, W, N],
+    ]
+};
+
+/// Modules per wide ITF element. ITF-14 allows a wide:narrow ratio of 2.25 to 3.0;
+/// whole modules need the upper bound.
+pub const ITF_WIDE_MODULES: usize = 3;
+
+/// Modules in an ITF-14 symbol: start, 14 digits of 3 narrow and 2 wide elements, stop
+pub const ITF14_MODULES: usize = 4 + 14 * (3 + 2 * ITF_WIDE_MODULES) + (ITF_WIDE_MODULES + 2);
+
+/// Encode an ITF-14 symbol. Digits are taken in pairs, the first encoded in the bars
+/// and the second in the interleaved spaces.
+pub fn encode_itf14(digits: &[u8; 14]) -> Pattern {
+    let mut pattern = Pattern::default();
+    let mut element = |wide: bool, bar: bool| {
+        let width = if wide { ITF_WIDE_MODULES } else { 1 };
+        pattern.push(&vec![bar; width], false);
+    };
+
+    // Start: narrow bar, narrow space, narrow bar, narrow space
+    for bar in [true, false, true, false] {
+        element(false, bar);
+    }
+    for pair in digits.chunks(2) {
+        let bars = ITF_PATTERNS[pair[0] as usize % 10];
+        let spaces = ITF_PATTERNS[pair[1] as usize % 10];
+        for (wide_bar, wide_space) in bars.into_iter().zip(spaces) {
+            element(wide_bar, true);
+            element(wide_space, false);
+        }
+    }
+    // Stop: wide bar, narrow space, narrow bar
+    element(true, true);
+    element(false, false);
+    element(false, true);
+    pattern
+}
+
+/// Encode a GTIN in the symbology it is normally printed with, if supported
+pub fn encode(gtin: &GTIN) -> Option<Pattern> {
+    // The digit count follows from the kind, so the conversions cannot fail
+    let digits = gtin.digits();
+    match gtin.kind() {
+        GtinKind::Ean13 => Some(encode_ean13(digits.try_into().ok()?)),
+        GtinKind::UpcA => Some(encode_upca(digits.try_into().ok()?)),
+        GtinKind::Ean8 => Some(encode_ean8(digits.try_into().ok()?)),
+        GtinKind::Gtin14 => Some(encode_itf14(digits.try_into().ok()?)),
+        GtinKind::UpcE => encode_upce(digits.try_into().ok()?),
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,158 @@
+use super::databar::{databar_widths, decode_databar, encode_databar, DataBar};
+use super::{digit_pattern, encode, encode_upce, left_parities, Parity};
+use crate::{GtinKind, GTIN};
+
+#[test]
+fn number_sets() {
+    let bits = |pattern: [bool; 7]| -> String {
+        pattern.iter().map(|&b| if b { '1' } else { '0' }).collect()
+    };
+    assert_eq!(bits(digit_pattern(0, Parity::L)), "0001101");
+    assert_eq!(bits(digit_pattern(0, Parity::G)), "0100111");
+    assert_eq!(bits(digit_pattern(0, Parity::R)), "1110010");
+    assert_eq!(bits(digit_pattern(6, Parity::G)), "0000101");
+    assert_eq!(bits(digit_pattern(9, Parity::R)), "1110100");
+}
+
+#[test]
+fn first_digit_parities() {
+    let expected = [
+        "LLLLLL", "LLGLGG", "LLGGLG", "LLGGGL", "LGLLGG", "LGGLLG", "LGGGLL", "LGLGLG", "LGLGGL",
+        "LGGLGL",
+    ];
+    for (first_digit, expected) in (0..10).zip(expected) {
+        let parities: String = left_parities(first_digit)
+            .iter()
+            .map(|parity| format!("{:?}", parity))
+            .collect();
+        assert_eq!(parities, expected, "first digit {}", first_digit);
+    }
+}
+
+#[test]
+fn encode_symbols() {
+    let cases = vec![
+        (
+            "8595701 530526", // EAN-13, first digit 8 selects LGLGGL
+            "10101100010010111011000100100010100111001100101010100111010000101110010100111011011001010000101",
+        ),
+        (
+            "6901234 567892", // EAN-13, first digit 6 selects LGGGLL
+            "10100010110100111011001100110110111101010001101010100111010100001000100100100011101001101100101",
+        ),
+        (
+            "0 71720 53977 4", // UPC-A
+            "10100011010111011001100101110110010011000110101010100111010000101110100100010010001001011100101",
+        ),
+        (
+            "5201 3485", // EAN-8
+            "1010110001001001100011010011001010101000010101110010010001001110101",
+        ),
+        (
+            "0 0071720 53977 4", // ITF-14, 3:1 wide to narrow
+            "101010101110001110001010101110111000100011101010100011100010111010001000111011100010001110101010111010111000100010101000111011100011101",
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        let gtin = match gtin.kind() {
+            // EAN-8 with a leading 5 parses as UPC-E
+            GtinKind::UpcE => GTIN::Ean8(gtin.digits().try_into().unwrap()),
+            _ => gtin,
+        };
+        let pattern = encode(&gtin).unwrap();
+        assert_eq!(
+            pattern.to_bit_string(),
+            expected,
+            "Failed to encode {}",
+            input
+        );
+        assert_eq!(pattern.guards().len(), pattern.len());
+    }
+}
+
+#[test]
+fn upca_outer_digits_are_extended() {
+    let pattern = encode(&GTIN::try_from("0 71720 53977 4").unwrap()).unwrap();
+    let extended: Vec<usize> = (0..pattern.len())
+        .filter(|&i| pattern.guards()[i])
+        .collect();
+    // Start guard and first digit, center guard, last digit and end guard
+    let expected: Vec<usize> = (0..10).chain(45..50).chain(85..95).collect();
+    assert_eq!(extended, expected);
+}
+
+#[test]
+fn encode_upce_parities() {
+    let cases = vec![
+        (
+            [0, 4, 2, 5, 2, 6, 1, 4], // expands to 0 42100 00526 4, parities GLGGLL
+            "101001110100100110111001001101101011110011001010101",
+        ),
+        (
+            [1, 2, 3, 4, 5, 6, 5, 6], // number system 1 inverts the parities
+            "101001001101000010011101011100101011110110001010101",
+        ),
+        (
+            [0, 6, 5, 4, 3, 2, 4, 0], // check digit 0, parities GGGLLL
+            "101000010101110010011101011110100100110100011010101",
+        ),
+        (
+            [1, 2, 3, 4, 5, 6, 7, 0], // check digit 0 in number system 1, parities LLLGGG
+            "101001001101111010100011011100100001010010001010101",
+        ),
+    ];
+
+    for (digits, expected) in cases {
+        let pattern = encode(&GTIN::UpcE(digits)).unwrap();
+        assert_eq!(pattern.to_bit_string(), expected, "{:?}", digits);
+    }
+
+    // Only number systems 0 and 1 have a UPC-E symbol
+    assert_eq!(encode_upce(&[5, 2, 0, 1, 3, 4, 8, 5]), None);
+}
+
+#[test]
+fn encode_databar_symbols() {
+    let gtin = GTIN::try_from("0 0071720 53977 4").unwrap();
+    let cases = vec![
+        (
+            DataBar::Omnidirectional,
+            "010101001000000001011111000000010100010100001000101001111010000101111111100011010000111000010101",
+        ),
+        (
+            DataBar::Limited,
+            "0101010100000010111000100001010110110100100101010011100010011111011011010100000",
+        ),
+    ];
+
+    for (variant, expected) in cases {
+        let pattern = encode_databar(&gtin, variant).unwrap();
+        assert_eq!(pattern.to_bit_string(), expected, "{:?}", variant);
+    }
+    assert_eq!(
+        encode_databar(&gtin, DataBar::Truncated),
+        encode_databar(&gtin, DataBar::Omnidirectional)
+    );
+
+    // Limited only carries indicator digits 0 and 1
+    let gtin = GTIN::try_from("2 0012345 67890 9").unwrap();
+    assert_eq!(encode_databar(&gtin, DataBar::Limited), None);
+}
+
+#[test]
+fn decode_databar_widths() {
+    let gtin = GTIN::try_from("1 5012345 67890 7").unwrap();
+    for variant in [DataBar::Omnidirectional, DataBar::Limited] {
+        let mut widths = databar_widths(&gtin, variant).unwrap();
+        assert_eq!(decode_databar(&widths), Some(gtin), "{:?}", variant);
+
+        // Moving a module between a bar and a space breaks the check character
+        widths[2] += 1;
+        widths[3] -= 1;
+        assert_eq!(decode_databar(&widths), None, "{:?}", variant);
+    }
+
+    assert_eq!(decode_databar(&[1; 10]), None);
+}
@@ -0,0 +1,55 @@
+use core::fmt::{Display, Formatter};
+
+use crate::GtinKind;
+
+/// Reasons a GTIN could not be constructed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum GtinError {
+    InvalidChecksum,
+    UnsupportedLength(usize), // no GTIN kind has this many digits
+    InvalidLength { kind: GtinKind, found: usize }, // wrong digit count for the requested kind
+    InvalidDigit(u8),         // digit value outside 0..=9
+    InvalidPadding,           // a fixed-width field did not follow its padding policy
+}
+
+impl Display for GtinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            GtinError::InvalidChecksum => write!(f, "Invalid GTIN checksum"),
+            GtinError::UnsupportedLength(_) => write!(f, "Unsupported GTIN length"),
+            GtinError::InvalidLength { kind, found } => write!(
+                f,
+                "{:?} requires {} digits, found {}",
+                kind,
+                kind.digit_count(),
+                found
+            ),
+            GtinError::InvalidDigit(digit) => write!(f, "Invalid digit value: {}", digit),
+            GtinError::InvalidPadding => write!(f, "Invalid padding in fixed-width GTIN"),
+        }
+    }
+}
+
+impl core::error::Error for GtinError {}
+
+/// Reasons a GTIN could not be converted to another kind
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ConversionError {
+    NotRepresentable { from: GtinKind, to: GtinKind }, // e.g. EAN-8 has no EAN-13 form
+    NonZeroIndicator(u8), // a GTIN-14 for a packaging level other than the item itself
+}
+
+impl Display for ConversionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ConversionError::NotRepresentable { from, to } => {
+                write!(f, "{:?} cannot be represented as {:?}", from, to)
+            }
+            ConversionError::NonZeroIndicator(indicator) => {
+                write!(f, "GTIN-14 has indicator digit {}, not 0", indicator)
+            }
+        }
+    }
+}
+
+impl core::error::Error for ConversionError {}
@@ -0,0 +1,95 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::util::{digits_to_string, extract_digits};
+use crate::GTIN;
+
+/// A GTIN that remembers how many digits it was received with, so it can be echoed back byte-exact
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ExactGtin {
+    gtin: GTIN,
+    width: usize,
+}
+
+impl ExactGtin {
+    /// Pair a GTIN with the width it should be written back with
+    pub fn with_width(gtin: GTIN, width: usize) -> Result<Self, String> {
+        let digits = gtin.digits();
+        // Leading zeros can be added or dropped, significant digits cannot
+        let significant = digits.len() - digits.iter().take_while(|&&d| d == 0).count();
+        if width < significant || width > 14 {
+            return Err(format!("GTIN cannot be written with {} digits", width));
+        }
+        Ok(ExactGtin { gtin, width })
+    }
+
+    pub fn gtin(&self) -> &GTIN {
+        &self.gtin
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn into_inner(self) -> GTIN {
+        self.gtin
+    }
+
+    /// Replace the GTIN (e.g. after normalization) while keeping the original width
+    pub fn map<F>(self, f: F) -> Result<Self, String>
+    where
+        F: FnOnce(GTIN) -> GTIN,
+    {
+        ExactGtin::with_width(f(self.gtin), self.width)
+    }
+
+    /// The digits exactly as wide as they were received
+    pub fn to_digit_string(&self) -> String {
+        let digits = self.gtin.digits();
+        if self.width >= digits.len() {
+            let mut padded = vec![0; self.width - digits.len()];
+            padded.extend_from_slice(digits);
+            digits_to_string(&padded)
+        } else {
+            digits_to_string(&digits[digits.len() - self.width..])
+        }
+    }
+}
+
+impl std::convert::TryFrom<&str> for ExactGtin {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let gtin = GTIN::try_from(value).map_err(|e| e.to_string())?;
+        ExactGtin::with_width(gtin, extract_digits(value).len())
+    }
+}
+
+impl From<ExactGtin> for GTIN {
+    fn from(exact: ExactGtin) -> Self {
+        exact.gtin
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for ExactGtin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_digit_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for ExactGtin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde::deserialize_str(deserializer, |s| ExactGtin::try_from(s))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,39 @@
+use super::ExactGtin;
+use crate::GTIN;
+
+#[cfg(feature = "serde")]
+#[test]
+fn round_trip_preserves_width() {
+    let cases = vec![
+        "\"71720539774\"",    // UPC-A with the leading zero removed
+        "\"071720539774\"",   // UPC-A
+        "\"0071720539774\"",  // zero-padded UPC-A
+        "\"00071720539774\"", // GTIN-14 form of a UPC-A
+    ];
+
+    for data in cases {
+        let exact: ExactGtin = serde_json::from_str(data).unwrap();
+        assert_eq!(serde_json::to_string(&exact).unwrap(), data);
+    }
+}
+
+#[test]
+fn width_survives_normalization() {
+    let exact = ExactGtin::try_from("0071720539774").unwrap();
+    assert_eq!(
+        exact.gtin(),
+        &GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])
+    );
+
+    let normalized = exact
+        .map(|_| GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]))
+        .unwrap();
+    assert_eq!(normalized.to_digit_string(), "0071720539774");
+}
+
+#[test]
+fn reject_truncating_width() {
+    let gtin = GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+    assert!(ExactGtin::with_width(gtin, 12).is_err());
+    assert!(ExactGtin::with_width(gtin, 14).is_ok());
+}
@@ -0,0 +1,84 @@
+//! Mining free text such as invoices, product descriptions and emails for GTINs.
+
+use std::ops::Range;
+
+use crate::split::to_gtin;
+use crate::GTIN;
+
+/// Characters that may separate the digit groups of a single GTIN in text
+const SEPARATORS: [char; 3] = [' ', '-', '.'];
+
+/// A GTIN found in text
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Found {
+    pub gtin: GTIN,
+    pub span: Range<usize>, // byte range in the text, from the first digit to the last
+}
+
+impl GTIN {
+    /// Every checksum-valid GTIN in the text, in order. Digit groups joined by a single
+    /// separator are read together, so `0 71720 53977 4` is found as one code; when the
+    /// groups do not validate as a whole, the longest valid runs of groups are taken.
+    pub fn find_all(text: &str) -> Vec<Found> {
+        let mut found = vec![];
+        for groups in digit_runs(text) {
+            let mut i = 0;
+            while i < groups.len() {
+                // No GTIN has more than 14 digits, so few spans are tried from each group
+                let mut digits = Vec::with_capacity(14);
+                let mut longest = None;
+                for (j, group) in groups.iter().enumerate().skip(i) {
+                    digits.extend(text[group.clone()].bytes().map(|b| b - b'0'));
+                    if digits.len() > 14 {
+                        break;
+                    }
+                    if let Some(gtin) = to_gtin(&digits) {
+                        longest = Some((j, gtin));
+                    }
+                }
+                match longest {
+                    Some((j, gtin)) => {
+                        found.push(Found {
+                            gtin,
+                            span: groups[i].start..groups[j].end,
+                        });
+                        i = j + 1;
+                    }
+                    None => i += 1,
+                }
+            }
+        }
+        found
+    }
+}
+
+/// Runs of ASCII digit groups joined by single separators, as byte ranges of the groups
+fn digit_runs(text: &str) -> Vec<Vec<Range<usize>>> {
+    let bytes = text.as_bytes();
+    let mut runs: Vec<Vec<Range<usize>>> = vec![];
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+
+        let joined = start > 1
+            && SEPARATORS.contains(&(bytes[start - 1] as char))
+            && bytes[start - 2].is_ascii_digit();
+        if !joined {
+            runs.push(vec![]);
+        }
+        if let Some(run) = runs.last_mut() {
+            run.push(start..i);
+        }
+    }
+    runs
+}
+
+#[cfg(test)]
+pub mod tests;
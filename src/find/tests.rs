@@ -0,0 +1,42 @@
+use crate::GTIN;
+
+#[test]
+fn find_in_text() {
+    let text = "Invoice 2024-117: 3x UPC 0 71720 53977 4 @ 1.99, EAN 8595701530526; ref 00123457.";
+    let found: Vec<_> = GTIN::find_all(text)
+        .into_iter()
+        .map(|f| (f.gtin.to_string(), &text[f.span]))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("UPC-A: 071720539774".to_string(), "0 71720 53977 4"),
+            ("EAN-13: 8595701530526".to_string(), "8595701530526"),
+            ("EAN-8: 00123457".to_string(), "00123457"),
+        ]
+    );
+}
+
+#[test]
+fn find_adjacent_codes() {
+    // The groups do not validate as one code, so the longest valid runs are taken
+    let found = GTIN::find_all("071720539774 8595701530526 12");
+    assert_eq!(found.len(), 2);
+    assert_eq!(found[0].span, 0..12);
+    assert_eq!(found[1].span, 13..26);
+
+    assert!(GTIN::find_all("071720539775, 4006381").is_empty());
+    assert_eq!(GTIN::find_all("ｓｋｕ：8595701530526")[0].span, 12..25);
+}
+
+#[test]
+fn find_in_long_runs_of_groups() {
+    // Every group is tried with the few after it, not with the whole run
+    let text = "1 ".repeat(100_000);
+    assert!(GTIN::find_all(&text).is_empty());
+
+    let text = format!("{}, 0 71720 53977 4", "1 ".repeat(100_000));
+    let found = GTIN::find_all(&text);
+    assert_eq!(found.len(), 1);
+    assert_eq!(&text[found[0].span.clone()], "0 71720 53977 4");
+}
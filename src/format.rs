@@ -0,0 +1,90 @@
+//! Explicit choices of how a GTIN is written, as `Display` adapters that need no allocation
+
+use core::fmt::{Display, Formatter, Write};
+
+use crate::util::Digits;
+use crate::{GtinKind, GTIN};
+
+/// How `GTIN::format` writes the digits
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FormatStyle {
+    #[default]
+    Plain, // the digits only, e.g. `071720539774`
+    Labeled,    // as `Display`, e.g. `UPC-A: 071720539774`
+    Spaced,     // grouped as GS1 prints them under the symbol, e.g. `0 71720 53977 4`
+    Hyphenated, // the same groups joined with hyphens, e.g. `0-71720-53977-4`
+}
+
+/// A GTIN written in a `FormatStyle`, see `GTIN::format`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Formatted<'a> {
+    gtin: &'a GTIN,
+    style: FormatStyle,
+}
+
+/// The group lengths of the human-readable digits of each kind
+fn groups(kind: GtinKind) -> &'static [usize] {
+    match kind {
+        GtinKind::UpcA => &[1, 5, 5, 1],
+        GtinKind::Ean13 => &[1, 6, 6],
+        GtinKind::Ean8 => &[4, 4],
+        GtinKind::UpcE => &[1, 6, 1],
+        GtinKind::Gtin14 => &[1, 2, 5, 5, 1], // indicator digit first
+    }
+}
+
+fn label(kind: GtinKind) -> &'static str {
+    match kind {
+        GtinKind::UpcE => "UPC-E",
+        GtinKind::UpcA => "UPC-A",
+        GtinKind::Ean8 => "EAN-8",
+        GtinKind::Ean13 => "EAN-13",
+        GtinKind::Gtin14 => "GTIN-14",
+    }
+}
+
+impl Display for Formatted<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let separator = match self.style {
+            FormatStyle::Plain => return write!(f, "{}", Digits(self.gtin.digits())),
+            FormatStyle::Labeled => {
+                let kind = self.gtin.kind();
+                return write!(f, "{}: {}", label(kind), Digits(self.gtin.digits()));
+            }
+            FormatStyle::Spaced => ' ',
+            FormatStyle::Hyphenated => '-',
+        };
+
+        let mut digits = self.gtin.digits();
+        for (i, &len) in groups(self.gtin.kind()).iter().enumerate() {
+            if i > 0 {
+                f.write_char(separator)?;
+            }
+            write!(f, "{}", Digits(&digits[..len]))?;
+            digits = &digits[len..];
+        }
+        Ok(())
+    }
+}
+
+impl GTIN {
+    /// Write the GTIN in the given style, e.g. `format!("{}", gtin.format(FormatStyle::Plain))`
+    pub fn format(&self, style: FormatStyle) -> Formatted<'_> {
+        Formatted { gtin: self, style }
+    }
+
+    /// Write the digits alone into any `core::fmt::Write`, without allocating
+    pub fn write_digits<W: Write>(&self, out: &mut W) -> core::fmt::Result {
+        write!(out, "{}", Digits(self.digits()))
+    }
+
+    /// The digits alone in a string on the stack, for `no_std` and hot paths
+    #[cfg(feature = "arrayvec")]
+    pub fn to_array_string(&self) -> arrayvec::ArrayString<14> {
+        let mut digits = arrayvec::ArrayString::new();
+        for &digit in self.digits() {
+            digits.push(char::from(b'0' + digit));
+        }
+        digits
+    }
+}
@@ -0,0 +1,47 @@
+//! `Arbitrary` for `GTIN`, so fuzz targets can take GTINs as input. Every generated GTIN is
+//! valid: the right length for its kind and a correct check digit, with UPC-E's computed
+//! over its UPC-A expansion.
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::util::{calculate_checksum_digit, upce_digits};
+use crate::GTIN;
+
+/// N - 1 arbitrary digits followed by their check digit
+fn digits<const N: usize>(u: &mut Unstructured) -> Result<[u8; N]> {
+    let mut digits = [0; N];
+    for digit in &mut digits[..N - 1] {
+        *digit = u.int_in_range(0..=9)?;
+    }
+    digits[N - 1] = calculate_checksum_digit(&digits[..N - 1]);
+    Ok(digits)
+}
+
+fn upce(u: &mut Unstructured) -> Result<[u8; 8]> {
+    let number_system = u.int_in_range(0..=1)?;
+    let mut body = [0; 6];
+    for digit in &mut body {
+        *digit = u.int_in_range(0..=9)?;
+    }
+    Ok(upce_digits(number_system, &body))
+}
+
+impl<'a> Arbitrary<'a> for GTIN {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(match u.int_in_range(0..=4)? {
+            0 => GTIN::UpcE(upce(u)?),
+            1 => GTIN::UpcA(digits(u)?),
+            2 => GTIN::Ean8(digits(u)?),
+            3 => GTIN::Ean13(digits(u)?),
+            _ => GTIN::Gtin14(digits(u)?),
+        })
+    }
+
+    /// One byte for the kind and one per digit before the check digit
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (8, Some(14))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
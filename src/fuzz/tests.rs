@@ -0,0 +1,43 @@
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::util::Digits;
+use crate::{GtinKind, GTIN};
+
+#[test]
+fn arbitrary_gtins_are_valid() {
+    // A fixed pseudo-random input, as a fuzzer would supply
+    let mut state = 0x2545_f491_4f6c_dd1du64;
+    let data: Vec<u8> = (0..64 * 1024)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as u8
+        })
+        .collect();
+
+    let mut u = Unstructured::new(&data);
+    let mut kinds = vec![];
+    while !u.is_empty() {
+        let gtin = GTIN::arbitrary(&mut u).unwrap();
+        let digits = Digits(gtin.digits()).to_string();
+        let parsed = match gtin.kind() {
+            GtinKind::UpcE => GTIN::try_from(format!("]E0{}", digits).as_str()),
+            _ => GTIN::try_from(digits.as_str()),
+        };
+        assert_eq!(
+            parsed.map(|parsed| parsed.digits() == gtin.digits()),
+            Ok(true),
+            "{}",
+            gtin
+        );
+        if !kinds.contains(&gtin.kind()) {
+            kinds.push(gtin.kind());
+        }
+    }
+    assert_eq!(kinds.len(), 5);
+
+    // Exhausted input still gives a valid GTIN
+    let gtin = GTIN::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert!(GTIN::try_from(Digits(gtin.digits()).to_string().as_str()).is_ok());
+}
@@ -0,0 +1,196 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::util::{compress_upca_to_upce, validate_check_digit};
+use crate::{GtinKind, GTIN};
+
+const VERSION: u128 = 1;
+
+/// An opaque 128-bit identifier for a GTIN in event-sourced streams.
+///
+/// The layout is `[48-bit unix millis][4-bit version][4-bit kind][8 bits reserved][64-bit canonical key]`,
+/// so ids order by creation time first, then by kind and canonical key. The textual form
+/// is UUID-shaped lowercase hex and sorts the same way as the ids themselves.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct GtinId(u128);
+
+fn kind_code(kind: GtinKind) -> u128 {
+    match kind {
+        GtinKind::UpcE => 1,
+        GtinKind::UpcA => 2,
+        GtinKind::Ean8 => 3,
+        GtinKind::Ean13 => 4,
+        GtinKind::Gtin14 => 5,
+    }
+}
+
+fn kind_from_code(code: u128) -> Option<GtinKind> {
+    match code {
+        1 => Some(GtinKind::UpcE),
+        2 => Some(GtinKind::UpcA),
+        3 => Some(GtinKind::Ean8),
+        4 => Some(GtinKind::Ean13),
+        5 => Some(GtinKind::Gtin14),
+        _ => None,
+    }
+}
+
+impl GtinId {
+    /// Create an id stamped with the current time
+    pub fn new(gtin: &GTIN) -> Result<Self, String> {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        GtinId::with_timestamp(gtin, millis)
+    }
+
+    /// Create an id with an explicit creation time, e.g. when replaying events. Fails for
+    /// GTINs built from digits that are not valid for their kind, such as a UPC-E outside
+    /// number systems 0 and 1, since the id could not be converted back.
+    pub fn with_timestamp(gtin: &GTIN, unix_millis: u64) -> Result<Self, String> {
+        let millis = (unix_millis as u128) & ((1 << 48) - 1);
+        GtinId(
+            millis << 80
+                | VERSION << 76
+                | kind_code(gtin.kind()) << 72
+                | gtin.canonical_key() as u128,
+        )
+        .validate()
+    }
+
+    pub fn created_at_millis(&self) -> u64 {
+        (self.0 >> 80) as u64
+    }
+
+    pub fn kind(&self) -> GtinKind {
+        match kind_from_code((self.0 >> 72) & 0xF) {
+            Some(kind) => kind,
+            None => unreachable!("GtinId is only constructed with valid kinds"),
+        }
+    }
+
+    pub fn canonical_key(&self) -> u64 {
+        self.0 as u64
+    }
+
+    /// Convert back to a GTIN of the original kind. UPC-E comes back in its canonical
+    /// zero-suppressed form.
+    pub fn to_gtin(&self) -> GTIN {
+        match self.try_to_gtin() {
+            Ok(gtin) => gtin,
+            Err(_) => unreachable!("GtinId is only constructed from valid GTINs"),
+        }
+    }
+
+    /// The id, if it converts back to a GTIN and its key has a valid check digit
+    fn validate(self) -> Result<Self, String> {
+        self.try_to_gtin()?;
+        // Leading zeros do not affect the check digit, so validate the padded key directly
+        let key_digits = crate::util::extract_digits(&format!("{:014}", self.canonical_key()));
+        if !validate_check_digit(&key_digits) {
+            return Err("Invalid GTIN checksum in GtinId".to_string());
+        }
+        Ok(self)
+    }
+
+    fn try_to_gtin(&self) -> Result<GTIN, String> {
+        let mut digits = [0u8; 14];
+        let mut key = self.canonical_key();
+        for digit in digits.iter_mut().rev() {
+            *digit = (key % 10) as u8;
+            key /= 10;
+        }
+        if key != 0 {
+            return Err("Canonical key in GtinId has more than 14 digits".to_string());
+        }
+
+        let kind = self.kind();
+        // UPC-E is stored expanded, so it occupies the same digits as a UPC-A
+        let width = match kind {
+            GtinKind::UpcE => 12,
+            _ => kind.digit_count(),
+        };
+        let (padding, tail) = digits.split_at(14 - width);
+        if padding.iter().any(|&d| d != 0) {
+            return Err("Canonical key in GtinId does not match its kind".to_string());
+        }
+
+        let gtin = match kind {
+            GtinKind::UpcE => compress_upca_to_upce(tail)?,
+            _ => GTIN::from_digits(kind, tail.iter().copied()).map_err(|e| e.to_string())?,
+        };
+        Ok(gtin)
+    }
+}
+
+impl From<GtinId> for GTIN {
+    fn from(id: GtinId) -> Self {
+        id.to_gtin()
+    }
+}
+
+impl Display for GtinId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let v = self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            v >> 96,
+            (v >> 80) & 0xFFFF,
+            (v >> 64) & 0xFFFF,
+            (v >> 48) & 0xFFFF,
+            v & 0xFFFF_FFFF_FFFF
+        )
+    }
+}
+
+impl FromStr for GtinId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let groups: Vec<&str> = s.split('-').collect();
+        let lengths: Vec<usize> = groups.iter().map(|g| g.len()).collect();
+        if lengths != [8, 4, 4, 4, 12] {
+            return Err("GtinId must be formatted as 8-4-4-4-12 hex digits".to_string());
+        }
+
+        let hex: String = groups.concat();
+        let value = u128::from_str_radix(&hex, 16).map_err(|e| e.to_string())?;
+
+        if (value >> 76) & 0xF != VERSION {
+            return Err("Unsupported GtinId version".to_string());
+        }
+        if kind_from_code((value >> 72) & 0xF).is_none() {
+            return Err("Unknown GTIN kind in GtinId".to_string());
+        }
+        GtinId(value).validate()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for GtinId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for GtinId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde::deserialize_str(deserializer, GtinId::from_str)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
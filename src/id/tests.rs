@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use super::GtinId;
+use crate::{GtinKind, GTIN};
+
+#[test]
+fn round_trip_through_id() {
+    let cases = vec![
+        "0 71720 53977 4",   // UPC-A
+        "8595701 530526",    // EAN-13
+        "0 0071720 53977 4", // GTIN-14 form of a UPC-A
+        "0520 1341",         // EAN-8
+    ];
+
+    for input in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        let id = GtinId::with_timestamp(&gtin, 1_700_000_000_000).unwrap();
+        assert_eq!(id.kind(), gtin.kind());
+        assert_eq!(id.created_at_millis(), 1_700_000_000_000);
+        assert_eq!(id.to_gtin(), gtin, "Failed to round trip: {}", input);
+
+        let parsed = GtinId::from_str(&id.to_string()).unwrap();
+        assert_eq!(parsed, id);
+    }
+}
+
+#[test]
+fn upce_round_trips_through_canonical_form() {
+    let gtin = GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]);
+    let id = GtinId::with_timestamp(&gtin, 0).unwrap();
+    assert_eq!(id.kind(), GtinKind::UpcE);
+    assert_eq!(id.canonical_key(), 41800000265);
+    assert_eq!(id.to_gtin(), gtin);
+}
+
+#[test]
+fn reject_ids_that_cannot_round_trip() {
+    // The key of a UPC-A with no UPC-E form, tagged as a UPC-E
+    let gtin = GTIN::try_from("0 71720 53977 4").unwrap();
+    let upca = GtinId::with_timestamp(&gtin, 0).unwrap().to_string();
+    assert!(GtinId::from_str(&upca.replacen("-1200-", "-1100-", 1)).is_err());
+    // Number system 5 has no UPC-E form, so these digits never make a GTIN to begin with
+    assert!(GTIN::from_digits(GtinKind::UpcE, [5, 2, 0, 1, 3, 4, 8, 5]).is_err());
+}
+
+#[test]
+fn ids_order_by_creation_time() {
+    let earlier = GTIN::try_from("8595701 530526").unwrap();
+    let later = GTIN::try_from("0 71720 53977 4").unwrap();
+    let a = GtinId::with_timestamp(&earlier, 1_000).unwrap();
+    let b = GtinId::with_timestamp(&later, 2_000).unwrap();
+
+    assert!(a < b);
+    assert!(a.to_string() < b.to_string());
+}
+
+#[test]
+fn reject_malformed_ids() {
+    let valid = GtinId::with_timestamp(&GTIN::try_from("0 71720 53977 4").unwrap(), 0).unwrap();
+    let text = valid.to_string();
+
+    assert!(GtinId::from_str(&text.replace('-', "")).is_err());
+    assert!(GtinId::from_str("00000000-0000-1000-0000-000000000000").is_err()); // no kind
+    assert!(GtinId::from_str("00000000-0000-1200-0000-000000000001").is_err()); // bad key
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_serialize_id() {
+    let gtin = GTIN::try_from("0 71720 53977 4").unwrap();
+    let id = GtinId::with_timestamp(&gtin, 1_700_000_000_000).unwrap();
+    let serialized = serde_json::to_string(&id).unwrap();
+    assert_eq!(serialized, "\"018bcfe5-6800-1200-0000-0010b2e0967e\"");
+    assert_eq!(serde_json::from_str::<GtinId>(&serialized).unwrap(), id);
+}
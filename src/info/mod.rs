@@ -0,0 +1,52 @@
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
+use crate::{GtinKind, NumberSystem, GTIN};
+
+/// Everything the crate can tell about an input, in a shape suited for JSON
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize))]
+pub struct GtinInfo {
+    pub input: String,
+    pub valid: bool,
+    pub error: Option<String>,
+    pub kind: Option<GtinKind>,
+    pub digits: Option<String>,
+    pub gtin14: Option<String>, // zero-padded canonical form
+    pub check_digit: Option<u8>,
+    pub number_system: Option<NumberSystem>,
+    pub country_code: Option<&'static str>,
+}
+
+impl GtinInfo {
+    /// Parse the input and describe the result, including why it failed
+    pub fn classify(input: &str) -> Self {
+        match GTIN::try_from(input) {
+            Ok(gtin) => GtinInfo {
+                input: input.to_string(),
+                valid: true,
+                error: None,
+                kind: Some(gtin.kind()),
+                digits: Some(crate::util::digits_to_string(gtin.digits())),
+                gtin14: Some(format!("{:014}", gtin.canonical_key())),
+                check_digit: gtin.digits().last().copied(),
+                number_system: Some(gtin.number_system()),
+                country_code: gtin.country_code(),
+            },
+            Err(e) => GtinInfo {
+                input: input.to_string(),
+                valid: false,
+                error: Some(e.to_string()),
+                kind: None,
+                digits: None,
+                gtin14: None,
+                check_digit: None,
+                number_system: None,
+                country_code: None,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,30 @@
+use super::GtinInfo;
+use crate::{GtinKind, NumberSystem};
+
+#[test]
+fn classify_valid_input() {
+    let info = GtinInfo::classify("0 71720 53977 4");
+    assert!(info.valid);
+    assert_eq!(info.kind, Some(GtinKind::UpcA));
+    assert_eq!(info.digits.as_deref(), Some("071720539774"));
+    assert_eq!(info.gtin14.as_deref(), Some("00071720539774"));
+    assert_eq!(info.check_digit, Some(4));
+    assert_eq!(info.number_system, Some(NumberSystem::General));
+    assert_eq!(info.country_code, Some("US"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn classify_to_json() {
+    let info = GtinInfo::classify("071720539775");
+    assert_eq!(
+        serde_json::to_string(&info).unwrap(),
+        r#"{"input":"071720539775","valid":false,"error":"Invalid GTIN checksum","kind":null,"digits":null,"gtin14":null,"check_digit":null,"number_system":null,"country_code":null}"#
+    );
+
+    let info = GtinInfo::classify("8595701 530526");
+    let json = serde_json::to_value(&info).unwrap();
+    assert_eq!(json["kind"], "Ean13");
+    assert_eq!(json["number_system"], "General");
+    assert_eq!(json["country_code"], "CZ");
+}
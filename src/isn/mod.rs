@@ -0,0 +1,98 @@
+//! The text of PostgreSQL's `isn` extension types. `isn` prints EAN13, ISBN, ISMN, ISSN
+//! and UPC values hyphenated, ISBNs, ISMNs and ISSNs in their short 10 and 8 character
+//! forms where they have one, and marks values stored with a wrong check digit with a
+//! trailing `!`. `parse` reads all of these back as GTINs, and `to_isn` gives text every
+//! `isn` type accepts for its range, so columns can move between `isn` and TEXT.
+
+use crate::util::calculate_checksum_digit;
+use crate::{ConversionError, GtinError, GTIN};
+
+/// The GTIN of a value as `isn` prints it, e.g. `978-0-393-04002-9`, `0-393-04002-X`,
+/// `M-060-11561-5` or `1436-4522`. Short forms are converted to their EAN-13, after their
+/// own check character is verified. A `!` suffix fails with `InvalidChecksum`.
+pub fn parse(value: &str) -> Result<GTIN, GtinError> {
+    let compact: Vec<u8> = value
+        .trim()
+        .bytes()
+        .filter(|&b| !matches!(b, b'-' | b' '))
+        .collect();
+    if compact.last() == Some(&b'!') {
+        return Err(GtinError::InvalidChecksum);
+    }
+
+    match compact.as_slice() {
+        [b'M' | b'm', rest @ ..] if rest.len() == 9 => ismn10(rest),
+        code if code.len() == 10 => short_form(b"978", code, 9, b"", 10),
+        code if code.len() == 8 => short_form(b"977", code, 7, b"00", 8),
+        code => GTIN::try_from(code),
+    }
+}
+
+/// Digit values of ASCII digits
+fn digit_values(ascii: &[u8]) -> Result<Vec<u8>, GtinError> {
+    ascii
+        .iter()
+        .map(|&b| match b {
+            b'0'..=b'9' => Ok(b - b'0'),
+            _ => Err(GtinError::InvalidDigit(b)),
+        })
+        .collect()
+}
+
+/// An ISBN-10 or ISSN: `body_len` digits and a mod-11 check character, weighted from
+/// `top_weight` down to 1, which becomes the EAN-13 `prefix`, body, `variant` and check digit
+fn short_form(
+    prefix: &[u8],
+    code: &[u8],
+    body_len: usize,
+    variant: &[u8],
+    top_weight: u32,
+) -> Result<GTIN, GtinError> {
+    let (body, check) = code.split_at(body_len);
+    let body = digit_values(body)?;
+    let check = match check {
+        [b'X' | b'x'] => 10,
+        [b @ b'0'..=b'9'] => u32::from(b - b'0'),
+        [b] => return Err(GtinError::InvalidDigit(*b)),
+        _ => unreachable!("one check character"),
+    };
+    let weighted: u32 = (2..=top_weight)
+        .rev()
+        .zip(&body)
+        .map(|(weight, &d)| weight * u32::from(d))
+        .sum();
+    if !(weighted + check).is_multiple_of(11) {
+        return Err(GtinError::InvalidChecksum);
+    }
+    ean13(prefix, &body, variant)
+}
+
+/// ISMN-10, `M` and 9 digits, whose check digit is that of its 979-0 EAN-13
+fn ismn10(code: &[u8]) -> Result<GTIN, GtinError> {
+    let digits = digit_values(code)?;
+    let (body, check) = digits.split_at(8);
+    let gtin = ean13(b"9790", body, b"")?;
+    if gtin.digits()[12] != check[0] {
+        return Err(GtinError::InvalidChecksum);
+    }
+    Ok(gtin)
+}
+
+/// The EAN-13 of ASCII `prefix`, digit values `body` and ASCII `variant`, with its check digit
+fn ean13(prefix: &[u8], body: &[u8], variant: &[u8]) -> Result<GTIN, GtinError> {
+    let mut digits = digit_values(prefix)?;
+    digits.extend(body);
+    digits.extend(digit_values(variant)?);
+    digits.push(calculate_checksum_digit(&digits));
+    GTIN::from_digits(crate::GtinKind::Ean13, digits)
+}
+
+/// The 13 digits `isn` reads into any of its types whose range the GTIN is in: the EAN-13
+/// form, zero-padding UPC-A and expanding UPC-E. EAN-8 and GTIN-14 with an indicator
+/// other than 0 have no EAN-13 form.
+pub fn to_isn(gtin: &GTIN) -> Result<String, ConversionError> {
+    gtin.as_ean13().map(String::from)
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,54 @@
+use super::{parse, to_isn};
+use crate::{ConversionError, GtinError, GtinKind, GTIN};
+
+#[test]
+fn parse_isn_output() {
+    let cases = [
+        ("978-0-393-04002-9", "9780393040029"), // EAN13 and ISBN13
+        ("0-393-04002-X", "9780393040029"),     // ISBN
+        ("0-393-04002-x", "9780393040029"),
+        ("0-8044-2957-X", "9780804429573"),
+        ("M-060-11561-5", "9790060115615"), // ISMN
+        ("979-0-060-11561-5", "9790060115615"),
+        ("1436-4522", "9771436452008"), // ISSN
+        ("977-1436-452-00-8", "9771436452008"),
+        ("036000291452", "036000291452"), // UPC
+        ("003-600029145-2", "0036000291452"),
+    ];
+    for (input, expected) in cases {
+        assert_eq!(parse(input), GTIN::try_from(expected), "{}", input);
+    }
+}
+
+#[test]
+fn parse_isn_errors() {
+    // Stored in weak mode with a wrong check digit
+    assert_eq!(parse("978-0-393-04002-8!"), Err(GtinError::InvalidChecksum));
+    assert_eq!(parse("0-393-04002-9"), Err(GtinError::InvalidChecksum));
+    assert_eq!(parse("1436-4523"), Err(GtinError::InvalidChecksum));
+    assert_eq!(parse("M-060-11561-4"), Err(GtinError::InvalidChecksum));
+    assert_eq!(parse("0-393-0400X-9"), Err(GtinError::InvalidDigit(b'X')));
+    assert_eq!(parse("0-393-04002-Y"), Err(GtinError::InvalidDigit(b'Y')));
+}
+
+#[test]
+fn to_isn_text() {
+    for (input, expected) in [
+        ("9780393040029", "9780393040029"),
+        ("036000291452", "0036000291452"),
+        ("00036000291452", "0036000291452"),
+    ] {
+        let gtin = GTIN::try_from(input).unwrap();
+        assert_eq!(to_isn(&gtin).as_deref(), Ok(expected));
+        assert!(parse(expected).unwrap().same_product(&gtin));
+    }
+    let upce = GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]);
+    assert_eq!(to_isn(&upce).as_deref(), Ok("0100000000052"));
+    assert_eq!(
+        to_isn(&GTIN::try_from("96385074").unwrap()),
+        Err(ConversionError::NotRepresentable {
+            from: GtinKind::Ean8,
+            to: GtinKind::Ean13
+        })
+    );
+}
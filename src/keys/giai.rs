@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+
+use super::{impl_serde_via_str, validate_alphanumeric};
+
+/// Global Individual Asset Identifier: a company prefix followed by an alphanumeric
+/// asset reference, up to 30 characters and without a check digit
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Giai(String);
+
+impl Giai {
+    pub const AI: &'static str = "8004";
+
+    pub fn as_element_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Display for Giai {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GIAI: {}", self.0)
+    }
+}
+
+impl std::convert::TryFrom<&str> for Giai {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        validate_alphanumeric(value, 30, "GIAI")?;
+        Ok(Giai(value.to_string()))
+    }
+}
+
+impl_serde_via_str!(Giai);
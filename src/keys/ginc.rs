@@ -0,0 +1,34 @@
+use std::fmt::{Display, Formatter};
+
+use super::{impl_serde_via_str, validate_alphanumeric};
+
+/// Global Identification Number for Consignment: a company prefix followed by an
+/// alphanumeric consignment reference, up to 30 characters and without a check digit
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Ginc(String);
+
+impl Ginc {
+    pub const AI: &'static str = "401";
+
+    pub fn as_element_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Display for Ginc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GINC: {}", self.0)
+    }
+}
+
+impl std::convert::TryFrom<&str> for Ginc {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        validate_alphanumeric(value, 30, "GINC")?;
+        Ok(Ginc(value.to_string()))
+    }
+}
+
+impl_serde_via_str!(Ginc);
@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+
+use super::{impl_serde_via_str, validate_alphanumeric};
+
+/// Character values for the check character calculation are positions in CSET 82
+const CSET82: &[u8] =
+    b"!\"%&'()*+,-./0123456789:;<=>?ABCDEFGHIJKLMNOPQRSTUVWXYZ_abcdefghijklmnopqrstuvwxyz";
+/// The check characters are drawn from CSET 32
+const CSET32: &[u8] = b"23456789ABCDEFGHJKLMNPQRSTUVWXYZ";
+/// Prime weights; the rightmost character of the body is weighted 2
+const WEIGHTS: [u32; 23] = [
+    83, 79, 73, 71, 67, 61, 59, 53, 47, 43, 41, 37, 31, 29, 23, 19, 17, 13, 11, 7, 5, 3, 2,
+];
+
+/// Global Model Number: a company prefix and model reference followed by a pair of
+/// check characters, 25 characters at most
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Gmn(String);
+
+impl Gmn {
+    pub const AI: &'static str = "8013";
+
+    /// Compute the mod-1021 check character pair for a GMN body
+    pub fn check_characters(body: &str) -> Result<[char; 2], String> {
+        validate_alphanumeric(body, WEIGHTS.len(), "GMN")?;
+
+        let offset = WEIGHTS.len() - body.len();
+        let sum: u32 = body
+            .bytes()
+            .zip(&WEIGHTS[offset..])
+            .map(|(c, &weight)| {
+                // validate_alphanumeric guarantees every character is in CSET 82
+                let value = CSET82.iter().position(|&x| x == c).unwrap_or(0) as u32;
+                value * weight
+            })
+            .sum::<u32>()
+            % 1021;
+
+        Ok([
+            CSET32[(sum >> 5) as usize] as char,
+            CSET32[(sum & 31) as usize] as char,
+        ])
+    }
+
+    /// Append the check character pair to a GMN body
+    pub fn with_check_characters(body: &str) -> Result<Self, String> {
+        let [first, second] = Gmn::check_characters(body)?;
+        Ok(Gmn(format!("{}{}{}", body, first, second)))
+    }
+
+    pub fn body(&self) -> &str {
+        &self.0[..self.0.len() - 2]
+    }
+
+    pub fn as_element_string(&self) -> String {
+        self.0.clone()
+    }
+}
+
+impl Display for Gmn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GMN: {}", self.0)
+    }
+}
+
+impl std::convert::TryFrom<&str> for Gmn {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if value.len() < 6 || !value.is_ascii() {
+            return Err("Unsupported GMN length".to_string());
+        }
+
+        let (body, check) = value.split_at(value.len() - 2);
+        let expected = Gmn::check_characters(body)?;
+        if check.chars().ne(expected) {
+            return Err("Invalid GMN check characters".to_string());
+        }
+        Ok(Gmn(value.to_string()))
+    }
+}
+
+impl_serde_via_str!(Gmn);
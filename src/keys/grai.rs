@@ -0,0 +1,75 @@
+use std::fmt::{Display, Formatter};
+
+use super::{impl_serde_via_str, is_cset82};
+use crate::util::{digits_to_string, extract_digits, validate_check_digit};
+
+/// Global Returnable Asset Identifier: a filler zero, company prefix, asset type and
+/// check digit, optionally followed by a serial component
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Grai {
+    digits: [u8; 14],
+    serial: Option<String>,
+}
+
+impl Grai {
+    pub const AI: &'static str = "8003";
+
+    pub fn digits(&self) -> &[u8] {
+        &self.digits
+    }
+
+    /// The optional serial component identifying an individual asset of the type
+    pub fn serial(&self) -> Option<&str> {
+        self.serial.as_deref()
+    }
+
+    pub fn as_element_string(&self) -> String {
+        format!(
+            "{}{}",
+            digits_to_string(&self.digits),
+            self.serial.as_deref().unwrap_or("")
+        )
+    }
+}
+
+impl Display for Grai {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GRAI: {}", self.as_element_string())
+    }
+}
+
+impl std::convert::TryFrom<&str> for Grai {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        if value.len() < 14 || !value.is_char_boundary(14) {
+            return Err("Unsupported GRAI length".to_string());
+        }
+        let (numeric, serial) = value.split_at(14);
+
+        let digits = extract_digits(numeric);
+        if digits.len() != 14 {
+            return Err("GRAI must start with 14 digits".to_string());
+        }
+        if digits[0] != 0 {
+            return Err("GRAI must start with a filler zero".to_string());
+        }
+        // The check digit covers the 13 digits after the filler
+        if !validate_check_digit(&digits[1..]) {
+            return Err("Invalid GRAI checksum".to_string());
+        }
+        if serial.len() > 16 || !serial.chars().all(is_cset82) {
+            return Err("Invalid GRAI serial component".to_string());
+        }
+
+        Ok(Grai {
+            digits: digits
+                .try_into()
+                .map_err(|_| "digits vector does not have exactly 14 elements".to_string())?,
+            serial: (!serial.is_empty()).then(|| serial.to_string()),
+        })
+    }
+}
+
+impl_serde_via_str!(Grai);
@@ -0,0 +1,45 @@
+use std::fmt::{Display, Formatter};
+
+use super::impl_serde_via_str;
+use crate::util::{digits_to_string, extract_digits, validate_check_digit, Digits};
+
+/// Global Shipment Identification Number, 17 digits including the check digit
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Gsin([u8; 17]);
+
+impl Gsin {
+    pub const AI: &'static str = "402";
+
+    pub fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn as_element_string(&self) -> String {
+        digits_to_string(&self.0)
+    }
+}
+
+impl Display for Gsin {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GSIN: {}", Digits(&self.0))
+    }
+}
+
+impl std::convert::TryFrom<&str> for Gsin {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits = extract_digits(value);
+        if digits.len() != 17 {
+            return Err("Unsupported GSIN length".to_string());
+        }
+        if !validate_check_digit(&digits) {
+            return Err("Invalid GSIN checksum".to_string());
+        }
+        Ok(Gsin(digits.try_into().map_err(|_| {
+            "digits vector does not have exactly 17 elements".to_string()
+        })?))
+    }
+}
+
+impl_serde_via_str!(Gsin);
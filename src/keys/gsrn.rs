@@ -0,0 +1,48 @@
+use std::fmt::{Display, Formatter};
+
+use super::impl_serde_via_str;
+use crate::util::{digits_to_string, extract_digits, validate_check_digit, Digits};
+
+/// Global Service Relation Number, 18 digits including the check digit
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Gsrn([u8; 18]);
+
+impl Gsrn {
+    /// GSRN identifying the provider of a service relation
+    pub const AI_PROVIDER: &'static str = "8017";
+    /// GSRN identifying the recipient of a service relation
+    pub const AI_RECIPIENT: &'static str = "8018";
+
+    pub fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn as_element_string(&self) -> String {
+        digits_to_string(&self.0)
+    }
+}
+
+impl Display for Gsrn {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "GSRN: {}", Digits(&self.0))
+    }
+}
+
+impl std::convert::TryFrom<&str> for Gsrn {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let digits = extract_digits(value);
+        if digits.len() != 18 {
+            return Err("Unsupported GSRN length".to_string());
+        }
+        if !validate_check_digit(&digits) {
+            return Err("Invalid GSRN checksum".to_string());
+        }
+        Ok(Gsrn(digits.try_into().map_err(|_| {
+            "digits vector does not have exactly 18 elements".to_string()
+        })?))
+    }
+}
+
+impl_serde_via_str!(Gsrn);
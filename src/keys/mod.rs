@@ -0,0 +1,67 @@
+//! The remaining GS1 identification keys. Each type knows its length rules, validates its
+//! check digit where the key has one, and exposes the application identifier (AI) it is
+//! carried under in GS1 element strings.
+
+pub mod giai;
+pub mod ginc;
+pub mod gmn;
+pub mod grai;
+pub mod gsin;
+pub mod gsrn;
+
+pub use crate::sscc::Sscc;
+pub use giai::Giai;
+pub use ginc::Ginc;
+pub use gmn::Gmn;
+pub use grai::Grai;
+pub use gsin::Gsin;
+pub use gsrn::Gsrn;
+
+/// GS1 AI encodable character set 82, used by the alphanumeric parts of keys
+pub fn is_cset82(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!\"%&'()*+,-./:;<=>?_".contains(c)
+}
+
+/// Validate an alphanumeric key that starts with a GS1 Company Prefix
+fn validate_alphanumeric(value: &str, max_len: usize, name: &str) -> Result<(), String> {
+    if value.len() > max_len {
+        return Err(format!("{} longer than {} characters", name, max_len));
+    }
+    if !value.chars().all(is_cset82) {
+        return Err(format!("{} contains characters outside CSET 82", name));
+    }
+    // The shortest GS1 Company Prefix has four digits
+    if value.len() < 4 || !value[..4].bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("{} must start with a GS1 Company Prefix", name));
+    }
+    Ok(())
+}
+
+/// Serialize and deserialize a key through its string form
+macro_rules! impl_serde_via_str {
+    ($key:ty) => {
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $key {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.as_element_string())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $key {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                crate::serde::deserialize_str(deserializer, |s| <$key>::try_from(s))
+            }
+        }
+    };
+}
+use impl_serde_via_str;
+
+#[cfg(test)]
+pub mod tests;
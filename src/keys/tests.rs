@@ -0,0 +1,80 @@
+use super::{Giai, Ginc, Gmn, Grai, Gsin, Gsrn};
+
+#[test]
+fn parse_grai() {
+    let grai = Grai::try_from("00614141000012ABC-123").unwrap();
+    assert_eq!(grai.serial(), Some("ABC-123"));
+    assert_eq!(grai.as_element_string(), "00614141000012ABC-123");
+
+    let grai = Grai::try_from("00614141000012").unwrap();
+    assert_eq!(grai.serial(), None);
+
+    assert!(Grai::try_from("10614141000012").is_err()); // missing filler zero
+    assert!(Grai::try_from("00614141000013").is_err()); // invalid check digit
+    assert!(Grai::try_from("00614141000012 SERIAL").is_err()); // space outside CSET 82
+    assert!(Grai::try_from("00614141000012ABCDEFGHIJKLMNOPQ").is_err()); // serial too long
+}
+
+#[test]
+fn parse_alphanumeric_keys() {
+    assert!(Giai::try_from("0614141A-12345").is_ok());
+    assert!(Ginc::try_from("0614141XYZ/001").is_ok());
+
+    assert!(Giai::try_from("ABC12345").is_err()); // no company prefix
+    assert!(Ginc::try_from("0614141 001").is_err()); // space outside CSET 82
+    assert!(Giai::try_from("0614141234567890123456789012345").is_err()); // 31 characters
+}
+
+#[test]
+fn parse_numeric_keys() {
+    let cases = vec![
+        (Gsrn::try_from("061414112345678902").is_ok(), true),
+        (Gsrn::try_from("061414112345678903").is_ok(), false),
+        (Gsin::try_from("0614141 123456789 0").is_ok(), true),
+        (Gsin::try_from("06141411234567891").is_ok(), false),
+    ];
+    for (index, (actual, expected)) in cases.into_iter().enumerate() {
+        assert_eq!(actual, expected, "Failed case {}", index);
+    }
+}
+
+#[test]
+fn application_identifiers() {
+    assert_eq!(super::Sscc::AI, "00");
+    assert_eq!(Grai::AI, "8003");
+    assert_eq!(Giai::AI, "8004");
+    assert_eq!(Gsrn::AI_PROVIDER, "8017");
+    assert_eq!(Gsrn::AI_RECIPIENT, "8018");
+    assert_eq!(Ginc::AI, "401");
+    assert_eq!(Gsin::AI, "402");
+    assert_eq!(Gmn::AI, "8013");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn round_trip_serialization() {
+    let grai = Grai::try_from("00614141000012ABC-123").unwrap();
+    let serialized = serde_json::to_string(&grai).unwrap();
+    assert_eq!(serialized, "\"00614141000012ABC-123\"");
+    assert_eq!(serde_json::from_str::<Grai>(&serialized).unwrap(), grai);
+}
+
+#[test]
+fn gmn_check_characters() {
+    // Example from the GS1 General Specifications
+    let gmn = Gmn::try_from("1987654Ad4X4bL5ttr2310c2K").unwrap();
+    assert_eq!(gmn.body(), "1987654Ad4X4bL5ttr2310c");
+
+    assert_eq!(
+        Gmn::check_characters("1987654Ad4X4bL5ttr2310c"),
+        Ok(['2', 'K'])
+    );
+    assert_eq!(
+        Gmn::with_check_characters("1987654Ad4X4bL5ttr2310c"),
+        Ok(gmn)
+    );
+
+    assert!(Gmn::try_from("1987654Ad4X4bL5ttr2310c2L").is_err()); // wrong check character
+    assert!(Gmn::try_from("1987654Ad4X4bL5ttr2310cK2").is_err()); // swapped check characters
+    assert!(Gmn::try_from("1987654Ad4X4bL5ttr2310cX2K").is_err()); // body too long
+}
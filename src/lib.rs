@@ -1,102 +1,904 @@
-use std::fmt::{Display, Formatter};
+//! Parse, validate and convert GTINs. Without the default `std` feature only the
+//! allocation-free core is built: the `GTIN` type, parsing, check digits and conversions.
 
-use util::{digits_to_string, validate_gtin};
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
+// the cdylib crate type needs a panic handler, which std supplies wherever it exists
+#[cfg(all(not(feature = "std"), not(target_os = "none")))]
+extern crate std;
 
+use core::fmt::{Display, Formatter};
+use core::ops::RangeInclusive;
+
+use util::validate_gtin;
+
+#[cfg(feature = "serde")]
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod aim;
+#[cfg(feature = "std")]
+pub mod allocator;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+#[cfg(feature = "std")]
+pub mod cache;
+#[cfg(feature = "std")]
+mod capabilities;
+#[cfg(feature = "std")]
+pub mod char14;
+#[cfg(feature = "std")]
+pub mod column;
+#[cfg(feature = "conformance")]
+pub mod conformance;
+#[cfg(feature = "std")]
+pub mod correct;
+#[cfg(feature = "csv")]
+pub mod csv;
+#[cfg(feature = "std")]
+pub mod decode;
+#[cfg(feature = "std")]
+pub mod detect;
+#[cfg(feature = "differential")]
+pub mod differential;
+#[cfg(feature = "std")]
+pub mod encode;
+mod error;
+#[cfg(feature = "std")]
+pub mod exact;
+#[cfg(feature = "std")]
+pub mod find;
+pub mod format;
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+#[cfg(feature = "std")]
+pub mod id;
+#[cfg(feature = "std")]
+pub mod info;
+#[cfg(feature = "std")]
+pub mod isn;
+#[cfg(feature = "std")]
+pub mod keys;
+mod macros;
+#[cfg(feature = "std")]
+pub mod migrating;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+#[cfg(feature = "utoipa")]
+mod openapi;
+#[cfg(feature = "std")]
+pub mod optional;
+#[cfg(feature = "diesel")]
+mod orm;
+#[cfg(feature = "rayon")]
+pub mod par;
+#[cfg(feature = "std")]
+pub mod parse;
+#[cfg(feature = "std")]
+pub mod pattern;
+#[cfg(feature = "std")]
+pub mod pipeline;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+pub mod receipt;
+#[cfg(feature = "redis")]
+mod redis;
+#[cfg(feature = "std")]
+pub mod render;
+#[cfg(feature = "serde")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod set;
+#[cfg(feature = "std")]
+pub mod split;
+#[cfg(feature = "sqlx")]
+mod sql;
+#[cfg(feature = "rusqlite")]
+mod sqlite;
+#[cfg(feature = "std")]
+pub mod sscc;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod telemetry;
+#[cfg(feature = "std")]
+pub mod trie;
+#[cfg(feature = "std")]
+pub mod url;
 pub mod util;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
-/// An enum to hold GTIN variants
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
-pub enum GTIN {
-    UpcE([u8; 8]),    // UPC-E always has 8 digits
-    UpcA([u8; 12]),   // UPC-A always has 12 digits
-    Ean8([u8; 8]),    // EAN-8 always has 8 digits
-    Ean13([u8; 13]),  // EAN-13 always has 13 digits
-    Gtin14([u8; 14]), // GTIN-14 always has 14 digits
+#[cfg(feature = "std")]
+pub use allocator::{GtinAllocator, GtinRange};
+#[cfg(feature = "std")]
+pub use cache::GtinCache;
+#[cfg(feature = "std")]
+pub use capabilities::{capabilities, Capabilities};
+#[cfg(feature = "std")]
+pub use column::GtinColumn;
+#[cfg(feature = "std")]
+pub use correct::{diagnose, Diagnosis};
+pub use error::{ConversionError, GtinError};
+#[cfg(feature = "std")]
+pub use exact::ExactGtin;
+pub use format::{FormatStyle, Formatted};
+#[cfg(feature = "std")]
+pub use id::GtinId;
+#[cfg(feature = "std")]
+pub use info::GtinInfo;
+#[cfg(feature = "std")]
+pub use migrating::MigratingGtin;
+#[cfg(feature = "std")]
+pub use optional::OptionalGtin;
+#[cfg(feature = "std")]
+pub use parse::{Correction, ParseOptions, ParseWarning};
+#[cfg(feature = "std")]
+pub use pattern::GtinPattern;
+#[cfg(feature = "std")]
+pub use pipeline::Pipeline;
+#[cfg(feature = "serde")]
+pub use schema::{schema, ErrorSchema, KindSchema, Schema};
+#[cfg(feature = "std")]
+pub use set::GtinSet;
+#[cfg(feature = "std")]
+pub use sscc::Sscc;
+#[cfg(feature = "std")]
+pub use stream::GtinStream;
+#[cfg(feature = "std")]
+pub use trie::{GtinTrie, PrefixTrie};
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+/// Version of the GS1 prefix tables behind `country_code` and `number_system`,
+/// bumped whenever those tables change
+pub const PREFIX_DATA_VERSION: &str = "1";
+
+/// A GTIN: its kind and digits, in one fixed-size buffer whatever the kind. Equality and
+/// hashing are by kind and digits, so a UPC-A and its EAN-13 form are distinct keys; key
+/// by `canonical_key` to merge them. Match on `kind()` and read `digits()`.
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize),
+    rkyv(derive(Debug, PartialEq, Eq, Hash), bytecheck(verify))
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Text, sql_type = diesel::sql_types::BigInt)
+)]
+pub struct GTIN {
+    digits: [u8; 14], // the first `len`, then zeros
+    #[cfg_attr(feature = "rkyv", rkyv(with = rkyv::with::Skip))]
+    len: u8, // always `kind.digit_count()`, so archives leave it out
+    kind: GtinKind,
+}
+
+/// The kinds of GTIN, which tell the digit count and how the digits are read
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Enum))]
+#[cfg_attr(
+    feature = "rkyv",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    rkyv(derive(Debug, PartialEq, Eq, Hash), compare(PartialEq))
+)]
+pub enum GtinKind {
+    UpcE,
+    UpcA,
+    Ean8,
+    Ean13,
+    Gtin14,
+}
+
+impl GtinKind {
+    /// Number of digits, including the check digit
+    pub const fn digit_count(self) -> usize {
+        match self {
+            GtinKind::UpcE | GtinKind::Ean8 => 8,
+            GtinKind::UpcA => 12,
+            GtinKind::Ean13 => 13,
+            GtinKind::Gtin14 => 14,
+        }
+    }
+
+    /// Canonical keys of valid UPC-E codes, which are keyed by their UPC-A expansion
+    pub const UPC_E_KEYS: RangeInclusive<GtinKey> = 0..=199_999_000_099;
+    pub const UPC_A_KEYS: RangeInclusive<GtinKey> = 0..=999_999_999_993;
+    pub const EAN_8_KEYS: RangeInclusive<GtinKey> = 0..=99_999_995;
+    pub const EAN_13_KEYS: RangeInclusive<GtinKey> = 0..=9_999_999_999_994;
+    pub const GTIN_14_KEYS: RangeInclusive<GtinKey> = 0..=99_999_999_999_997;
+
+    /// The range of canonical keys a valid GTIN of this kind can have
+    pub const fn keys(self) -> RangeInclusive<GtinKey> {
+        match self {
+            GtinKind::UpcE => GtinKind::UPC_E_KEYS,
+            GtinKind::UpcA => GtinKind::UPC_A_KEYS,
+            GtinKind::Ean8 => GtinKind::EAN_8_KEYS,
+            GtinKind::Ean13 => GtinKind::EAN_13_KEYS,
+            GtinKind::Gtin14 => GtinKind::GTIN_14_KEYS,
+        }
+    }
+
+    pub const fn min(self) -> GtinKey {
+        *self.keys().start()
+    }
+
+    pub const fn max(self) -> GtinKey {
+        *self.keys().end()
+    }
 }
 
+/// A GTIN's zero-padded 14-digit value, see `GTIN::canonical_key`
+pub type GtinKey = u64;
+
+/// The kind and digits, e.g. `UPC-A: 071720539774`, or with `{:#}` the digits alone; see
+/// `GTIN::format` for other styles
 impl Display for GTIN {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        match *self {
-            GTIN::UpcE(digits) => write!(f, "UPC-E: {}", digits_to_string(&digits)),
-            GTIN::UpcA(digits) => write!(f, "UPC-A: {}", digits_to_string(&digits)),
-            GTIN::Ean8(digits) => write!(f, "EAN-8: {}", digits_to_string(&digits)),
-            GTIN::Ean13(digits) => write!(f, "EAN-13: {}", digits_to_string(&digits)),
-            GTIN::Gtin14(digits) => write!(f, "GTIN-14: {}", digits_to_string(&digits)),
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let style = if f.alternate() {
+            FormatStyle::Plain
+        } else {
+            FormatStyle::Labeled
+        };
+        self.format(style).fmt(f)
+    }
+}
+
+/// Whether the string holds exactly these digits, ignoring spaces, hyphens and dots
+/// between them. Nothing is parsed, so `"71720539774"` is not the UPC-A `071720539774`.
+impl PartialEq<str> for GTIN {
+    fn eq(&self, other: &str) -> bool {
+        let mut digits = self.digits().iter();
+        for c in other.chars() {
+            match c {
+                '0'..='9' if digits.next() == Some(&(c as u8 - b'0')) => {}
+                ' ' | '-' | '.' => {}
+                _ => return false,
+            }
         }
+        digits.next().is_none()
+    }
+}
+
+impl PartialEq<&str> for GTIN {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<GTIN> for str {
+    fn eq(&self, other: &GTIN) -> bool {
+        other == self
     }
 }
 
-impl std::convert::TryFrom<&str> for GTIN {
-    type Error = String;
+impl PartialEq<GTIN> for &str {
+    fn eq(&self, other: &GTIN) -> bool {
+        other == *self
+    }
+}
+
+/// By canonical key, so the forms of a product sort together, then by kind, then by
+/// digits for the UPC-E codes that share a key
+impl Ord for GTIN {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let key = |gtin: &GTIN| (gtin.canonical_key(), gtin.kind());
+        key(self)
+            .cmp(&key(other))
+            .then_with(|| self.digits().cmp(other.digits()))
+    }
+}
+
+impl PartialOrd for GTIN {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The digits alone, unlike `Display`, which names the kind
+#[cfg(feature = "std")]
+impl From<GTIN> for String {
+    fn from(gtin: GTIN) -> Self {
+        gtin.format(FormatStyle::Plain).to_string()
+    }
+}
+
+/// The kind and digit array, e.g. `UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])`
+impl core::fmt::Debug for GTIN {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple(match self.kind {
+            GtinKind::UpcE => "UpcE",
+            GtinKind::UpcA => "UpcA",
+            GtinKind::Ean8 => "Ean8",
+            GtinKind::Ean13 => "Ean13",
+            GtinKind::Gtin14 => "Gtin14",
+        })
+        .field(&self.digits())
+        .finish()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl ArchivedGTIN {
+    fn kind(&self) -> GtinKind {
+        match self.kind {
+            ArchivedGtinKind::UpcE => GtinKind::UpcE,
+            ArchivedGtinKind::UpcA => GtinKind::UpcA,
+            ArchivedGtinKind::Ean8 => GtinKind::Ean8,
+            ArchivedGtinKind::Ean13 => GtinKind::Ean13,
+            ArchivedGtinKind::Gtin14 => GtinKind::Gtin14,
+        }
+    }
+}
+
+/// Validated archives hold digits `from_digits` accepts, with zeros after them
+#[cfg(feature = "rkyv")]
+unsafe impl<C> rkyv::bytecheck::Verify<C> for ArchivedGTIN
+where
+    C: rkyv::rancor::Fallible + ?Sized,
+    C::Error: rkyv::rancor::Source,
+{
+    fn verify(&self, _: &mut C) -> Result<(), C::Error> {
+        let (digits, padding) = self.digits.split_at(self.kind().digit_count());
+        if padding.iter().any(|&d| d != 0) {
+            return Err(rkyv::rancor::Source::new(GtinError::InvalidPadding));
+        }
+        match GTIN::from_digits(self.kind(), digits.iter().copied()) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(rkyv::rancor::Source::new(e)),
+        }
+    }
+}
+
+/// Read a GTIN straight out of an archive, e.g. a memory-mapped catalog, without rkyv's
+/// deserializer
+#[cfg(feature = "rkyv")]
+impl From<&ArchivedGTIN> for GTIN {
+    fn from(archived: &ArchivedGTIN) -> Self {
+        GTIN::new(archived.kind(), &archived.digits)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl PartialEq<GTIN> for ArchivedGTIN {
+    fn eq(&self, other: &GTIN) -> bool {
+        GTIN::from(self) == *other
+    }
+}
+
+/// The length is not archived, so it comes from the kind
+#[cfg(feature = "rkyv")]
+impl<D: rkyv::rancor::Fallible + ?Sized> rkyv::Deserialize<GTIN, D> for ArchivedGTIN {
+    fn deserialize(&self, _: &mut D) -> Result<GTIN, D::Error> {
+        Ok(GTIN::from(self))
+    }
+}
+
+/// Digit values 0 to 9, as `GTIN::digits` gives them
+impl AsRef<[u8]> for GTIN {
+    fn as_ref(&self) -> &[u8] {
+        self.digits()
+    }
+}
+
+/// Fails with a `GtinError`. This was a `String` before `GtinError` was added; its
+/// `to_string()` gives the same messages, e.g. "Invalid GTIN checksum".
+impl TryFrom<&str> for GTIN {
+    type Error = GtinError;
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        let mut digits: Vec<u8> = util::extract_digits(value);
-
-        if validate_gtin(&digits) {
-            match digits.len() {
-                8 => {
-                    // Try to determine if it is UPC-E or EAN-8
-                    // Simple heuristic: UPC-E is mostly used in North America and rarely has leading zeroes.
-                    if digits[0] == 0 {
-                        Ok(GTIN::Ean8(digits.try_into().map_err(|_| {
-                            "digits vector does not have exactly 8 elements".to_string()
-                        })?))
-                    } else {
-                        Ok(GTIN::UpcE(digits.try_into().map_err(|_| {
-                            "digits vector does not have exactly 8 elements".to_string()
-                        })?))
-                    }
-                }
-                // 11 digits is probably a UPC-A with a leading zero that was removed
-                // when the data was stored as a number in another system
-                11 => {
-                    digits.insert(0, 0);
-                    Ok(GTIN::UpcA(digits.try_into().map_err(|_| {
-                        "digits vector does not have exactly 12 elements".to_string()
-                    })?))
+        let result = parse_str(value);
+        if result.is_ok() {
+            telemetry::increment(telemetry::PARSED_OK);
+        }
+        result
+    }
+}
+
+impl TryFrom<u64> for GTIN {
+    type Error = GtinError;
+
+    /// A GTIN stored as an integer, which loses its leading zeros, so the kind is judged
+    /// from the digits left: up to 8 is EAN-8, or UPC-E when all 8 remain and carry the
+    /// check digit of their UPC-A expansion, 9 to 12 is UPC-A, 13 EAN-13 and 14 GTIN-14.
+    /// When the kind is known, use `GTIN::from_canonical_key` instead.
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        let kind = match value.checked_ilog10().unwrap_or(0) + 1 {
+            ..=7 => GtinKind::Ean8,
+            8 => {
+                let digits = key_digits(value).ok_or(GtinError::InvalidPadding)?;
+                let upce: [u8; 8] = core::array::from_fn(|i| digits[i + 6]);
+                if util::validate_upce(&upce) {
+                    return Ok(GTIN::UpcE(upce));
                 }
-                12 => Ok(GTIN::UpcA(digits.try_into().map_err(|_| {
-                    "digits vector does not have exactly 12 elements".to_string()
-                })?)),
-                13 => Ok(GTIN::Ean13(digits.try_into().map_err(|_| {
-                    "digits vector does not have exactly 13 elements".to_string()
-                })?)),
-                14 => Ok(GTIN::Gtin14(digits.try_into().map_err(|_| {
-                    "digits vector does not have exactly 14 elements".to_string()
-                })?)),
-                _ => Err("Unsupported GTIN length".to_string()),
+                GtinKind::Ean8
+            }
+            9..=12 => GtinKind::UpcA,
+            13 => GtinKind::Ean13,
+            14 => GtinKind::Gtin14,
+            len => return Err(GtinError::UnsupportedLength(len as usize)),
+        };
+        GTIN::from_canonical_key(value, kind)
+    }
+}
+
+/// The 14 digits of a key, or None if it has more
+fn key_digits(key: GtinKey) -> Option<[u8; 14]> {
+    let mut digits = [0u8; 14];
+    let mut rest = key;
+    for digit in digits.iter_mut().rev() {
+        *digit = (rest % 10) as u8;
+        rest /= 10;
+    }
+    (rest == 0).then_some(digits)
+}
+
+impl TryFrom<&[u8]> for GTIN {
+    type Error = GtinError;
+
+    /// Parse ASCII bytes, e.g. a scanner SDK's buffer, the way `&str` input is parsed
+    /// but without validating UTF-8 first. For digit values rather than ASCII digits,
+    /// use `GTIN::from_digits`.
+    fn try_from(value: &[u8]) -> Result<Self, Self::Error> {
+        let (identifier, data) = aim::strip_identifier_bytes(value);
+        let result = parse_data(identifier, data);
+        if result.is_ok() {
+            telemetry::increment(telemetry::PARSED_OK);
+        }
+        result
+    }
+}
+
+fn parse_str(value: &str) -> Result<GTIN, GtinError> {
+    let (identifier, data) = aim::strip_identifier(value);
+    parse_data(identifier, data.as_bytes())
+}
+
+/// Parse the data following an optional symbology identifier
+fn parse_data(identifier: Option<&str>, data: &[u8]) -> Result<GTIN, GtinError> {
+    let extracted = util::DigitBuffer::extract(data);
+    let mut digits = extracted.as_slice();
+
+    // GS1 carriers send the element string, the GTIN following AI (01)
+    if identifier.is_some_and(aim::is_gs1_carrier) && digits.len() == 16 && digits[..2] == [0, 1] {
+        digits = &digits[2..];
+    }
+
+    // A symbol the scanner reports as UPC-E is checked the way UPC-E is, through its
+    // UPC-A expansion, rather than with the flat mod-10 check of the 8 digits
+    if identifier.and_then(aim::eight_digit_kind) == Some(GtinKind::UpcE) && digits.len() == 8 {
+        return match <[u8; 8]>::try_from(digits) {
+            Ok(d) if util::validate_upce(&d) => Ok(GTIN::UpcE(d)),
+            _ => {
+                telemetry::increment(telemetry::CHECKSUM_FAILED);
+                Err(GtinError::InvalidChecksum)
             }
+        };
+    }
+
+    if validate_gtin(digits) {
+        // 11 digits is probably a UPC-A with a leading zero that was removed
+        // when the data was stored as a number in another system
+        let mut padded = [0u8; 12];
+        if digits.len() == 11 {
+            telemetry::increment(telemetry::HEURISTIC_PADDED);
+            padded[1..].copy_from_slice(digits);
+            digits = &padded;
+        }
+
+        // Each conversion is the length check, so no kind gets the wrong digit count
+        if let Ok(d) = <[u8; 8]>::try_from(digits) {
+            // Try to determine if it is UPC-E or EAN-8, unless the identifier settled it
+            // Simple heuristic: UPC-E is mostly used in North America and rarely has leading zeroes.
+            // Only codes that also carry the check digit of their UPC-A expansion can be UPC-E.
+            let settled = identifier.and_then(aim::eight_digit_kind).is_some();
+            if !settled && d[0] != 0 && util::validate_upce(&d) {
+                Ok(GTIN::UpcE(d))
+            } else {
+                Ok(GTIN::Ean8(d))
+            }
+        } else if let Ok(d) = <[u8; 12]>::try_from(digits) {
+            Ok(GTIN::UpcA(d))
+        } else if let Ok(d) = <[u8; 13]>::try_from(digits) {
+            Ok(GTIN::Ean13(d))
+        } else if let Ok(d) = <[u8; 14]>::try_from(digits) {
+            Ok(GTIN::Gtin14(d))
         } else {
-            Err("Invalid GTIN checksum".to_string())
+            Err(GtinError::UnsupportedLength(digits.len()))
+        }
+    } else if let Some(d) = upce_only(identifier, digits) {
+        Ok(GTIN::UpcE(d))
+    } else {
+        // Lengths validate_gtin does not support are reported the same way, but they
+        // are not checksum failures as far as data quality is concerned
+        if (8..=14).contains(&digits.len()) {
+            telemetry::increment(telemetry::CHECKSUM_FAILED);
+        }
+        Err(GtinError::InvalidChecksum)
+    }
+}
+
+/// Eight digits that fail the flat mod-10 check but are a UPC-E, whose check digit is that
+/// of its UPC-A expansion, unless the identifier says they are EAN-8
+fn upce_only(identifier: Option<&str>, digits: &[u8]) -> Option<[u8; 8]> {
+    let digits = <[u8; 8]>::try_from(digits).ok()?;
+    let settled = identifier.and_then(aim::eight_digit_kind).is_some();
+    (!settled && util::validate_upce(&digits)).then_some(digits)
+}
+
+/// Whether the first 8 digits are a UPC-E: number system 0 or 1, and the check digit of
+/// their UPC-A expansion
+const fn is_upce(digits: &[u8; 14]) -> bool {
+    if digits[0] > 1 {
+        return false;
+    }
+    let body = [digits[1], digits[2], digits[3], digits[4], digits[5], digits[6]];
+    let expanded = util::upce::expand(&body);
+    let mut upca = [digits[0]; 11];
+    let mut i = 0;
+    while i < 10 {
+        upca[i + 1] = expanded[i];
+        i += 1;
+    }
+    util::calculate_checksum_digit(&upca) == digits[7]
+}
+
+// The constructor arrays must hold exactly `GtinKind::digit_count` digits
+const _: () = {
+    assert!(GtinKind::UpcE.digit_count() == 8);
+    assert!(GtinKind::UpcA.digit_count() == 12);
+    assert!(GtinKind::Ean8.digit_count() == 8);
+    assert!(GtinKind::Ean13.digit_count() == 13);
+    assert!(GtinKind::Gtin14.digit_count() == 14);
+};
+
+// The kind leaves a niche, so an absent GTIN costs nothing
+const _: () = assert!(size_of::<Option<GTIN>>() == size_of::<GTIN>());
+
+/// Constructors named after the kinds, for digits known to be valid, e.g. in constants.
+/// Use `from_digits` to get an error instead.
+///
+/// # Panics
+///
+/// If a digit is above 9 or the check digit does not match.
+#[allow(non_snake_case)]
+impl GTIN {
+    pub const fn UpcE(digits: [u8; 8]) -> GTIN {
+        GTIN::checked(GtinKind::UpcE, &digits)
+    }
+
+    pub const fn UpcA(digits: [u8; 12]) -> GTIN {
+        GTIN::checked(GtinKind::UpcA, &digits)
+    }
+
+    pub const fn Ean8(digits: [u8; 8]) -> GTIN {
+        GTIN::checked(GtinKind::Ean8, &digits)
+    }
+
+    pub const fn Ean13(digits: [u8; 13]) -> GTIN {
+        GTIN::checked(GtinKind::Ean13, &digits)
+    }
+
+    pub const fn Gtin14(digits: [u8; 14]) -> GTIN {
+        GTIN::checked(GtinKind::Gtin14, &digits)
+    }
+}
+
+/// The digits when the GTIN is of the named kind, for matching on the kind and digits
+/// together, e.g. `if let Some([0, 7, ..]) = gtin.as_upca_digits()`.
+impl GTIN {
+    pub fn as_upce_digits(&self) -> Option<[u8; 8]> {
+        self.digits_of(GtinKind::UpcE)
+    }
+
+    pub fn as_upca_digits(&self) -> Option<[u8; 12]> {
+        self.digits_of(GtinKind::UpcA)
+    }
+
+    pub fn as_ean8_digits(&self) -> Option<[u8; 8]> {
+        self.digits_of(GtinKind::Ean8)
+    }
+
+    pub fn as_ean13_digits(&self) -> Option<[u8; 13]> {
+        self.digits_of(GtinKind::Ean13)
+    }
+
+    pub fn as_gtin14_digits(&self) -> Option<[u8; 14]> {
+        self.digits_of(GtinKind::Gtin14)
+    }
+
+    fn digits_of<const N: usize>(&self, kind: GtinKind) -> Option<[u8; N]> {
+        match self.kind == kind {
+            true => self.digits().try_into().ok(),
+            false => None,
         }
     }
 }
 
 impl GTIN {
+    /// `new`, panicking unless the digits are 0 to 9 and end in a valid check digit
+    const fn checked(kind: GtinKind, digits: &[u8]) -> GTIN {
+        let mut i = 0;
+        while i < digits.len() {
+            assert!(digits[i] <= 9, "GTIN digits must be 0 to 9");
+            i += 1;
+        }
+        let gtin = GTIN::new(kind, digits);
+        // UPC-E carries the check digit of its UPC-A expansion
+        let valid = match kind {
+            GtinKind::UpcE => is_upce(&gtin.digits),
+            _ => util::validate_check_digit(digits),
+        };
+        assert!(valid, "Invalid GTIN checksum");
+        gtin
+    }
+
+    /// The first `kind.digit_count()` of the digits, which must have at least that many
+    const fn new(kind: GtinKind, digits: &[u8]) -> GTIN {
+        let len = kind.digit_count();
+        let mut buffer = [0; 14];
+        let mut i = 0;
+        while i < len {
+            buffer[i] = digits[i];
+            i += 1;
+        }
+        GTIN {
+            digits: buffer,
+            len: len as u8,
+            kind,
+        }
+    }
+
+    /// Build a GTIN of the given kind from individual digit values, validating the
+    /// digit count, that each value is in 0..=9, and the check digit. At most one digit
+    /// more than the kind holds is read, so an endless iterator fails with `found` one
+    /// past the digit count.
+    pub fn from_digits<I>(kind: GtinKind, digits: I) -> Result<GTIN, GtinError>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        let expected = kind.digit_count();
+        let mut buffer = [0u8; 14];
+        let mut count = 0;
+        for digit in digits.into_iter().take(expected + 1) {
+            if digit > 9 {
+                return Err(GtinError::InvalidDigit(digit));
+            }
+            if count < expected {
+                buffer[count] = digit;
+            }
+            count += 1;
+        }
+
+        if count != expected {
+            return Err(GtinError::InvalidLength { kind, found: count });
+        }
+        // UPC-E carries the check digit of its UPC-A expansion
+        let valid = match kind {
+            GtinKind::UpcE => util::validate_upce(&buffer[..expected]),
+            _ => util::validate_check_digit(&buffer[..expected]),
+        };
+        if !valid {
+            return Err(GtinError::InvalidChecksum);
+        }
+
+        Ok(GTIN::new(kind, &buffer))
+    }
+
     pub fn digits(&self) -> &[u8] {
-        match self {
-            GTIN::UpcE(digits) => digits,
-            GTIN::UpcA(digits) => digits,
-            GTIN::Ean8(digits) => digits,
-            GTIN::Ean13(digits) => digits,
-            GTIN::Gtin14(digits) => digits,
+        &self.digits[..usize::from(self.len)]
+    }
+
+    /// The digit array, as digit values 0 to 9 rather than ASCII
+    pub fn as_bytes(&self) -> &[u8] {
+        self.digits()
+    }
+
+    pub fn kind(&self) -> GtinKind {
+        self.kind
+    }
+
+    /// The zero-padded GTIN-14 value packed into a u64. UPC-E is expanded to UPC-A first,
+    /// as GS1 requires when storing it in a 14-digit field.
+    pub fn canonical_key(&self) -> GtinKey {
+        let to_key = |digits: &[u8]| digits.iter().fold(0, |acc, &d| acc * 10 + d as u64);
+        match self.kind {
+            GtinKind::UpcE => to_key(&self.upce_as_upca()),
+            _ => to_key(self.digits()),
         }
     }
 
-    pub fn as_ean13(self) -> Option<GTIN> {
-        match self {
-            GTIN::Ean13(_) => Some(self),
-            GTIN::UpcA(digits) => {
-                let mut ean13_digits = [0; 13]; // Initialize all elements to zero
-                ean13_digits[1..13].copy_from_slice(&digits[0..12]); // Copy UPC-A digits, including the check digit
-                Some(GTIN::Ean13(ean13_digits))
+    /// Parse in a const context, as `gtin!` does. Takes the full digits, optionally
+    /// separated by spaces, hyphens or dots, and reads 8 digits as UPC-E or EAN-8 the way
+    /// `TryFrom<&str>` does; other characters, such as a symbology identifier, fail with
+    /// `InvalidDigit` and no missing leading zero is restored.
+    pub const fn parse_const(value: &str) -> Result<GTIN, GtinError> {
+        let bytes = value.as_bytes();
+        let mut buffer = [0u8; 14];
+        let mut count = 0;
+        let mut i = 0;
+        while i < bytes.len() {
+            match bytes[i] {
+                digit @ b'0'..=b'9' => {
+                    if count < buffer.len() {
+                        buffer[count] = digit - b'0';
+                    }
+                    count += 1;
+                }
+                b' ' | b'-' | b'.' => {}
+                other => return Err(GtinError::InvalidDigit(other)),
+            }
+            i += 1;
+        }
+        if !matches!(count, 8 | 12 | 13 | 14) {
+            return Err(GtinError::UnsupportedLength(count));
+        }
+
+        // Eight digits are read as `TryFrom<&str>` reads them: UPC-E when they fail the
+        // flat check but not their UPC-A expansion's, or pass both without a leading zero
+        let flat = validate_gtin(buffer.split_at(count).0);
+        let kind = match count {
+            8 if is_upce(&buffer) && (!flat || buffer[0] != 0) => GtinKind::UpcE,
+            _ if !flat => return Err(GtinError::InvalidChecksum),
+            8 => GtinKind::Ean8,
+            12 => GtinKind::UpcA,
+            13 => GtinKind::Ean13,
+            _ => GtinKind::Gtin14,
+        };
+        Ok(GTIN::new(kind, &buffer))
+    }
+
+    /// Whether both identify the same product: a UPC-A, its zero-padded EAN-13 and
+    /// GTIN-14 forms and any UPC-E that expands to it. `==` compares kind and digits.
+    pub fn same_product(&self, other: &GTIN) -> bool {
+        self.canonical_key() == other.canonical_key()
+    }
+
+    /// The next GTIN of the same kind under the first `prefix_len` digits, e.g. a company
+    /// prefix (after a GTIN-14's indicator), with the check digit recomputed. None once the
+    /// item reference is all nines, and for UPC-E, which has no item reference of its own.
+    pub fn successor(&self, prefix_len: usize) -> Option<GTIN> {
+        self.step(prefix_len, true)
+    }
+
+    /// The previous GTIN under the first `prefix_len` digits, as `successor` counts. None
+    /// once the item reference is all zeros.
+    pub fn predecessor(&self, prefix_len: usize) -> Option<GTIN> {
+        self.step(prefix_len, false)
+    }
+
+    fn step(&self, prefix_len: usize, up: bool) -> Option<GTIN> {
+        if self.kind() == GtinKind::UpcE {
+            return None;
+        }
+        let len = self.digits().len();
+        let mut digits = [0u8; 14];
+        digits[..len].copy_from_slice(self.digits());
+
+        // Carry or borrow leftwards from the digit before the check digit
+        let (wrap_from, wrap_to) = if up { (9, 0) } else { (0, 9) };
+        let item_reference = digits.get_mut(prefix_len..len - 1)?;
+        let digit = item_reference.iter_mut().rev().find_map(|digit| {
+            if *digit == wrap_from {
+                *digit = wrap_to;
+                None
+            } else {
+                Some(digit)
+            }
+        })?;
+        *digit = if up { *digit + 1 } else { *digit - 1 };
+        digits[len - 1] = util::calculate_checksum_digit(&digits[..len - 1]);
+        GTIN::from_digits(self.kind(), digits[..len].iter().copied()).ok()
+    }
+
+    /// The GTIN of `kind` with this canonical key, the inverse of `canonical_key`. Keys
+    /// with more digits than the kind holds, and UPC-A keys that cannot be zero-suppressed
+    /// when `kind` is UPC-E, fail with `InvalidPadding`, as do EAN-8 keys (six leading
+    /// zeros) for UPC-A, UPC-E and EAN-13. Where a UPC-A has several UPC-E forms, the one
+    /// `compress_upca_to_upce` gives is returned.
+    pub fn from_canonical_key(key: GtinKey, kind: GtinKind) -> Result<GTIN, GtinError> {
+        let digits = key_digits(key).ok_or(GtinError::InvalidPadding)?;
+        // UPC-E is keyed by its UPC-A expansion
+        let width = match kind {
+            GtinKind::UpcE => GtinKind::UpcA.digit_count(),
+            _ => kind.digit_count(),
+        };
+        let (padding, digits) = digits.split_at(14 - width);
+        if padding.iter().any(|&d| d != 0) {
+            return Err(GtinError::InvalidPadding);
+        }
+        // an EAN-8 only becomes a GTIN-14, so nothing else is keyed in its range
+        let ean8_range = GtinKind::Ean8.keys();
+        if ean8_range.contains(&key) && !matches!(kind, GtinKind::Ean8 | GtinKind::Gtin14) {
+            return Err(GtinError::InvalidPadding);
+        }
+
+        match kind {
+            GtinKind::UpcE if !util::validate_check_digit(digits) => {
+                Err(GtinError::InvalidChecksum)
+            }
+            GtinKind::UpcE => {
+                util::compress_upca_to_upce(digits).map_err(|_| GtinError::InvalidPadding)
+            }
+            _ => GTIN::from_digits(kind, digits.iter().copied()),
+        }
+    }
+
+    /// The digits as an integer, the way numeric columns store them. Unlike
+    /// `canonical_key`, UPC-E is not expanded.
+    pub fn to_u64(&self) -> u64 {
+        self.digits().iter().fold(0, |acc, &d| acc * 10 + d as u64)
+    }
+
+    /// The 13-digit form, zero-padding UPC-A and expanding UPC-E first. GTIN-14 converts
+    /// only for indicator 0, and EAN-8 has no 13-digit form.
+    pub fn as_ean13(self) -> Result<GTIN, ConversionError> {
+        match (self.kind, self.digits()) {
+            (GtinKind::Ean13, _) => Ok(self),
+            (GtinKind::UpcE, _) => GTIN::UpcA(self.upce_as_upca()).as_ean13(),
+            // Right-aligning in 13 digits pads UPC-A with a zero and drops the zero indicator
+            (GtinKind::UpcA, digits) | (GtinKind::Gtin14, digits @ [0, ..]) => {
+                Ok(GTIN::from_right_aligned(GtinKind::Ean13, digits))
             }
-            _ => None, // For other GTIN types, we return None TODO: Implement conversion for other GTIN types
+            (GtinKind::Gtin14, digits) => Err(ConversionError::NonZeroIndicator(digits[0])),
+            (GtinKind::Ean8, _) => Err(ConversionError::NotRepresentable {
+                from: GtinKind::Ean8,
+                to: GtinKind::Ean13,
+            }),
+        }
+    }
+
+    /// The same product as `kind`: `as_ean13` for EAN-13, otherwise the GTIN of `kind`
+    /// with the same canonical key. EAN-8 converts only to GTIN-14, GTIN-14 only from
+    /// indicator 0, and UPC-E only from codes that can be zero-suppressed.
+    pub fn convert_to(self, kind: GtinKind) -> Result<GTIN, ConversionError> {
+        match (kind, self.digits()) {
+            (GtinKind::Ean13, _) => self.as_ean13(),
+            _ if kind == self.kind => Ok(self),
+            (_, digits @ [1..=9, ..]) if self.kind == GtinKind::Gtin14 => {
+                Err(ConversionError::NonZeroIndicator(digits[0]))
+            }
+            _ => GTIN::from_canonical_key(self.canonical_key(), kind).map_err(|_| {
+                ConversionError::NotRepresentable {
+                    from: self.kind,
+                    to: kind,
+                }
+            }),
         }
     }
 
+    /// The last `kind.digit_count()` digits, zero-padded on the left
+    fn from_right_aligned(kind: GtinKind, digits: &[u8]) -> GTIN {
+        let len = kind.digit_count();
+        let mut buffer = [0; 14];
+        let kept = digits.len().min(len);
+        buffer[len - kept..len].copy_from_slice(&digits[digits.len() - kept..]);
+        GTIN::new(kind, &buffer)
+    }
+
+    /// The UPC-A digits of a UPC-E, keeping its number system digit. Unlike
+    /// `expand_upce_to_upca` this cannot fail, so it also covers values built directly.
+    fn upce_as_upca(&self) -> [u8; 12] {
+        let digits = &self.digits;
+        util::upca_digits(digits[0], &core::array::from_fn(|i| digits[i + 1]))
+    }
+
+    /// Strip the zero padding from EAN-13 and GTIN-14 values that are really UPC-A,
+    /// for legacy US retail systems that expect 12 digits. Other values are unchanged.
+    pub fn prefer_upc_a(self) -> GTIN {
+        match (self.kind, self.digits()) {
+            (GtinKind::Ean13, digits @ [0, ..]) | (GtinKind::Gtin14, digits @ [0, 0, ..]) => {
+                GTIN::from_right_aligned(GtinKind::UpcA, digits)
+            }
+            _ => self,
+        }
+    }
+
+    /// Country of the GS1 member organisation that issued the prefix, see `PREFIX_DATA_VERSION`
     pub fn country_code(&self) -> Option<&'static str> {
         // TODO: implement strong types? https://github.com/rust-iso/rust_iso3166
         match self.number_system() {
@@ -110,7 +912,8 @@ impl GTIN {
             | NumberSystem::Refund => None, // No country for these codes
             _ => {
                 let prefix = self
-                    .as_ean13()?
+                    .as_ean13()
+                    .ok()?
                     .digits()
                     .iter()
                     .take(3)
@@ -169,39 +972,51 @@ impl GTIN {
 
     pub fn number_system(&self) -> NumberSystem {
         match self.as_ean13() {
-            Some(gtin) => NumberSystem::from_ean13_prefix(&gtin.digits()[0..3]),
-            None => NumberSystem::Unknown,
+            Ok(gtin) => NumberSystem::from_ean13_prefix(&gtin.digits()[0..3]),
+            Err(_) => NumberSystem::Unknown,
         }
     }
 }
 
+/// The digit string in human-readable formats such as JSON. Binary formats such as
+/// bincode or postcard get a u64 packed as `GtinColumn` packs it, a kind nibble above the
+/// value of the digits, which keeps the kind and takes half the space.
+#[cfg(feature = "serde")]
 impl Serialize for GTIN {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let s = match *self {
-            GTIN::UpcE(digits) | GTIN::Ean8(digits) => digits_to_string(&digits),
-            GTIN::UpcA(digits) => digits_to_string(&digits),
-            GTIN::Ean13(digits) => digits_to_string(&digits),
-            GTIN::Gtin14(digits) => digits_to_string(&digits),
-        };
-        serializer.serialize_str(&s)
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.format(FormatStyle::Plain))
+        } else {
+            serializer.serialize_u64(column::pack(self))
+        }
     }
 }
 
+/// Human-readable formats give the digit string, parsed as `TryFrom<&str>` parses it.
+/// Integers are rejected, since a format that guesses field types, such as CSV, reads
+/// `0071720539774` as the UPC-A's number; `serde::lenient` accepts them for feeds that
+/// send bare numbers.
+#[cfg(feature = "serde")]
 impl<'de> Deserialize<'de> for GTIN {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        GTIN::try_from(s.as_str()).map_err(serde::de::Error::custom)
+        if !deserializer.is_human_readable() {
+            let word = u64::deserialize(deserializer)?;
+            return column::try_unpack(word)
+                .ok_or_else(|| ::serde::de::Error::custom("Invalid packed GTIN"));
+        }
+        serde::deserialize_str(deserializer, |s| GTIN::try_from(s))
     }
 }
 
 // TODO: Add tests for all number systems
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum NumberSystem {
     General,
     StoreUse,
@@ -234,5 +1049,5 @@ impl NumberSystem {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 pub mod tests;
@@ -0,0 +1,30 @@
+/// A GTIN from a string, parsed at compile time by `GTIN::parse_const` so a wrong check
+/// digit or length fails the build instead of a test run.
+///
+/// ```
+/// use gtin::{gtin, GTIN};
+///
+/// const OREO: GTIN = gtin!("0 71720 53977 4");
+/// assert_eq!(OREO, GTIN::try_from("071720539774").unwrap());
+/// ```
+///
+/// ```compile_fail
+/// const OREO: gtin::GTIN = gtin::gtin!("0 71720 53977 5");
+/// ```
+#[macro_export]
+macro_rules! gtin {
+    ($value:expr) => {
+        const {
+            match $crate::GTIN::parse_const($value) {
+                ::core::result::Result::Ok(gtin) => gtin,
+                ::core::result::Result::Err($crate::GtinError::InvalidChecksum) => {
+                    ::core::panic!("invalid GTIN check digit")
+                }
+                ::core::result::Result::Err($crate::GtinError::InvalidDigit(_)) => {
+                    ::core::panic!("GTIN literals hold digits, spaces, hyphens and dots")
+                }
+                ::core::result::Result::Err(_) => ::core::panic!("unsupported GTIN length"),
+            }
+        }
+    };
+}
@@ -0,0 +1,161 @@
+//! A GTIN field type for moving a free-form string field to GTIN typing in phases: old
+//! values of any shape are accepted and kept, and what they were parsed as is recorded
+//! alongside, so clients can be switched to the canonical form when they are ready.
+
+#[cfg(feature = "serde")]
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::de::{self, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::util::Digits;
+use crate::{GtinError, ParseWarning, GTIN};
+
+/// What a `MigratingGtin` is serialized as
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum EchoMode {
+    #[default]
+    Original, // the value exactly as it was received
+    Canonical, // the parsed GTIN's digits, or the original when it did not parse
+}
+
+/// How a legacy value parsed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MigrationStatus {
+    Clean(GTIN),
+    Repaired(GTIN, Vec<ParseWarning>), // parsed, but only through the parser's heuristics
+    Invalid(GtinError),
+}
+
+/// A legacy GTIN value together with what it parsed as
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MigratingGtin {
+    original: String,
+    status: MigrationStatus,
+    mode: EchoMode,
+}
+
+impl MigratingGtin {
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+
+    pub fn status(&self) -> &MigrationStatus {
+        &self.status
+    }
+
+    pub fn gtin(&self) -> Option<&GTIN> {
+        match &self.status {
+            MigrationStatus::Clean(gtin) | MigrationStatus::Repaired(gtin, _) => Some(gtin),
+            MigrationStatus::Invalid(_) => None,
+        }
+    }
+
+    /// Whether the value parsed without any of the parser's heuristics
+    pub fn is_clean(&self) -> bool {
+        matches!(self.status, MigrationStatus::Clean(_))
+    }
+
+    pub fn mode(&self) -> EchoMode {
+        self.mode
+    }
+
+    pub fn with_mode(mut self, mode: EchoMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn set_mode(&mut self, mode: EchoMode) {
+        self.mode = mode;
+    }
+}
+
+impl From<&str> for MigratingGtin {
+    fn from(value: &str) -> Self {
+        let (result, mut warnings) = GTIN::parse_with_report(value);
+        // a restricted prefix describes the GTIN, not a repair of the input
+        warnings.retain(|warning| !matches!(warning, ParseWarning::RestrictedPrefix(_)));
+        let status = match (result, warnings) {
+            (Ok(gtin), warnings) if warnings.is_empty() => MigrationStatus::Clean(gtin),
+            (Ok(gtin), warnings) => MigrationStatus::Repaired(gtin, warnings),
+            (Err(e), _) => MigrationStatus::Invalid(e),
+        };
+        MigratingGtin {
+            original: value.to_string(),
+            status,
+            mode: EchoMode::default(),
+        }
+    }
+}
+
+impl From<GTIN> for MigratingGtin {
+    fn from(gtin: GTIN) -> Self {
+        MigratingGtin {
+            original: Digits(gtin.digits()).to_string(),
+            status: MigrationStatus::Clean(gtin),
+            mode: EchoMode::default(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for MigratingGtin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match (self.mode, self.gtin()) {
+            (EchoMode::Canonical, Some(gtin)) => gtin.serialize(serializer),
+            _ => serializer.serialize_str(&self.original),
+        }
+    }
+}
+
+/// For `#[serde(serialize_with = "...")]`, to write a field canonically whatever the mode
+/// of its values
+#[cfg(feature = "serde")]
+pub fn serialize_canonical<S>(value: &MigratingGtin, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value.gtin() {
+        Some(gtin) => gtin.serialize(serializer),
+        None => serializer.serialize_str(&value.original),
+    }
+}
+
+/// Legacy fields hold strings, but some stored GTINs as numbers
+#[cfg(feature = "serde")]
+struct MigratingVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for MigratingVisitor {
+    type Value = MigratingGtin;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string or an unsigned integer")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(MigratingGtin::from(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(MigratingGtin::from(value.to_string().as_str()))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for MigratingGtin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(MigratingVisitor)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,74 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::{MigratingGtin, MigrationStatus};
+use crate::{GtinError, ParseWarning, GTIN};
+
+#[test]
+fn record_status() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+
+    let clean = MigratingGtin::from("071720539774");
+    assert_eq!(clean.status(), &MigrationStatus::Clean(upca));
+    assert!(clean.is_clean());
+
+    let repaired = MigratingGtin::from("71720539774");
+    assert_eq!(
+        repaired.status(),
+        &MigrationStatus::Repaired(upca, vec![ParseWarning::LeadingZeroInserted])
+    );
+    assert_eq!(repaired.gtin(), Some(&upca));
+    assert_eq!(repaired.original(), "71720539774");
+
+    let invalid = MigratingGtin::from("see supplier sheet");
+    assert!(matches!(invalid.status(), MigrationStatus::Invalid(_)));
+    assert_eq!(invalid.gtin(), None);
+    assert_eq!(
+        MigratingGtin::from("071720539775").status(),
+        &MigrationStatus::Invalid(GtinError::InvalidChecksum)
+    );
+}
+
+#[test]
+fn restricted_prefix_is_not_a_repair() {
+    let store = GTIN::UpcA([2, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 9]);
+    assert_eq!(
+        MigratingGtin::from("212345678909").status(),
+        &MigrationStatus::Clean(store)
+    );
+    assert_eq!(
+        MigratingGtin::from("2 12345 67890 9 hello").status(),
+        &MigrationStatus::Repaired(store, vec![ParseWarning::IgnoredCharacters(5)])
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn echo_modes() {
+    use super::EchoMode;
+
+    #[derive(Serialize, Deserialize)]
+    struct Product {
+        gtin: MigratingGtin,
+        #[serde(serialize_with = "super::serialize_canonical")]
+        case_gtin: MigratingGtin,
+    }
+
+    let json = r#"{"gtin":"0 71720-53977 4","case_gtin":71720539774}"#;
+    let mut product: Product = serde_json::from_str(json).unwrap();
+    assert_eq!(product.case_gtin.original(), "71720539774"); // a number in the legacy data
+    assert_eq!(
+        serde_json::to_string(&product).unwrap(),
+        r#"{"gtin":"0 71720-53977 4","case_gtin":"071720539774"}"#
+    );
+
+    product.gtin.set_mode(EchoMode::Canonical);
+    assert_eq!(
+        serde_json::to_string(&product.gtin).unwrap(),
+        r#""071720539774""#
+    );
+
+    // Values that did not parse are always echoed
+    let invalid = MigratingGtin::from("TBD").with_mode(EchoMode::Canonical);
+    assert_eq!(serde_json::to_string(&invalid).unwrap(), r#""TBD""#);
+}
@@ -0,0 +1,65 @@
+//! Kotlin and Swift bindings through UniFFI, enabled by the `uniffi` feature, so scanner
+//! apps validate codes the way the backend does. Codes are passed as strings, and the
+//! digits of results are returned as strings; `GtinKind` is exported as an enum.
+
+use crate::{GtinKind, GTIN};
+
+/// Why a code was rejected, thrown as an exception by the generated bindings
+#[derive(Debug, PartialEq, Eq, Clone, uniffi::Error)]
+pub enum MobileError {
+    InvalidGtin { reason: String },
+    NotConvertible { from: GtinKind, to: GtinKind },
+}
+
+impl std::fmt::Display for MobileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MobileError::InvalidGtin { reason } => write!(f, "{}", reason),
+            MobileError::NotConvertible { from, to } => {
+                write!(f, "{:?} cannot be converted to {:?}", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MobileError {}
+
+fn parse_gtin(input: &str) -> Result<GTIN, MobileError> {
+    GTIN::try_from(input).map_err(|e| MobileError::InvalidGtin {
+        reason: e.to_string(),
+    })
+}
+
+/// The digits of the GTIN the input parses as, e.g. `"071720539774"` for `"0 71720 53977 4"`
+#[uniffi::export]
+pub fn parse(input: &str) -> Result<String, MobileError> {
+    parse_gtin(input).map(String::from)
+}
+
+/// Whether the input parses as a GTIN
+#[uniffi::export]
+pub fn validate(input: &str) -> bool {
+    GTIN::try_from(input).is_ok()
+}
+
+/// The digits of the input as `to`, see `GTIN::convert_to`
+#[uniffi::export]
+pub fn convert(input: &str, to: GtinKind) -> Result<String, MobileError> {
+    let gtin = parse_gtin(input)?;
+    gtin.convert_to(to)
+        .map(String::from)
+        .map_err(|_| MobileError::NotConvertible {
+            from: gtin.kind(),
+            to,
+        })
+}
+
+/// Country of the GS1 member organisation that issued the prefix, or null for prefixes
+/// without one
+#[uniffi::export]
+pub fn country_code(input: &str) -> Result<Option<String>, MobileError> {
+    Ok(parse_gtin(input)?.country_code().map(String::from))
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,45 @@
+use super::{convert, country_code, parse, validate, MobileError};
+use crate::GtinKind;
+
+#[test]
+fn mobile_bindings() {
+    assert_eq!(parse("0 71720 53977 4"), Ok("071720539774".to_string()));
+    assert_eq!(
+        parse("071720539775"),
+        Err(MobileError::InvalidGtin {
+            reason: "Invalid GTIN checksum".to_string()
+        })
+    );
+    assert!(validate("4006381333931"));
+    assert!(!validate("4006381333932"));
+
+    assert_eq!(
+        convert("071720539774", GtinKind::Ean13),
+        Ok("0071720539774".to_string())
+    );
+    assert_eq!(
+        convert("96385074", GtinKind::Gtin14),
+        Ok("00000096385074".to_string())
+    );
+    assert_eq!(
+        convert("96385074", GtinKind::Ean13),
+        Err(MobileError::NotConvertible {
+            from: GtinKind::Ean8,
+            to: GtinKind::Ean13
+        })
+    );
+    assert_eq!(
+        convert("96385074", GtinKind::UpcA),
+        Err(MobileError::NotConvertible {
+            from: GtinKind::Ean8,
+            to: GtinKind::UpcA
+        })
+    );
+    assert_eq!(
+        convert("071720539774", GtinKind::UpcE).map_err(|e| e.to_string()),
+        Err("UpcA cannot be converted to UpcE".to_string())
+    );
+
+    assert_eq!(country_code("4006381333931"), Ok(Some("DE".to_string())));
+    assert!(country_code("4006381333932").is_err());
+}
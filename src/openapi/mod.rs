@@ -0,0 +1,28 @@
+//! `ToSchema` for `GTIN`, so GTIN fields of utoipa-documented APIs appear in the OpenAPI
+//! document as the digit strings they are serialized as.
+
+use utoipa::openapi::schema::{ObjectBuilder, Schema, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::GTIN;
+
+impl PartialSchema for GTIN {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(
+                "A GTIN as its digits: EAN-8, UPC-E, UPC-A, EAN-13 or GTIN-14",
+            ))
+            .min_length(Some(8))
+            .max_length(Some(14))
+            .pattern(Some("^([0-9]{8}|[0-9]{12,14})$"))
+            .examples(["4006381333931"])
+            .into()
+    }
+}
+
+impl ToSchema for GTIN {}
+
+#[cfg(test)]
+pub mod tests;
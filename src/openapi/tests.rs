@@ -0,0 +1,24 @@
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::GTIN;
+
+#[test]
+fn gtin_schema() {
+    assert_eq!(GTIN::name(), "GTIN");
+
+    let schema = serde_json::to_value(GTIN::schema()).unwrap();
+    assert_eq!(schema["type"], "string");
+    assert_eq!(schema["minLength"], 8);
+    assert_eq!(schema["maxLength"], 14);
+
+    // The example and the serialized form of every kind match the pattern's lengths
+    let example = schema["examples"][0].as_str().unwrap();
+    assert!(GTIN::try_from(example).is_ok());
+    for input in ["96385074", "10000052", "071720539774", "10071720539771"] {
+        let gtin = GTIN::try_from(input).unwrap();
+        let json = serde_json::to_value(gtin).unwrap();
+        let digits = json.as_str().unwrap();
+        assert!(matches!(digits.len(), 8 | 12..=14), "{}", digits);
+        assert!(digits.bytes().all(|b| b.is_ascii_digit()), "{}", digits);
+    }
+}
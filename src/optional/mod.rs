@@ -0,0 +1,149 @@
+#[cfg(feature = "serde")]
+use std::fmt;
+
+#[cfg(feature = "serde")]
+use serde::de::{self, Visitor};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+#[cfg(feature = "serde")]
+use crate::column;
+use crate::{GtinError, GTIN};
+
+/// Sentinel strings marketplaces use to flag a GTIN-exempt listing
+const EXEMPT_SENTINELS: [&str; 5] = ["n/a", "na", "none", "exempt", "-"];
+
+/// The compact serde form of `Exempt`, a word no packed GTIN has
+#[cfg(feature = "serde")]
+const EXEMPT_WORD: u64 = u64::MAX;
+
+/// A GTIN field that may legitimately be absent, as found in marketplace feeds
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub enum OptionalGtin {
+    Present(GTIN),
+    Exempt, // "N/A", "exempt", all zeros and similar
+    #[default]
+    Missing, // empty string, whitespace or null
+    Invalid(GtinError),
+}
+
+impl OptionalGtin {
+    pub fn gtin(&self) -> Option<&GTIN> {
+        match self {
+            OptionalGtin::Present(gtin) => Some(gtin),
+            _ => None,
+        }
+    }
+
+    pub fn is_exempt(&self) -> bool {
+        matches!(self, OptionalGtin::Exempt)
+    }
+}
+
+impl From<&str> for OptionalGtin {
+    fn from(value: &str) -> Self {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return OptionalGtin::Missing;
+        }
+
+        if EXEMPT_SENTINELS
+            .iter()
+            .any(|sentinel| trimmed.eq_ignore_ascii_case(sentinel))
+        {
+            return OptionalGtin::Exempt;
+        }
+
+        // All zeros (with or without separators) is the usual numeric placeholder
+        let digits = crate::util::extract_digits(trimmed);
+        if !digits.is_empty() && digits.iter().all(|&d| d == 0) {
+            return OptionalGtin::Exempt;
+        }
+
+        match GTIN::try_from(trimmed) {
+            Ok(gtin) => OptionalGtin::Present(gtin),
+            Err(e) => OptionalGtin::Invalid(e),
+        }
+    }
+}
+
+impl From<GTIN> for OptionalGtin {
+    fn from(gtin: GTIN) -> Self {
+        OptionalGtin::Present(gtin)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for OptionalGtin {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Some and None, so formats that tag options read back what was written
+        match self {
+            OptionalGtin::Present(gtin) => serializer.serialize_some(gtin),
+            OptionalGtin::Exempt if serializer.is_human_readable() => {
+                serializer.serialize_some("N/A")
+            }
+            OptionalGtin::Exempt => serializer.serialize_some(&EXEMPT_WORD),
+            OptionalGtin::Missing => serializer.serialize_none(),
+            OptionalGtin::Invalid(e) => Err(serde::ser::Error::custom(format!(
+                "cannot serialize invalid GTIN: {}",
+                e
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for OptionalGtin {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_option(OptionalVisitor)
+    }
+}
+
+/// Reads the string borrowed from the input when the format allows
+#[cfg(feature = "serde")]
+struct OptionalVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for OptionalVisitor {
+    type Value = OptionalGtin;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(OptionalGtin::Missing)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(OptionalGtin::Missing)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            return deserializer.deserialize_str(self);
+        }
+        match u64::deserialize(deserializer)? {
+            EXEMPT_WORD => Ok(OptionalGtin::Exempt),
+            word => column::try_unpack(word)
+                .map(OptionalGtin::Present)
+                .ok_or_else(|| de::Error::custom("Invalid packed GTIN")),
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(OptionalGtin::from(value))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
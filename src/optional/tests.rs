@@ -0,0 +1,108 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::OptionalGtin;
+use crate::{GtinError, GTIN};
+
+#[test]
+fn parse_sentinels() {
+    let cases = vec![
+        ("", OptionalGtin::Missing),
+        ("   ", OptionalGtin::Missing),
+        ("N/A", OptionalGtin::Exempt),
+        ("exempt", OptionalGtin::Exempt),
+        ("0000000000000", OptionalGtin::Exempt),
+        ("00000 00000 00", OptionalGtin::Exempt),
+        (
+            "0 71720 53977 4",
+            OptionalGtin::Present(GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])),
+        ),
+        (
+            "071720539775",
+            OptionalGtin::Invalid(GtinError::InvalidChecksum),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(
+            OptionalGtin::from(input),
+            expected,
+            "Failed to match input: {:?}",
+            input
+        );
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Listing {
+    name: String,
+    #[serde(default)]
+    gtin: OptionalGtin,
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_deserialize_listings() {
+    let cases = vec![
+        (r#"{"name":"Oreo","gtin":"071720539774"}"#, true, false),
+        (r#"{"name":"Handmade","gtin":"N/A"}"#, false, true),
+        (r#"{"name":"Handmade","gtin":null}"#, false, false),
+        (r#"{"name":"Handmade"}"#, false, false),
+    ];
+
+    for (json, present, exempt) in cases {
+        let listing: Listing = serde_json::from_str(json).unwrap();
+        assert_eq!(listing.gtin.gtin().is_some(), present, "{}", json);
+        assert_eq!(listing.gtin.is_exempt(), exempt, "{}", json);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn json_serialize_listings() {
+    let listing = Listing {
+        name: "Handmade".to_string(),
+        gtin: OptionalGtin::Exempt,
+    };
+    assert_eq!(
+        serde_json::to_string(&listing).unwrap(),
+        r#"{"name":"Handmade","gtin":"N/A"}"#
+    );
+
+    let listing = Listing {
+        name: "Handmade".to_string(),
+        gtin: OptionalGtin::Invalid(GtinError::InvalidChecksum),
+    };
+    assert!(serde_json::to_string(&listing).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn option_tokens() {
+    use serde_test::{assert_tokens, Configure, Token};
+
+    let gtin = GTIN::try_from("4006381333931").unwrap();
+    let present = OptionalGtin::Present(gtin);
+    assert_tokens(
+        &present.readable(),
+        &[Token::Some, Token::Str("4006381333931")],
+    );
+    assert_tokens(
+        &OptionalGtin::Exempt.readable(),
+        &[Token::Some, Token::Str("N/A")],
+    );
+    assert_tokens(&OptionalGtin::Missing.readable(), &[Token::None]);
+
+    // Binary formats get the packed word GTIN serializes to, and a sentinel for Exempt
+    let packed = crate::column::pack(&gtin);
+    assert_tokens(
+        &OptionalGtin::Present(gtin).compact(),
+        &[Token::Some, Token::U64(packed)],
+    );
+    assert_tokens(
+        &OptionalGtin::Exempt.compact(),
+        &[Token::Some, Token::U64(u64::MAX)],
+    );
+    assert_tokens(&OptionalGtin::Missing.compact(), &[Token::None]);
+}
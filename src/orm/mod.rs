@@ -0,0 +1,96 @@
+//! Diesel support: `GTIN` values can be bound to and loaded from `Text` columns, as their
+//! digit string, and `BigInt` columns, as the packed word `GtinColumn` stores, which keeps
+//! the kind. Loading works with any backend; binding needs the backend's feature,
+//! `diesel-postgres`, `diesel-mysql` or `diesel-sqlite`.
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql};
+#[cfg(feature = "diesel-sqlite")]
+use diesel::serialize::IsNull;
+#[cfg(any(
+    feature = "diesel-postgres",
+    feature = "diesel-mysql",
+    feature = "diesel-sqlite"
+))]
+use diesel::serialize::{self, Output, ToSql};
+use diesel::sql_types::{BigInt, Text};
+
+#[cfg(any(
+    feature = "diesel-postgres",
+    feature = "diesel-mysql",
+    feature = "diesel-sqlite"
+))]
+use crate::column::pack;
+use crate::column::try_unpack;
+use crate::GTIN;
+
+impl<DB: Backend> FromSql<Text, DB> for GTIN
+where
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let value = String::from_sql(bytes)?;
+        Ok(GTIN::try_from(value.as_str())?)
+    }
+}
+
+impl<DB: Backend> FromSql<BigInt, DB> for GTIN
+where
+    i64: FromSql<BigInt, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let word = i64::from_sql(bytes)?;
+        u64::try_from(word)
+            .ok()
+            .and_then(try_unpack)
+            .ok_or_else(|| format!("Invalid packed GTIN: {}", word).into())
+    }
+}
+
+/// Implement `ToSql` for a backend that serializes binds into a byte buffer, which takes
+/// them by reference, so the digit string and packed word go through a reborrowed output
+#[cfg(any(feature = "diesel-postgres", feature = "diesel-mysql"))]
+macro_rules! impl_to_sql_raw_bytes {
+    ($backend:ty) => {
+        impl ToSql<Text, $backend> for GTIN {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                let digits = String::from(*self);
+                <str as ToSql<Text, $backend>>::to_sql(&digits, &mut out.reborrow())
+            }
+        }
+
+        impl ToSql<BigInt, $backend> for GTIN {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, $backend>) -> serialize::Result {
+                let word = pack(self) as i64; // under 2^52
+                <i64 as ToSql<BigInt, $backend>>::to_sql(&word, &mut out.reborrow())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "diesel-postgres")]
+impl_to_sql_raw_bytes!(diesel::pg::Pg);
+
+#[cfg(feature = "diesel-mysql")]
+impl_to_sql_raw_bytes!(diesel::mysql::Mysql);
+
+// SQLite takes ownership of bound values
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<Text, diesel::sqlite::Sqlite> for GTIN {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(String::from(*self));
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+impl ToSql<BigInt, diesel::sqlite::Sqlite> for GTIN {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, diesel::sqlite::Sqlite>) -> serialize::Result {
+        out.set_value(pack(self) as i64); // under 2^52
+        Ok(IsNull::No)
+    }
+}
+
+// Binding needs a backend, and SQLite is the one tests can open
+#[cfg(all(test, feature = "diesel-sqlite"))]
+pub mod tests;
@@ -0,0 +1,66 @@
+use diesel::connection::SimpleConnection;
+use diesel::prelude::*;
+use diesel::sql_types::{BigInt, Text};
+use diesel::sqlite::SqliteConnection;
+
+use crate::GTIN;
+
+diesel::table! {
+    products (id) {
+        id -> Integer,
+        gtin -> Text,
+        packed -> BigInt,
+    }
+}
+
+#[test]
+fn sqlite_round_trip() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    conn.batch_execute(
+        "CREATE TABLE products (id INTEGER PRIMARY KEY, gtin TEXT NOT NULL, packed BIGINT NOT NULL)",
+    )
+    .unwrap();
+
+    let gtins = [
+        GTIN::try_from("071720539774").unwrap(),
+        GTIN::try_from("0071720539774").unwrap(),
+        GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]),
+    ];
+    for (id, gtin) in gtins.iter().enumerate() {
+        diesel::insert_into(products::table)
+            .values((
+                products::id.eq(id as i32),
+                products::gtin.eq(gtin),
+                products::packed.eq(gtin),
+            ))
+            .execute(&mut conn)
+            .unwrap();
+    }
+
+    let loaded: Vec<(GTIN, GTIN)> = products::table
+        .select((products::gtin, products::packed))
+        .order(products::id)
+        .load(&mut conn)
+        .unwrap();
+    for (gtin, (text, packed)) in gtins.iter().zip(&loaded) {
+        // Text keeps the digits, from which the parser may settle on another kind
+        assert!(text.same_product(gtin));
+        assert_eq!(text.digits(), gtin.digits());
+        // The packed word keeps the kind
+        assert_eq!(packed, gtin);
+    }
+
+    let found: i32 = products::table
+        .filter(products::packed.eq(gtins[1]))
+        .select(products::id)
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(found, 1);
+
+    // Invalid values fail to load
+    let invalid =
+        diesel::select(diesel::dsl::sql::<Text>("'071720539775'")).get_result::<GTIN>(&mut conn);
+    assert!(invalid.is_err());
+    let invalid = diesel::select(diesel::dsl::sql::<BigInt>("-1")).get_result::<GTIN>(&mut conn);
+    assert!(invalid.is_err());
+}
@@ -0,0 +1,31 @@
+//! Parse and validate large batches on all cores with rayon. Results are in input order,
+//! and each input is handled exactly as `GTIN::try_from` handles it.
+
+use rayon::prelude::*;
+
+use crate::{GtinError, GTIN};
+
+/// Parse every input in parallel
+pub fn parse_par<S>(inputs: &[S]) -> Vec<Result<GTIN, GtinError>>
+where
+    S: AsRef<str> + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|input| GTIN::try_from(input.as_ref()))
+        .collect()
+}
+
+/// Whether each input parses, in parallel
+pub fn validate_par<S>(inputs: &[S]) -> Vec<bool>
+where
+    S: AsRef<str> + Sync,
+{
+    inputs
+        .par_iter()
+        .map(|input| GTIN::try_from(input.as_ref()).is_ok())
+        .collect()
+}
+
+#[cfg(test)]
+pub mod tests;
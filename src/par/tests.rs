@@ -0,0 +1,23 @@
+use super::{parse_par, validate_par};
+use crate::{GtinError, GTIN};
+
+#[test]
+fn parse_in_parallel() {
+    let inputs: Vec<String> = (0..10_000)
+        .map(|i| match i % 3 {
+            0 => "071720539774".to_string(),
+            1 => "071720539775".to_string(),
+            _ => format!("{:08}", i),
+        })
+        .collect();
+
+    let parsed = parse_par(&inputs);
+    let sequential: Vec<_> = inputs.iter().map(|s| GTIN::try_from(s.as_str())).collect();
+    assert_eq!(parsed, sequential);
+    assert_eq!(parsed[1], Err(GtinError::InvalidChecksum));
+
+    let valid = validate_par(&inputs);
+    assert_eq!(valid.len(), inputs.len());
+    assert!(valid.iter().zip(&parsed).all(|(&v, p)| v == p.is_ok()));
+    assert_eq!(validate_par(&["0 71720 53977 4", ""]), vec![true, false]);
+}
@@ -0,0 +1,282 @@
+//! Opt-in parsing modes for input that `GTIN::try_from` rejects but that can be read
+//! reliably with some knowledge of how it was mangled.
+
+use std::borrow::Cow;
+
+use crate::aim::{eight_digit_kind, strip_identifier};
+use crate::util::extract_digits;
+use crate::{parse_str, telemetry, GtinError, GtinKind, NumberSystem, GTIN};
+
+/// What the number row of a keyboard layout sends for `1234567890`, for scanners set up
+/// for the wrong layout. Tried in order; the first reading that validates is accepted.
+const KEYBOARD_LAYOUTS: [[char; 10]; 4] = [
+    ['!', '@', '#', '$', '%', '^', '&', '*', '(', ')'], // US, shifted
+    ['!', '"', '£', '$', '%', '^', '&', '*', '(', ')'], // UK, shifted
+    ['&', 'é', '"', '\'', '(', '-', 'è', '_', 'ç', 'à'], // French AZERTY, unshifted
+    ['!', '"', '§', '$', '%', '&', '/', '(', ')', '='], // German QWERTZ, shifted
+];
+
+/// Characters OCR engines commonly read in place of a digit
+const OCR_CONFUSABLES: [(char, u8); 16] = [
+    ('O', 0),
+    ('o', 0),
+    ('D', 0),
+    ('Q', 0),
+    ('I', 1),
+    ('l', 1),
+    ('i', 1),
+    ('|', 1),
+    ('Z', 2),
+    ('z', 2),
+    ('A', 4),
+    ('S', 5),
+    ('s', 5),
+    ('G', 6),
+    ('B', 8),
+    ('g', 9),
+];
+
+/// The zero of each Unicode decimal digit block seen in international data feeds; the
+/// other nine digits follow it in order
+const UNICODE_ZEROS: [char; 12] = [
+    '\u{0660}',  // Arabic-Indic
+    '\u{06F0}',  // Extended Arabic-Indic (Persian, Urdu)
+    '\u{0966}',  // Devanagari
+    '\u{09E6}',  // Bengali
+    '\u{0BE6}',  // Tamil
+    '\u{0E50}',  // Thai
+    '\u{0ED0}',  // Lao
+    '\u{1040}',  // Myanmar
+    '\u{17E0}',  // Khmer
+    '\u{FF10}',  // Fullwidth, from CJK input methods
+    '\u{1D7CE}', // Mathematical bold
+    '\u{1D7F6}', // Mathematical monospace
+];
+
+/// A character the OCR correction mode read as a digit
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Correction {
+    pub offset: usize, // byte offset in the input
+    pub found: char,
+    pub digit: u8,
+}
+
+/// A non-fatal observation about how an input was parsed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseWarning {
+    SymbologyIdentifier(String),   // a scanner's `]Cm` prefix was removed
+    IgnoredCharacters(usize),      // characters besides digits and separators were dropped
+    LeadingZeroInserted,           // 11 digits were read as UPC-A missing its leading zero
+    AmbiguousEightDigit(GtinKind), // the kind assumed for an 8-digit code
+    UnicodeDigitsNormalized,       // digits of another script were read as ASCII
+    KeyboardLayoutRecovered,       // number-row symbols were read as digits
+    OcrCorrected(Vec<Correction>),
+    RestrictedPrefix(NumberSystem), // store use, coupon or refund codes, not for open trade
+}
+
+/// Parser settings beyond what `GTIN::try_from` does. Every mode is off by default.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct ParseOptions {
+    keyboard_recovery: bool, // read number-row symbols as the digits they share a key with
+    ocr_correction: bool,    // read letters OCR confuses with digits as those digits
+    unicode_digits: bool,    // read digits of other scripts as ASCII digits
+}
+
+impl ParseOptions {
+    pub fn new() -> Self {
+        ParseOptions::default()
+    }
+
+    pub fn with_keyboard_recovery(mut self, keyboard_recovery: bool) -> Self {
+        self.keyboard_recovery = keyboard_recovery;
+        self
+    }
+
+    pub fn with_ocr_correction(mut self, ocr_correction: bool) -> Self {
+        self.ocr_correction = ocr_correction;
+        self
+    }
+
+    pub fn with_unicode_digits(mut self, unicode_digits: bool) -> Self {
+        self.unicode_digits = unicode_digits;
+        self
+    }
+}
+
+impl GTIN {
+    /// Parse like `GTIN::try_from`, falling back to the recovery modes enabled in `options`
+    /// when that fails
+    pub fn parse_with(value: &str, options: &ParseOptions) -> Result<GTIN, GtinError> {
+        GTIN::parse_with_warnings(value, options, &mut vec![])
+    }
+
+    /// Parse like `parse_with`, adding warnings about a successful parse to `warnings`.
+    /// Nothing is added for input that is rejected.
+    pub fn parse_with_warnings(
+        value: &str,
+        options: &ParseOptions,
+        sink: &mut Vec<ParseWarning>,
+    ) -> Result<GTIN, GtinError> {
+        let mut warnings = vec![];
+
+        // Other scripts' digits are normalized first, as plain parsing would drop them
+        let normalized = match options.unicode_digits {
+            true => normalize_digits(value),
+            false => Cow::Borrowed(value),
+        };
+        if let Cow::Owned(_) = normalized {
+            warnings.push(ParseWarning::UnicodeDigitsNormalized);
+        }
+        let value = normalized.as_ref();
+
+        let result = match GTIN::try_from(value) {
+            Ok(gtin) => {
+                observe(value, &gtin, &mut warnings);
+                Ok(gtin)
+            }
+            Err(error) => {
+                let mut recovered = None;
+                if options.keyboard_recovery {
+                    recovered = recover_keyboard(value);
+                    if recovered.is_some() {
+                        telemetry::increment(telemetry::REPAIRED);
+                        telemetry::increment(telemetry::PARSED_OK);
+                        warnings.push(ParseWarning::KeyboardLayoutRecovered);
+                    }
+                }
+                if options.ocr_correction && recovered.is_none() {
+                    if let Some((gtin, corrections)) = correct_ocr(value) {
+                        recovered = Some(gtin);
+                        warnings.push(ParseWarning::OcrCorrected(corrections));
+                    }
+                }
+                recovered.ok_or(error)
+            }
+        };
+
+        if result.is_ok() {
+            sink.extend(warnings);
+        }
+        result
+    }
+
+    /// Parse like `GTIN::try_from`, also reporting what the parser's heuristics did
+    pub fn parse_with_report(value: &str) -> (Result<GTIN, GtinError>, Vec<ParseWarning>) {
+        let mut warnings = vec![];
+        let result = GTIN::parse_with_warnings(value, &ParseOptions::new(), &mut warnings);
+        (result, warnings)
+    }
+
+    /// Parse OCR output, reading confusable letters such as `O` and `l` as digits when the
+    /// input does not parse as it is. Corrections are only accepted if the check digit then
+    /// validates, and are returned with the GTIN.
+    pub fn parse_ocr(value: &str) -> Result<(GTIN, Vec<Correction>), GtinError> {
+        match GTIN::try_from(value) {
+            Ok(gtin) => Ok((gtin, vec![])),
+            Err(e) => correct_ocr(value).ok_or(e),
+        }
+    }
+}
+
+/// Note what the parser did to read `value` as `gtin`
+fn observe(value: &str, gtin: &GTIN, warnings: &mut Vec<ParseWarning>) {
+    let (identifier, data) = strip_identifier(value);
+    if let Some(identifier) = identifier {
+        warnings.push(ParseWarning::SymbologyIdentifier(identifier.to_string()));
+    }
+    let ignored = data
+        .chars()
+        .filter(|c| !c.is_ascii_digit() && !c.is_whitespace() && !['-', '.'].contains(c))
+        .count();
+    if ignored > 0 {
+        warnings.push(ParseWarning::IgnoredCharacters(ignored));
+    }
+    match extract_digits(data).len() {
+        11 => warnings.push(ParseWarning::LeadingZeroInserted),
+        8 if identifier.and_then(eight_digit_kind).is_none() && is_ambiguous(value) => {
+            warnings.push(ParseWarning::AmbiguousEightDigit(gtin.kind()))
+        }
+        _ => {}
+    }
+    let system = gtin.number_system();
+    if matches!(
+        system,
+        NumberSystem::StoreUse | NumberSystem::Coupon | NumberSystem::Refund
+    ) {
+        warnings.push(ParseWarning::RestrictedPrefix(system));
+    }
+}
+
+fn is_ambiguous(value: &str) -> bool {
+    let candidates = GTIN::parse_eight_digit(value);
+    candidates.ean8.is_some() && candidates.upce.is_some()
+}
+
+/// Replace the decimal digits of the scripts in `UNICODE_ZEROS` with ASCII digits
+pub fn normalize_digits(value: &str) -> Cow<'_, str> {
+    let digit = |c: char| {
+        UNICODE_ZEROS.iter().find_map(|&zero| {
+            let offset = (c as u32).checked_sub(zero as u32)?;
+            (offset < 10).then(|| char::from(b'0' + offset as u8))
+        })
+    };
+    if value.is_ascii() || !value.chars().any(|c| digit(c).is_some()) {
+        return Cow::Borrowed(value);
+    }
+    Cow::Owned(value.chars().map(|c| digit(c).unwrap_or(c)).collect())
+}
+
+fn recover_keyboard(value: &str) -> Option<GTIN> {
+    // Only input with no digits at all is taken to be mangled, so that separators some
+    // layouts put on the number row are not misread in ordinary input
+    let (_, data) = strip_identifier(value);
+    if data.chars().any(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    KEYBOARD_LAYOUTS.iter().find_map(|layout| {
+        let digit = |c: char| {
+            let i = layout.iter().position(|&key| key == c)?;
+            Some(char::from(b'0' + ((i + 1) % 10) as u8))
+        };
+        // The parser would drop a character the layout leaves unread, and the shorter
+        // reading could validate, so every character must be a key or a separator
+        let unread = |c: char| digit(c).is_none() && !c.is_whitespace() && !['-', '.'].contains(&c);
+        if data.chars().any(unread) {
+            return None;
+        }
+        let mapped: String = value.chars().map(|c| digit(c).unwrap_or(c)).collect();
+        parse_str(&mapped).ok()
+    })
+}
+
+fn correct_ocr(value: &str) -> Option<(GTIN, Vec<Correction>)> {
+    let mut corrections = vec![];
+    let corrected: String = value
+        .char_indices()
+        .map(
+            |(offset, c)| match OCR_CONFUSABLES.iter().find(|&&(found, _)| found == c) {
+                Some(&(found, digit)) => {
+                    corrections.push(Correction {
+                        offset,
+                        found,
+                        digit,
+                    });
+                    char::from(b'0' + digit)
+                }
+                None => c,
+            },
+        )
+        .collect();
+    if corrections.is_empty() {
+        return None;
+    }
+
+    let gtin = parse_str(&corrected).ok()?;
+    telemetry::increment(telemetry::REPAIRED);
+    telemetry::increment(telemetry::PARSED_OK);
+    Some((gtin, corrections))
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,185 @@
+use super::{normalize_digits, Correction, ParseOptions, ParseWarning};
+use crate::{GtinError, GtinKind, NumberSystem, GTIN};
+
+#[test]
+fn keyboard_recovery() {
+    let options = ParseOptions::new().with_keyboard_recovery(true);
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let ean13 = GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+    let german = GTIN::Ean13([0, 0, 0, 0, 0, 1, 8, 6, 8, 8, 8, 4, 9]);
+
+    let cases = vec![
+        (")&!&@)%#(&&$", Ok(upca)),       // US
+        ("à è&è é à ( \"çèè'", Ok(upca)), // AZERTY
+        ("*%(%&)!%£)%\"^", Ok(ean13)),    // UK
+        ("]E0)&!&@)%#(&&$", Ok(upca)),    // identifier is kept
+        ("071720539774", Ok(upca)),       // ordinary input is unchanged
+        (")&!&@)%#(&&%", Err(GtinError::InvalidChecksum)),
+        ("0717205397-4", Err(GtinError::InvalidChecksum)), // not read as AZERTY
+        ("=====!(&((($)", Ok(german)), // not the US reading of the keys it shares
+    ];
+    for (input, expected) in cases {
+        assert_eq!(GTIN::parse_with(input, &options), expected, "{}", input);
+    }
+
+    assert!(GTIN::parse_with(")&!&@)%#(&&$", &ParseOptions::new()).is_err());
+}
+
+#[test]
+fn ocr_correction() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let (gtin, corrections) = GTIN::parse_ocr("O7l72O539774").unwrap();
+    assert_eq!(gtin, upca);
+    assert_eq!(
+        corrections,
+        vec![
+            Correction {
+                offset: 0,
+                found: 'O',
+                digit: 0
+            },
+            Correction {
+                offset: 2,
+                found: 'l',
+                digit: 1
+            },
+            Correction {
+                offset: 5,
+                found: 'O',
+                digit: 0
+            },
+        ]
+    );
+
+    assert_eq!(GTIN::parse_ocr("071720539774"), Ok((upca, vec![])));
+    assert_eq!(
+        GTIN::parse_ocr("859S7O153O5ZG"),
+        Ok((
+            GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]),
+            vec![
+                Correction {
+                    offset: 3,
+                    found: 'S',
+                    digit: 5
+                },
+                Correction {
+                    offset: 5,
+                    found: 'O',
+                    digit: 0
+                },
+                Correction {
+                    offset: 9,
+                    found: 'O',
+                    digit: 0
+                },
+                Correction {
+                    offset: 11,
+                    found: 'Z',
+                    digit: 2
+                },
+                Correction {
+                    offset: 12,
+                    found: 'G',
+                    digit: 6
+                },
+            ]
+        ))
+    );
+    // Corrections that leave the check digit wrong are not accepted
+    assert_eq!(
+        GTIN::parse_ocr("O7l72O539775"),
+        Err(GtinError::InvalidChecksum)
+    );
+
+    let options = ParseOptions::new().with_ocr_correction(true);
+    assert_eq!(GTIN::parse_with("O7l72O539774", &options), Ok(upca));
+    assert!(GTIN::parse_with("O7l72O539774", &ParseOptions::new()).is_err());
+}
+
+#[test]
+fn unicode_digits() {
+    let options = ParseOptions::new().with_unicode_digits(true);
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+
+    assert_eq!(
+        GTIN::parse_with("０７１７２０５３９７７４", &options),
+        Ok(upca)
+    );
+    assert_eq!(GTIN::parse_with("٠٧١٧٢٠٥٣٩٧٧٤", &options), Ok(upca));
+    assert_eq!(GTIN::parse_with("۰۷۱۷۲۰ 539774", &options), Ok(upca)); // mixed
+    assert_eq!(normalize_digits("0717 abc"), "0717 abc");
+
+    // Without normalization the digits are dropped
+    assert!(GTIN::parse_with("０７１７２０５３９７７４", &ParseOptions::new()).is_err());
+}
+
+#[test]
+fn collect_warnings() {
+    let parse = |input: &str| {
+        let mut warnings = vec![];
+        let options = ParseOptions::new()
+            .with_ocr_correction(true)
+            .with_unicode_digits(true);
+        let result = GTIN::parse_with_warnings(input, &options, &mut warnings);
+        (result.is_ok(), warnings)
+    };
+
+    assert_eq!(parse("0 71720 53977 4"), (true, vec![]));
+    assert_eq!(
+        parse("]E0\"71720539774\""),
+        (
+            true,
+            vec![
+                ParseWarning::SymbologyIdentifier("]E0".to_string()),
+                ParseWarning::IgnoredCharacters(2),
+                ParseWarning::LeadingZeroInserted,
+            ]
+        )
+    );
+    assert_eq!(
+        parse("１０００ ００５２"),
+        (
+            true,
+            vec![
+                ParseWarning::UnicodeDigitsNormalized,
+                ParseWarning::AmbiguousEightDigit(GtinKind::UpcE),
+            ]
+        )
+    );
+    let (ok, warnings) = parse("O7l72O539774");
+    assert!(ok);
+    assert!(matches!(warnings[..], [ParseWarning::OcrCorrected(_)]));
+    assert_eq!(parse("071720539775"), (false, vec![]));
+}
+
+#[test]
+fn parse_with_report() {
+    assert_eq!(
+        GTIN::parse_with_report("71720539774"),
+        (
+            Ok(GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])),
+            vec![ParseWarning::LeadingZeroInserted]
+        )
+    );
+    assert_eq!(
+        GTIN::parse_with_report("2 12345 67890 9 hello").1,
+        vec![
+            ParseWarning::IgnoredCharacters(5),
+            ParseWarning::RestrictedPrefix(NumberSystem::StoreUse)
+        ]
+    );
+    assert_eq!(
+        GTIN::parse_with_report("071720539775"),
+        (Err(GtinError::InvalidChecksum), vec![])
+    );
+}
+
+#[test]
+fn share_options_across_threads() {
+    let options = ParseOptions::new().with_ocr_correction(true);
+    std::thread::scope(|scope| {
+        for input in ["O7l72O539774", "071720539774"] {
+            scope.spawn(move || assert!(GTIN::parse_with(input, &options).is_ok()));
+        }
+    });
+}
@@ -0,0 +1,126 @@
+//! Masked GTINs, as category rules and blocklists of promotion engines write them:
+//! `?` stands for any one digit and `*` for any run of digits, so `0 71720 5397? ?`
+//! matches the ten valid UPC-As `0717205397?c`, where `c` is each one's check digit.
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::GTIN;
+
+/// Characters people put between the digits, ignored in patterns
+const SEPARATORS: [char; 3] = [' ', '-', '.'];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Token {
+    Digit(u8),
+    Any,    // `?`
+    AnyRun, // `*`, possibly empty
+}
+
+/// Why a pattern could not be parsed
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum PatternError {
+    Empty,
+    InvalidCharacter(char),
+}
+
+impl Display for PatternError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "Empty pattern"),
+            PatternError::InvalidCharacter(c) => write!(f, "Invalid pattern character: {:?}", c),
+        }
+    }
+}
+
+impl std::error::Error for PatternError {}
+
+/// A GTIN pattern of digits and wildcards, matched against the digits of a GTIN as
+/// written, so a UPC-A pattern does not match the EAN-13 form of the same product
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GtinPattern {
+    tokens: Vec<Token>,
+}
+
+impl GtinPattern {
+    pub fn parse(pattern: &str) -> Result<Self, PatternError> {
+        let mut tokens = vec![];
+        for c in pattern.chars().filter(|c| !SEPARATORS.contains(c)) {
+            let token = match c {
+                '?' => Token::Any,
+                '*' if tokens.last() == Some(&Token::AnyRun) => continue,
+                '*' => Token::AnyRun,
+                _ => match c.to_digit(10) {
+                    Some(digit) => Token::Digit(digit as u8),
+                    None => return Err(PatternError::InvalidCharacter(c)),
+                },
+            };
+            tokens.push(token);
+        }
+        if tokens.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        Ok(GtinPattern { tokens })
+    }
+
+    pub fn matches(&self, gtin: &GTIN) -> bool {
+        self.matches_digits(gtin.digits())
+    }
+
+    /// Match digit values, e.g. from a digit column, without building a GTIN
+    pub fn matches_digits(&self, digits: &[u8]) -> bool {
+        // Greedy matching that backtracks to the last `*` on a mismatch
+        let (mut token, mut digit) = (0, 0);
+        let mut backtrack = None;
+        while digit < digits.len() {
+            match self.tokens.get(token) {
+                Some(Token::AnyRun) => {
+                    token += 1;
+                    backtrack = Some((token, digit));
+                }
+                Some(&Token::Digit(d)) if d == digits[digit] => {
+                    token += 1;
+                    digit += 1;
+                }
+                Some(Token::Any) => {
+                    token += 1;
+                    digit += 1;
+                }
+                _ => match backtrack {
+                    Some((after_star, start)) => {
+                        token = after_star;
+                        digit = start + 1;
+                        backtrack = Some((after_star, start + 1));
+                    }
+                    None => return false,
+                },
+            }
+        }
+        self.tokens[token..].iter().all(|&t| t == Token::AnyRun)
+    }
+}
+
+impl FromStr for GtinPattern {
+    type Err = PatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        GtinPattern::parse(s)
+    }
+}
+
+impl Display for GtinPattern {
+    /// The pattern without separators
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for token in &self.tokens {
+            match token {
+                Token::Digit(d) => write!(f, "{}", d)?,
+                Token::Any => write!(f, "?")?,
+                Token::AnyRun => write!(f, "*")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
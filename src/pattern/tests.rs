@@ -0,0 +1,47 @@
+use super::{GtinPattern, PatternError};
+use crate::GTIN;
+
+fn gtin(value: &str) -> GTIN {
+    GTIN::try_from(value).unwrap()
+}
+
+#[test]
+fn wildcards() {
+    let pattern = GtinPattern::parse("0 71720 5397? ?").unwrap();
+    assert_eq!(pattern.to_string(), "0717205397??");
+    assert!(pattern.matches(&gtin("071720539774")));
+    assert!(!pattern.matches(&gtin("036000291452")));
+    // Only the form as written
+    assert!(!pattern.matches(&gtin("0071720539774")));
+
+    let company: GtinPattern = "0071720*".parse().unwrap();
+    assert!(company.matches(&gtin("0071720539774")));
+    assert!(!company.matches(&gtin("071720539774")));
+
+    let ends = GtinPattern::parse("*4").unwrap();
+    assert!(ends.matches(&gtin("071720539774")));
+    assert!(!ends.matches(&gtin("036000291452")));
+
+    let middle = GtinPattern::parse("40*3?31").unwrap();
+    assert!(middle.matches(&gtin("4006381333931")));
+    assert!(!middle.matches_digits(&[4, 0, 1, 3, 3, 1]));
+    assert!(GtinPattern::parse("**").unwrap().matches(&gtin("96385074")));
+    assert_eq!(GtinPattern::parse("1**2").unwrap().to_string(), "1*2");
+
+    // `?` needs a digit, `*` does not
+    assert!(!GtinPattern::parse("9638507?4")
+        .unwrap()
+        .matches(&gtin("96385074")));
+    assert!(GtinPattern::parse("963850*74")
+        .unwrap()
+        .matches(&gtin("96385074")));
+}
+
+#[test]
+fn pattern_errors() {
+    assert_eq!(GtinPattern::parse(" - "), Err(PatternError::Empty));
+    assert_eq!(
+        GtinPattern::parse("0717x"),
+        Err(PatternError::InvalidCharacter('x'))
+    );
+}
@@ -0,0 +1,205 @@
+//! Declarative GTIN hygiene for batch jobs and services: strip, normalize digits, parse,
+//! canonicalize, apply policy and deduplicate, configured once and run over any source.
+
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::aim::strip_identifier;
+use crate::parse::normalize_digits;
+use crate::{GtinError, GtinKind, NumberSystem, GTIN};
+
+/// Characters people and spreadsheets put between the digits of a GTIN
+const SEPARATORS: [char; 3] = [' ', '-', '.'];
+
+/// The form accepted GTINs are converted to
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Canonical {
+    #[default]
+    AsParsed,
+    Gtin14,     // zero-padded 14 digits, UPC-E expanded first
+    PreferUpcA, // see `GTIN::prefer_upc_a`
+}
+
+/// Why the pipeline did not produce a GTIN for an input
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Rejection {
+    Empty,
+    InvalidCharacter(char), // only with `strict_characters`
+    Invalid(GtinError),
+    KindNotAllowed(GtinKind),
+    NumberSystemRejected(NumberSystem),
+    Duplicate(GTIN), // the canonical form of an earlier input
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejection::Empty => write!(f, "Empty input"),
+            Rejection::InvalidCharacter(c) => write!(f, "Invalid character: {:?}", c),
+            Rejection::Invalid(e) => write!(f, "{}", e),
+            Rejection::KindNotAllowed(kind) => write!(f, "{:?} is not allowed", kind),
+            Rejection::NumberSystemRejected(system) => {
+                write!(f, "{:?} number system is not allowed", system)
+            }
+            Rejection::Duplicate(gtin) => write!(f, "Duplicate of {}", gtin),
+        }
+    }
+}
+
+impl std::error::Error for Rejection {}
+
+/// A configured sequence of normalization steps. Build one with the `with_*` methods or
+/// load it from TOML; every field has a permissive default.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Pipeline {
+    strip_quotes: bool,      // remove quotes spreadsheets wrap around long numbers
+    normalize_digits: bool,  // read digits of other scripts, e.g. fullwidth, as ASCII
+    strict_characters: bool, // reject anything besides digits and separators
+    allowed_kinds: Option<Vec<GtinKind>>, // kinds as supplied, None allows every kind
+    rejected_number_systems: Vec<NumberSystem>,
+    canonical: Canonical,
+    dedup: bool,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Pipeline::default()
+    }
+
+    /// Load a pipeline from TOML, e.g. `allowed_kinds = ["UpcA", "Ean13"]`
+    #[cfg(feature = "toml")]
+    pub fn from_toml(config: &str) -> Result<Self, String> {
+        toml::from_str(config).map_err(|e| e.to_string())
+    }
+
+    pub fn with_strip_quotes(mut self, strip_quotes: bool) -> Self {
+        self.strip_quotes = strip_quotes;
+        self
+    }
+
+    pub fn with_normalize_digits(mut self, normalize_digits: bool) -> Self {
+        self.normalize_digits = normalize_digits;
+        self
+    }
+
+    pub fn with_strict_characters(mut self, strict_characters: bool) -> Self {
+        self.strict_characters = strict_characters;
+        self
+    }
+
+    pub fn with_allowed_kinds(mut self, kinds: &[GtinKind]) -> Self {
+        self.allowed_kinds = Some(kinds.to_vec());
+        self
+    }
+
+    pub fn with_rejected_number_systems(mut self, systems: &[NumberSystem]) -> Self {
+        self.rejected_number_systems = systems.to_vec();
+        self
+    }
+
+    pub fn with_canonical(mut self, canonical: Canonical) -> Self {
+        self.canonical = canonical;
+        self
+    }
+
+    pub fn with_dedup(mut self, dedup: bool) -> Self {
+        self.dedup = dedup;
+        self
+    }
+
+    /// Run every step except deduplication on a single input
+    pub fn process(&self, input: &str) -> Result<GTIN, Rejection> {
+        let mut value = input.trim();
+        if self.strip_quotes {
+            value = value.trim_matches(|c| c == '"' || c == '\'').trim();
+        }
+        if value.is_empty() {
+            return Err(Rejection::Empty);
+        }
+
+        let normalized = match self.normalize_digits {
+            true => normalize_digits(value),
+            false => value.into(),
+        };
+        let value = normalized.as_ref();
+
+        if self.strict_characters {
+            // A scanner's symbology identifier is not part of the data
+            let (_, data) = strip_identifier(value);
+            if let Some(c) = data
+                .chars()
+                .find(|c| !c.is_ascii_digit() && !SEPARATORS.contains(c))
+            {
+                return Err(Rejection::InvalidCharacter(c));
+            }
+        }
+
+        let parsed = GTIN::try_from(value).map_err(Rejection::Invalid)?;
+        let gtin = match self.canonical {
+            Canonical::AsParsed => parsed,
+            Canonical::Gtin14 => parsed
+                .convert_to(GtinKind::Gtin14)
+                .expect("every kind has a GTIN-14 form"),
+            Canonical::PreferUpcA => parsed.prefer_upc_a(),
+        };
+
+        // The kind policy is about what was supplied, as every kind becomes GTIN-14
+        // under `Canonical::Gtin14`; the number system is the canonical form's
+        if let Some(kinds) = &self.allowed_kinds {
+            if !kinds.contains(&parsed.kind()) {
+                return Err(Rejection::KindNotAllowed(parsed.kind()));
+            }
+        }
+        let system = gtin.number_system();
+        if self.rejected_number_systems.contains(&system) {
+            return Err(Rejection::NumberSystemRejected(system));
+        }
+        Ok(gtin)
+    }
+
+    /// Run the pipeline over a sequence of inputs, yielding one result per input
+    pub fn run<'a, I>(&'a self, inputs: I) -> impl Iterator<Item = Result<GTIN, Rejection>> + 'a
+    where
+        I: IntoIterator + 'a,
+        I::Item: AsRef<str>,
+    {
+        let mut seen = HashSet::new();
+        inputs.into_iter().map(move |input| {
+            let gtin = self.process(input.as_ref())?;
+            if self.dedup && !seen.insert(gtin.canonical_key()) {
+                return Err(Rejection::Duplicate(gtin));
+            }
+            Ok(gtin)
+        })
+    }
+
+    /// Run the pipeline over each line of a file, reading one line at a time
+    pub fn run_file<P: AsRef<Path>>(
+        &self,
+        path: P,
+    ) -> std::io::Result<Vec<Result<GTIN, Rejection>>> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut read_error = None;
+        let lines = reader
+            .lines()
+            .map_while(|line| line.map_err(|e| read_error = Some(e)).ok());
+        let results = self.run(lines).collect();
+        match read_error {
+            Some(e) => Err(e),
+            None => Ok(results),
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
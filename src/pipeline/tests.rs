@@ -0,0 +1,117 @@
+use super::{Canonical, Pipeline, Rejection};
+use crate::{GtinError, GtinKind, NumberSystem, GTIN};
+
+#[test]
+fn process_steps() {
+    let pipeline = Pipeline::new()
+        .with_strip_quotes(true)
+        .with_normalize_digits(true)
+        .with_strict_characters(true)
+        .with_allowed_kinds(&[GtinKind::UpcA, GtinKind::Ean13])
+        .with_rejected_number_systems(&[NumberSystem::Coupon])
+        .with_canonical(Canonical::PreferUpcA);
+
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let cases = vec![
+        ("\"071720539774\"", Ok(upca)),
+        ("0 071720-539774", Ok(upca)), // EAN-13 padding removed
+        ("]E0071720539774", Ok(upca)),
+        ("０７１７２０５３９７７４", Ok(upca)), // fullwidth digits
+        ("  ", Err(Rejection::Empty)),
+        ("0717205397x4", Err(Rejection::InvalidCharacter('x'))),
+        (
+            "071720539775",
+            Err(Rejection::Invalid(GtinError::InvalidChecksum)),
+        ),
+        ("1000 0052", Err(Rejection::KindNotAllowed(GtinKind::UpcE))),
+        (
+            "5 12345 67890 0",
+            Err(Rejection::NumberSystemRejected(NumberSystem::Coupon)),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(pipeline.process(input), expected, "{}", input);
+    }
+}
+
+#[test]
+fn run_with_dedup() {
+    let pipeline = Pipeline::new()
+        .with_canonical(Canonical::Gtin14)
+        .with_dedup(true);
+    let results: Vec<_> = pipeline
+        .run(["071720539774", "0071720539774", "96385074"])
+        .collect();
+
+    let gtin14 = GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert_eq!(results[0], Ok(gtin14));
+    assert_eq!(results[1], Err(Rejection::Duplicate(gtin14)));
+    assert!(results[2].is_ok());
+}
+
+#[test]
+fn policy_applies_after_canonicalization() {
+    // A GTIN-14 is allowed by the kind it was supplied as, and its number system is read
+    // from the canonical form
+    let pipeline = Pipeline::new()
+        .with_allowed_kinds(&[GtinKind::Ean13])
+        .with_rejected_number_systems(&[NumberSystem::Drug])
+        .with_canonical(Canonical::Gtin14);
+
+    let gtin14 = GTIN::Gtin14([0, 8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+    assert_eq!(pipeline.process("8595701530526"), Ok(gtin14));
+    assert_eq!(
+        pipeline.process("0300450449108"),
+        Err(Rejection::NumberSystemRejected(NumberSystem::Drug))
+    );
+    assert_eq!(
+        pipeline.process("071720539774"),
+        Err(Rejection::KindNotAllowed(GtinKind::UpcA))
+    );
+}
+
+#[test]
+fn run_file() {
+    // Unique per process, as test runs can overlap
+    let name = format!("gtin_pipeline_run_file_{}.txt", std::process::id());
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, "071720539774\n\n071720539775\n").unwrap();
+    let results = Pipeline::new().run_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert!(results[0].is_ok());
+    assert_eq!(results[1], Err(Rejection::Empty));
+    assert!(results[2].is_err());
+
+    // Read errors are returned, not treated as the end of the file
+    std::fs::write(&path, b"071720539774\n\xff\n").unwrap();
+    let error = Pipeline::new().run_file(&path).unwrap_err();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert!(Pipeline::new().run_file(&path).is_err());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn load_from_toml() {
+    let config = r#"
+        normalize_digits = true
+        strict_characters = true
+        allowed_kinds = ["UpcA", "Ean13"]
+        rejected_number_systems = ["Coupon", "StoreUse"]
+        canonical = "gtin14"
+        dedup = true
+    "#;
+    let expected = Pipeline::new()
+        .with_normalize_digits(true)
+        .with_strict_characters(true)
+        .with_allowed_kinds(&[GtinKind::UpcA, GtinKind::Ean13])
+        .with_rejected_number_systems(&[NumberSystem::Coupon, NumberSystem::StoreUse])
+        .with_canonical(Canonical::Gtin14)
+        .with_dedup(true);
+    assert_eq!(Pipeline::from_toml(config), Ok(expected));
+
+    assert!(Pipeline::from_toml("dedupe = true").is_err());
+}
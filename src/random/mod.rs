@@ -0,0 +1,46 @@
+//! Random GTINs with correct check digits, for seeding test databases and demo environments.
+
+use rand::{Rng, RngExt};
+
+use crate::util::{calculate_checksum_digit, upce_digits};
+use crate::{GtinKind, GTIN};
+
+/// N - 1 digits, the first ones given and the rest random, followed by their check digit
+fn digits<const N: usize, R: Rng + ?Sized>(prefix: &[u8], rng: &mut R) -> [u8; N] {
+    let mut digits = [0; N];
+    digits[..prefix.len()].copy_from_slice(prefix);
+    for digit in &mut digits[prefix.len()..N - 1] {
+        *digit = rng.random_range(0..10);
+    }
+    digits[N - 1] = calculate_checksum_digit(&digits[..N - 1]);
+    digits
+}
+
+impl GTIN {
+    /// A random GTIN of the given kind. UPC-E codes use number system 0 or 1, so they
+    /// expand to UPC-A.
+    pub fn random<R: Rng + ?Sized>(kind: GtinKind, rng: &mut R) -> GTIN {
+        match kind {
+            GtinKind::UpcE => {
+                let number_system = rng.random_range(0..2);
+                let body: [u8; 6] = core::array::from_fn(|_| rng.random_range(0..10));
+                GTIN::UpcE(upce_digits(number_system, &body))
+            }
+            GtinKind::UpcA => GTIN::UpcA(digits(&[], rng)),
+            GtinKind::Ean8 => GTIN::Ean8(digits(&[], rng)),
+            GtinKind::Ean13 => GTIN::Ean13(digits(&[], rng)),
+            GtinKind::Gtin14 => GTIN::Gtin14(digits(&[], rng)),
+        }
+    }
+
+    /// A random EAN-13 starting with `prefix`, e.g. a GS1 Company Prefix, as digit values.
+    /// Panics if the prefix is longer than 12 digits or holds a value over 9.
+    pub fn random_with_prefix<R: Rng + ?Sized>(prefix: &[u8], rng: &mut R) -> GTIN {
+        assert!(prefix.len() <= 12, "prefix longer than 12 digits");
+        assert!(prefix.iter().all(|&d| d <= 9), "prefix digit over 9");
+        GTIN::Ean13(digits(prefix, rng))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,60 @@
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::util::Digits;
+use crate::{GtinKind, GTIN};
+
+const KINDS: [GtinKind; 5] = [
+    GtinKind::UpcE,
+    GtinKind::UpcA,
+    GtinKind::Ean8,
+    GtinKind::Ean13,
+    GtinKind::Gtin14,
+];
+
+#[test]
+fn random_gtins_are_valid() {
+    let mut rng = StdRng::seed_from_u64(338);
+    for kind in KINDS {
+        for _ in 0..200 {
+            let gtin = GTIN::random(kind, &mut rng);
+            assert_eq!(gtin.kind(), kind);
+            let digits = Digits(gtin.digits()).to_string();
+            let input = match kind {
+                GtinKind::UpcE => format!("]E0{}", digits),
+                GtinKind::Ean8 => format!("]E4{}", digits),
+                _ => digits,
+            };
+            assert_eq!(GTIN::try_from(input.as_str()), Ok(gtin), "{}", input);
+        }
+    }
+
+    // The same seed gives the same codes
+    let a = GTIN::random(GtinKind::Ean13, &mut StdRng::seed_from_u64(1));
+    let b = GTIN::random(GtinKind::Ean13, &mut StdRng::seed_from_u64(1));
+    assert_eq!(a, b);
+}
+
+#[test]
+fn random_with_prefix() {
+    let mut rng = StdRng::seed_from_u64(338);
+    for prefix in [
+        &[][..],
+        &[4, 0, 0, 6, 3, 8, 1],
+        &[5, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8],
+    ] {
+        for _ in 0..50 {
+            let gtin = GTIN::random_with_prefix(prefix, &mut rng);
+            assert_eq!(gtin.kind(), GtinKind::Ean13);
+            assert!(gtin.digits().starts_with(prefix));
+            let digits = Digits(gtin.digits()).to_string();
+            assert!(GTIN::try_from(digits.as_str()).is_ok(), "{}", digits);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "prefix longer than 12 digits")]
+fn random_with_long_prefix() {
+    GTIN::random_with_prefix(&[1; 13], &mut StdRng::seed_from_u64(0));
+}
@@ -0,0 +1,221 @@
+//! Parse the item lines of receipts and point-of-sale exports, e.g. `2 x 4006381333931 3,49`,
+//! into quantity, GTIN and price, with the column layout described by a `ReceiptFormat`.
+
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::Deserialize;
+
+use crate::{GtinError, GTIN};
+
+/// What a column of a receipt line holds
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Column {
+    Quantity, // e.g. `2`, `2x` or `x2`
+    Gtin,
+    Price, // e.g. `3.49`, `€3,49` or `-1.00` for a refund
+    Skip,  // descriptions, tax codes and anything else
+}
+
+/// An item line of a receipt
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ReceiptLine {
+    pub quantity: u32, // 1 when the format has no quantity column
+    pub gtin: GTIN,
+    pub price: Option<i64>, // in hundredths, None when the format has no price column
+}
+
+/// Why a receipt line could not be read
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ReceiptError {
+    MissingColumn(Column),
+    InvalidQuantity(String),
+    InvalidGtin(GtinError),
+    InvalidPrice(String),
+}
+
+impl Display for ReceiptError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReceiptError::MissingColumn(column) => write!(f, "Missing {:?} column", column),
+            ReceiptError::InvalidQuantity(value) => write!(f, "Invalid quantity: {:?}", value),
+            ReceiptError::InvalidGtin(e) => write!(f, "{}", e),
+            ReceiptError::InvalidPrice(value) => write!(f, "Invalid price: {:?}", value),
+        }
+    }
+}
+
+impl std::error::Error for ReceiptError {}
+
+/// The layout of receipt lines. Build one with the `with_*` methods or deserialize it,
+/// e.g. from `columns = ["skip", "gtin", "price"]` and `delimiter = ";"`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct ReceiptFormat {
+    columns: Vec<Column>,
+    delimiter: Option<char>, // None splits on runs of whitespace
+    decimal_separator: char,
+    skip_header: bool,
+}
+
+impl Default for ReceiptFormat {
+    /// Whitespace separated quantity, GTIN and price
+    fn default() -> Self {
+        ReceiptFormat {
+            columns: vec![Column::Quantity, Column::Gtin, Column::Price],
+            delimiter: None,
+            decimal_separator: '.',
+            skip_header: false,
+        }
+    }
+}
+
+impl ReceiptFormat {
+    pub fn new() -> Self {
+        ReceiptFormat::default()
+    }
+
+    /// Load a format from TOML, e.g. `columns = ["gtin", "quantity"]`
+    #[cfg(feature = "toml")]
+    pub fn from_toml(config: &str) -> Result<Self, String> {
+        toml::from_str(config).map_err(|e| e.to_string())
+    }
+
+    /// Comma separated quantity, GTIN and price with a header row, as spreadsheets export
+    pub fn csv() -> Self {
+        ReceiptFormat::new()
+            .with_delimiter(Some(','))
+            .with_skip_header(true)
+    }
+
+    pub fn with_columns(mut self, columns: &[Column]) -> Self {
+        self.columns = columns.to_vec();
+        self
+    }
+
+    pub fn with_delimiter(mut self, delimiter: Option<char>) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn with_decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    pub fn with_skip_header(mut self, skip_header: bool) -> Self {
+        self.skip_header = skip_header;
+        self
+    }
+
+    /// Read a single line. Columns after the last described one are ignored, so free-text
+    /// descriptions can trail the data in whitespace separated formats.
+    pub fn parse_line(&self, line: &str) -> Result<ReceiptLine, ReceiptError> {
+        let fields: Vec<&str> = match self.delimiter {
+            Some(delimiter) => line.split(delimiter).collect(),
+            None => self.split_whitespace(line),
+        };
+
+        let field = |column: Column| -> Result<Option<&str>, ReceiptError> {
+            match self.columns.iter().position(|&c| c == column) {
+                Some(index) => fields
+                    .get(index)
+                    .map(|value| Some(unquote(value)))
+                    .ok_or(ReceiptError::MissingColumn(column)),
+                None => Ok(None),
+            }
+        };
+
+        let gtin = field(Column::Gtin)?.ok_or(ReceiptError::MissingColumn(Column::Gtin))?;
+        let gtin = GTIN::try_from(gtin).map_err(ReceiptError::InvalidGtin)?;
+        let quantity = match field(Column::Quantity)? {
+            Some(value) => parse_quantity(value)?,
+            None => 1,
+        };
+        let price = match field(Column::Price)? {
+            Some(value) => Some(parse_price(value, self.decimal_separator)?),
+            None => None,
+        };
+        Ok(ReceiptLine {
+            quantity,
+            gtin,
+            price,
+        })
+    }
+
+    /// Read every line, skipping blank lines and the header, with 1-based line numbers
+    pub fn parse(&self, text: &str) -> Vec<(usize, Result<ReceiptLine, ReceiptError>)> {
+        text.lines()
+            .enumerate()
+            .skip(usize::from(self.skip_header))
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| (index + 1, self.parse_line(line)))
+            .collect()
+    }
+
+    /// Whitespace splits the line, except that a quantity can be written `2 x`
+    fn split_whitespace<'a>(&self, line: &'a str) -> Vec<&'a str> {
+        let mut fields: Vec<&str> = line.split_whitespace().collect();
+        if self.columns.first() == Some(&Column::Quantity)
+            && fields.len() > 1
+            && is_multiplier(fields[1])
+        {
+            fields.remove(1);
+        }
+        fields
+    }
+}
+
+/// What quantities are written with, as in `2 x` or `x2`
+const MULTIPLIERS: [char; 4] = ['x', 'X', '×', '*'];
+
+fn is_multiplier(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!((chars.next(), chars.next()), (Some(c), None) if MULTIPLIERS.contains(&c))
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim()
+}
+
+fn parse_quantity(value: &str) -> Result<u32, ReceiptError> {
+    value
+        .trim_matches(|c: char| c.is_whitespace() || MULTIPLIERS.contains(&c))
+        .parse()
+        .map_err(|_| ReceiptError::InvalidQuantity(value.to_string()))
+}
+
+/// Hundredths of the amount, ignoring currency symbols and grouping separators
+fn parse_price(value: &str, decimal_separator: char) -> Result<i64, ReceiptError> {
+    let invalid = || ReceiptError::InvalidPrice(value.to_string());
+    let negative = value.contains('-');
+    let amount: String = value
+        .chars()
+        .filter(|&c| c.is_ascii_digit() || c == decimal_separator)
+        .collect();
+
+    let (units, fraction) = match amount.split_once(decimal_separator) {
+        Some((units, fraction)) => (units, fraction),
+        None => (amount.as_str(), ""),
+    };
+    if (units.is_empty() && fraction.is_empty()) || fraction.len() > 2 {
+        return Err(invalid());
+    }
+    let units: i64 = match units {
+        "" => 0,
+        units => units.parse().map_err(|_| invalid())?,
+    };
+    let fraction: i64 = format!("{:0<2}", fraction).parse().map_err(|_| invalid())?;
+
+    let hundredths = units
+        .checked_mul(100)
+        .and_then(|h| h.checked_add(fraction))
+        .ok_or_else(invalid)?;
+    Ok(if negative { -hundredths } else { hundredths })
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,104 @@
+use super::{Column, ReceiptError, ReceiptFormat, ReceiptLine};
+use crate::{GtinError, GTIN};
+
+#[test]
+fn parse_receipt_lines() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let format = ReceiptFormat::new();
+    let cases = vec![
+        ("2 071720539774 3.49", 2, Some(349)),
+        ("2 x 071720539774 $3.49 ORANGE JUICE", 2, Some(349)),
+        ("x3 071720539774 1", 3, Some(100)),
+        ("1 071720539774 -0.5", 1, Some(-50)), // refund
+        ("10 071720539774 1,234.00", 10, Some(123400)),
+    ];
+    for (input, quantity, price) in cases {
+        assert_eq!(
+            format.parse_line(input),
+            Ok(ReceiptLine {
+                quantity,
+                gtin: upca,
+                price
+            }),
+            "{}",
+            input
+        );
+    }
+
+    let european = ReceiptFormat::new()
+        .with_columns(&[Column::Skip, Column::Gtin, Column::Quantity, Column::Price])
+        .with_delimiter(Some(';'))
+        .with_decimal_separator(',');
+    assert_eq!(
+        european.parse_line("Milch 1L;\"8595701530526\";2;1.234,5 €"),
+        Ok(ReceiptLine {
+            quantity: 2,
+            gtin: GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]),
+            price: Some(123450),
+        })
+    );
+
+    let gtin_only = ReceiptFormat::new().with_columns(&[Column::Gtin]);
+    assert_eq!(
+        gtin_only.parse_line("071720539774"),
+        Ok(ReceiptLine {
+            quantity: 1,
+            gtin: upca,
+            price: None
+        })
+    );
+}
+
+#[test]
+fn receipt_errors() {
+    let format = ReceiptFormat::new();
+    assert_eq!(
+        format.parse_line("2 071720539774"),
+        Err(ReceiptError::MissingColumn(Column::Price))
+    );
+    assert_eq!(
+        format.parse_line("two 071720539774 3.49"),
+        Err(ReceiptError::InvalidQuantity("two".to_string()))
+    );
+    assert_eq!(
+        format.parse_line("2 071720539775 3.49"),
+        Err(ReceiptError::InvalidGtin(GtinError::InvalidChecksum))
+    );
+    assert_eq!(
+        format.parse_line("2 071720539774 3.499"),
+        Err(ReceiptError::InvalidPrice("3.499".to_string()))
+    );
+}
+
+#[test]
+fn parse_csv_export() {
+    let export = "qty,gtin,price\n1,071720539774,3.49\n\n2,8595701530526,1\n1,123,0.99\n";
+    let lines = ReceiptFormat::csv().parse(export);
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0].0, 2);
+    assert_eq!(lines[1].1.as_ref().unwrap().price, Some(100));
+    assert_eq!(lines[2].0, 5);
+    assert!(lines[2].1.is_err());
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn receipt_format_from_toml() {
+    let format = ReceiptFormat::from_toml(
+        r#"
+        columns = ["skip", "gtin", "price"]
+        delimiter = ";"
+        decimal_separator = ","
+        "#,
+    )
+    .unwrap();
+    assert_eq!(
+        format.parse_line("Milch;8595701530526;1,29"),
+        Ok(ReceiptLine {
+            quantity: 1,
+            gtin: GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]),
+            price: Some(129),
+        })
+    );
+    assert!(ReceiptFormat::from_toml("columns = [\"sku\"]").is_err());
+}
@@ -0,0 +1,36 @@
+//! redis `ToRedisArgs` and `FromRedisValue` for `GTIN`, so GTINs can be keys and values
+//! directly. They are written as their digits, with `]E0` or `]E4` in front when eight
+//! digits would read as the other kind, and read with `GTIN::try_from`, so a value reads
+//! back as the GTIN that was written. The forms of one product are different keys; key by
+//! `GTIN::canonical_key` to make them the same.
+
+use ::redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use crate::split::to_tagged_string;
+use crate::GTIN;
+
+impl ToRedisArgs for GTIN {
+    fn write_redis_args<W>(&self, out: &mut W)
+    where
+        W: ?Sized + RedisWrite,
+    {
+        out.write_arg(to_tagged_string(self).as_bytes());
+    }
+}
+
+impl ToSingleRedisArg for GTIN {}
+
+impl FromRedisValue for GTIN {
+    fn from_redis_value_ref(value: &Value) -> Result<Self, ParsingError> {
+        let value = String::from_redis_value_ref(value)?;
+        GTIN::try_from(value.as_str())
+            .map_err(|e| format!("Invalid GTIN {:?}: {}", value, e).into())
+    }
+
+    fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+        Self::from_redis_value_ref(&value)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,47 @@
+use ::redis::{FromRedisValue, ToRedisArgs, Value};
+
+use crate::GTIN;
+
+#[test]
+fn redis_args() {
+    let upca = GTIN::try_from("071720539774").unwrap();
+    let ean13 = GTIN::try_from("0071720539774").unwrap();
+    let upce = GTIN::UpcE([0, 4, 1, 8, 2, 6, 5, 8]);
+    assert_eq!(upca.to_redis_args(), vec![b"071720539774".to_vec()]);
+    assert_eq!(ean13.to_redis_args(), vec![b"0071720539774".to_vec()]);
+    // 04182658 alone reads as an EAN-8
+    assert_eq!(upce.to_redis_args(), vec![b"]E004182658".to_vec()]);
+    assert_eq!(
+        vec![upca, upce].to_redis_args(),
+        vec![b"071720539774".to_vec(), b"]E004182658".to_vec()]
+    );
+}
+
+#[test]
+fn redis_values() {
+    let cases = vec![
+        "071720539774",
+        "0071720539774",
+        "00071720539774",
+        "96385074",
+        "]E010000052",
+        "]E004182658",
+        "]E410000052",
+    ];
+    for input in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        let value = Value::BulkString(gtin.to_redis_args().remove(0));
+        assert_eq!(GTIN::from_redis_value_ref(&value), Ok(gtin), "{}", input);
+        assert_eq!(GTIN::from_redis_value(value), Ok(gtin), "{}", input);
+    }
+
+    let upca = GTIN::try_from("071720539774").unwrap();
+    // Numbers that lost their leading zeros, as INCR-style integer replies give them
+    let gtin = GTIN::from_redis_value(Value::Int(71720539774)).unwrap();
+    assert!(gtin.same_product(&upca));
+
+    let error = GTIN::from_redis_value(Value::BulkString(b"071720539775".to_vec()));
+    assert!(error.unwrap_err().to_string().contains("Invalid GTIN"));
+    assert!(GTIN::from_redis_value(Value::Nil).is_err());
+    assert_eq!(Option::<GTIN>::from_redis_value(Value::Nil).unwrap(), None);
+}
@@ -0,0 +1,113 @@
+//! The price printed above the 5-digit add-on of Bookland (ISBN) symbols. The add-on's
+//! first digit names the currency and the other four give the price in hundredths.
+
+/// Currencies of the 5-digit Bookland price add-on
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Currency {
+    Gbp, // 0 and 1
+    Aud, // 3
+    Nzd, // 4
+    Usd, // 5
+    Cad, // 6
+}
+
+impl Currency {
+    fn symbol(self) -> &'static str {
+        match self {
+            Currency::Gbp => "£",
+            Currency::Aud => "A$",
+            Currency::Nzd => "NZ$",
+            Currency::Usd => "$",
+            Currency::Cad => "C$",
+        }
+    }
+}
+
+/// A suggested retail price from an add-on
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PriceAddOn {
+    pub currency: Currency,
+    pub hundredths: u16, // 9999 means the price is higher than 99.98
+}
+
+/// How a region writes prices
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PriceLocale {
+    pub symbol_after: bool, // `19,95 $` rather than `$19.95`
+    pub decimal_separator: char,
+    pub space: bool, // between the symbol and the amount
+}
+
+impl PriceLocale {
+    /// `$19.95`, as in the US, UK, Canada, Australia and New Zealand
+    pub const ENGLISH: PriceLocale = PriceLocale {
+        symbol_after: false,
+        decimal_separator: '.',
+        space: false,
+    };
+    /// `19,95 $`, as in France and French-speaking Canada
+    pub const FRENCH: PriceLocale = PriceLocale {
+        symbol_after: true,
+        decimal_separator: ',',
+        space: true,
+    };
+    /// `$ 19,95`, as in the Netherlands
+    pub const DUTCH: PriceLocale = PriceLocale {
+        symbol_after: false,
+        decimal_separator: ',',
+        space: true,
+    };
+}
+
+impl Default for PriceLocale {
+    fn default() -> Self {
+        PriceLocale::ENGLISH
+    }
+}
+
+impl PriceAddOn {
+    /// Read a 5-digit add-on. Add-ons starting with 9 (no price, or publisher's internal
+    /// codes) and unassigned currency digits have no price.
+    pub fn parse(addon: &str) -> Option<PriceAddOn> {
+        let digits: Vec<u16> = addon
+            .chars()
+            .map(|c| {
+                c.to_digit(10)
+                    .filter(|_| c.is_ascii_digit())
+                    .map(|d| d as u16)
+            })
+            .collect::<Option<_>>()?;
+        if digits.len() != 5 {
+            return None;
+        }
+        let currency = match digits[0] {
+            0 | 1 => Currency::Gbp,
+            3 => Currency::Aud,
+            4 => Currency::Nzd,
+            5 => Currency::Usd,
+            6 => Currency::Cad,
+            _ => return None,
+        };
+        let hundredths = digits[1..].iter().fold(0, |acc, &d| acc * 10 + d);
+        Some(PriceAddOn {
+            currency,
+            hundredths,
+        })
+    }
+
+    /// The price as printed above the add-on, e.g. `$19.95`
+    pub fn caption(&self, locale: &PriceLocale) -> String {
+        let amount = format!(
+            "{}{}{:02}",
+            self.hundredths / 100,
+            locale.decimal_separator,
+            self.hundredths % 100
+        );
+        let space = if locale.space { " " } else { "" };
+        let symbol = self.currency.symbol();
+        match locale.symbol_after {
+            true => format!("{}{}{}", amount, space, symbol),
+            false => format!("{}{}{}", symbol, space, amount),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use super::{hri_layout, quiet_zones};
+use crate::encode::encode;
+use crate::GTIN;
+
+const BAR: char = '█';
+
+impl GTIN {
+    /// Render the symbol with block characters, one column per module and `height` rows
+    /// of bars, followed by a row with the extended guard bars and the digits
+    pub fn to_ascii_art(&self, height: usize) -> Option<String> {
+        let pattern = encode(self)?;
+        let (left, right) = quiet_zones(self.kind());
+        let width = left + pattern.len() + right;
+
+        let mut bars = vec![' '; width];
+        let mut last_row = vec![' '; width];
+        for (i, (&bar, &guard)) in pattern.modules().iter().zip(pattern.guards()).enumerate() {
+            if bar {
+                bars[left + i] = BAR;
+                if guard {
+                    last_row[left + i] = BAR;
+                }
+            }
+        }
+
+        for item in hri_layout(self) {
+            let first_center = item.center - (item.text.len() as f64 - 1.0) * 3.5;
+            for (i, digit) in item.text.chars().enumerate() {
+                let column = left as f64 + first_center + i as f64 * 7.0;
+                if let Some(cell) = last_row.get_mut(column.round().max(0.0) as usize) {
+                    *cell = digit;
+                }
+            }
+        }
+
+        let bar_row: String = bars.into_iter().collect();
+        let mut art = String::new();
+        for _ in 0..height {
+            art.push_str(&bar_row);
+            art.push('\n');
+        }
+        art.extend(last_row);
+        art.push('\n');
+        Some(art)
+    }
+}
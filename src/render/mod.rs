@@ -0,0 +1,151 @@
+//! Layout shared by the symbol renderers: quiet zones, bar runs and the placement of
+//! the human-readable digits. Horizontal positions are in modules from the left edge of
+//! the symbol (the first module of the start guard).
+
+use std::fmt::{Display, Formatter};
+
+use crate::encode::{Pattern, ITF14_MODULES};
+use crate::util::digits_to_string;
+use crate::{FormatStyle, GtinKind, GTIN};
+
+pub mod addon;
+pub mod ascii;
+pub mod symbol;
+#[cfg(feature = "image")]
+pub mod raster;
+#[cfg(feature = "svg")]
+pub mod svg;
+
+/// How far guard bars extend below the other bars, in modules
+pub const GUARD_EXTENSION: f64 = 5.0;
+
+/// Minimum quiet zones (left, right) in modules, per the GS1 General Specifications
+pub fn quiet_zones(kind: GtinKind) -> (usize, usize) {
+    match kind {
+        GtinKind::Ean13 => (11, 7),
+        GtinKind::UpcA | GtinKind::UpcE => (9, 9),
+        GtinKind::Ean8 => (7, 7),
+        GtinKind::Gtin14 => (10, 10),
+    }
+}
+
+/// The bearer bars printed around ITF-14 symbols so a slanted scan line cannot read a
+/// partial symbol. Dimensions are in modules.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BearerBars {
+    pub thickness: f64,
+    pub frame: bool, // a full rectangle rather than just top and bottom bars
+}
+
+impl Default for BearerBars {
+    /// The GS1 recommendation for ITF-14 printed directly on corrugated cases
+    fn default() -> Self {
+        BearerBars {
+            thickness: 4.8,
+            frame: true,
+        }
+    }
+}
+
+/// Bearer bars the symbology calls for, if any
+pub fn bearer_bars(kind: GtinKind) -> Option<BearerBars> {
+    match kind {
+        GtinKind::Gtin14 => Some(BearerBars::default()),
+        _ => None,
+    }
+}
+
+/// A run of adjacent bar modules
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BarRun {
+    pub start: usize,
+    pub width: usize,
+    pub guard: bool, // extended below the other bars
+}
+
+/// Merge the bar modules of a pattern into runs, splitting where the guard flag changes
+pub fn bar_runs(pattern: &Pattern) -> Vec<BarRun> {
+    let mut runs: Vec<BarRun> = Vec::new();
+    for (i, (&bar, &guard)) in pattern.modules().iter().zip(pattern.guards()).enumerate() {
+        if !bar {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.start + run.width == i && run.guard == guard => run.width += 1,
+            _ => runs.push(BarRun {
+                start: i,
+                width: 1,
+                guard,
+            }),
+        }
+    }
+    runs
+}
+
+/// A group of human-readable digits, centred on `center`
+#[derive(Debug, PartialEq, Clone)]
+pub struct TextItem {
+    pub text: String,
+    pub center: f64,
+    pub small: bool, // printed outside the symbol in a smaller font
+}
+
+/// Where the human-readable digits go under (or beside) the symbol
+pub fn hri_layout(gtin: &GTIN) -> Vec<TextItem> {
+    let item = |digits: &[u8], center: f64, small: bool| TextItem {
+        text: digits_to_string(digits),
+        center,
+        small,
+    };
+
+    let d = gtin.digits();
+    match gtin.kind() {
+        GtinKind::Ean13 => vec![
+            item(&d[..1], -4.0, false), // in the left quiet zone
+            item(&d[1..7], 24.0, false),
+            item(&d[7..], 71.0, false),
+        ],
+        GtinKind::UpcA => vec![
+            item(&d[..1], -4.0, true),
+            item(&d[1..6], 27.5, false),
+            item(&d[6..11], 67.5, false),
+            item(&d[11..], 99.0, true),
+        ],
+        GtinKind::Ean8 => vec![item(&d[..4], 17.0, false), item(&d[4..], 50.0, false)],
+        GtinKind::UpcE => vec![
+            item(&d[..1], -4.0, true),
+            item(&d[1..7], 24.0, false),
+            item(&d[7..], 55.0, true),
+        ],
+        GtinKind::Gtin14 => vec![TextItem {
+            text: gtin.hri(),
+            center: ITF14_MODULES as f64 / 2.0,
+            small: false,
+        }],
+    }
+}
+
+/// The grouped human-readable digits, written straight into the formatter
+pub struct Hri<'a>(&'a GTIN);
+
+impl Display for Hri<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.format(FormatStyle::Spaced).fmt(f)
+    }
+}
+
+impl GTIN {
+    /// The digits grouped as GS1 specifies for printing under the symbol,
+    /// e.g. `0 71720 53977 4` for UPC-A
+    pub fn hri(&self) -> String {
+        self.hri_display().to_string()
+    }
+
+    /// Like `hri`, for `format!` and logging without building a `String` first
+    pub fn hri_display(&self) -> Hri<'_> {
+        Hri(self)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
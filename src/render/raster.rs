@@ -0,0 +1,151 @@
+use image::{GrayImage, Luma};
+
+use super::{bearer_bars, hri_layout, quiet_zones, GUARD_EXTENSION};
+use crate::encode::encode;
+use crate::GTIN;
+
+/// 5x7 bitmaps of the digits 0-9, one row per byte, most significant of 5 bits on the left
+const DIGIT_FONT: [[u8; 7]; 10] = [
+    [
+        0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110,
+    ],
+    [
+        0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110,
+    ],
+    [
+        0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111,
+    ],
+    [
+        0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110,
+    ],
+    [
+        0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010,
+    ],
+    [
+        0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110,
+    ],
+    [
+        0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110,
+    ],
+    [
+        0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000,
+    ],
+    [
+        0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110,
+    ],
+    [
+        0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100,
+    ],
+];
+
+const BLACK: Luma<u8> = Luma([0]);
+const WHITE: Luma<u8> = Luma([255]);
+
+impl GTIN {
+    /// Render the symbol as a bitmap with `x_dimension_px` pixels per module and bars
+    /// `height_px` tall, surrounded by quiet zones
+    pub fn to_image(&self, x_dimension_px: u32, height_px: u32) -> Option<GrayImage> {
+        self.rasterize(x_dimension_px, height_px, false)
+    }
+
+    /// Like `to_image`, with the human-readable digits baked in below the bars
+    pub fn to_image_with_hri(&self, x_dimension_px: u32, height_px: u32) -> Option<GrayImage> {
+        self.rasterize(x_dimension_px, height_px, true)
+    }
+
+    fn rasterize(&self, x: u32, bar_height: u32, show_text: bool) -> Option<GrayImage> {
+        let pattern = encode(self)?;
+        let x = x.max(1);
+        let (left, right) = quiet_zones(self.kind());
+
+        let bearer = bearer_bars(self.kind());
+        let bearer_width = bearer.map_or(0, |b| (b.thickness * x as f64).round() as u32);
+        let side = match bearer {
+            Some(b) if b.frame => bearer_width,
+            _ => 0,
+        };
+        // Symbols with bearer bars have no guards to extend
+        let extension = if bearer.is_some() {
+            0
+        } else {
+            GUARD_EXTENSION as u32 * x
+        };
+
+        let origin = side + left as u32 * x;
+        let top = bearer_width;
+        let symbol_bottom = top + bar_height;
+        let text_top = symbol_bottom + bearer_width + x;
+        // Digits are 7 font pixels tall with one module of space above and below
+        let height = if show_text {
+            (text_top + 8 * x).max(symbol_bottom + bearer_width + extension)
+        } else {
+            symbol_bottom + bearer_width + extension
+        };
+        let width = 2 * side + (left + pattern.len() + right) as u32 * x;
+
+        let mut image = GrayImage::from_pixel(width, height, WHITE);
+        for (i, (&bar, &guard)) in pattern.modules().iter().zip(pattern.guards()).enumerate() {
+            if !bar {
+                continue;
+            }
+            let bottom = if guard || !show_text {
+                symbol_bottom + extension
+            } else {
+                symbol_bottom
+            };
+            fill(&mut image, origin + i as u32 * x, top, x, bottom - top);
+        }
+
+        if let Some(bearer) = bearer {
+            let outer = symbol_bottom + bearer_width;
+            fill(&mut image, 0, 0, width, bearer_width);
+            fill(&mut image, 0, symbol_bottom, width, bearer_width);
+            if bearer.frame {
+                fill(&mut image, 0, 0, bearer_width, outer);
+                fill(&mut image, width - bearer_width, 0, bearer_width, outer);
+            }
+        }
+
+        if show_text {
+            for item in hri_layout(self) {
+                let count = item.text.len() as f64;
+                // Each digit takes the 7 modules of a symbol character
+                let first_center = item.center - (count - 1.0) * 3.5;
+                for (i, digit) in item.text.bytes().enumerate() {
+                    if !digit.is_ascii_digit() {
+                        continue; // keep the spacing of grouped digits
+                    }
+                    let center = origin as f64 + (first_center + i as f64 * 7.0) * x as f64;
+                    let left_edge = (center - 2.5 * x as f64).round().max(0.0) as u32;
+                    draw_digit(&mut image, digit - b'0', left_edge, text_top, x);
+                }
+            }
+        }
+
+        Some(image)
+    }
+}
+
+fn fill(image: &mut GrayImage, x: u32, y: u32, width: u32, height: u32) {
+    for px in x..(x + width).min(image.width()) {
+        for py in y..(y + height).min(image.height()) {
+            image.put_pixel(px, py, BLACK);
+        }
+    }
+}
+
+fn draw_digit(image: &mut GrayImage, digit: u8, x: u32, y: u32, scale: u32) {
+    for (row, bits) in DIGIT_FONT[digit as usize % 10].iter().enumerate() {
+        for column in 0..5 {
+            if bits & (0b10000 >> column) != 0 {
+                fill(
+                    image,
+                    x + column * scale,
+                    y + row as u32 * scale,
+                    scale,
+                    scale,
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::fmt::Write;
+
+use super::{bar_runs, bearer_bars, hri_layout, quiet_zones, GUARD_EXTENSION};
+use crate::encode::encode;
+use crate::GTIN;
+
+/// Dimensions for SVG output, in millimetres
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SvgOptions {
+    pub x_dimension: f64, // width of a single module
+    pub bar_height: f64,
+    pub show_text: bool,
+}
+
+impl Default for SvgOptions {
+    /// Nominal (100% magnification) EAN-13 dimensions
+    fn default() -> Self {
+        SvgOptions {
+            x_dimension: 0.33,
+            bar_height: 22.85,
+            show_text: true,
+        }
+    }
+}
+
+impl GTIN {
+    /// Render the symbol as a standalone SVG document, including quiet zones and, for
+    /// ITF-14, bearer bars
+    pub fn to_svg(&self, options: SvgOptions) -> Option<String> {
+        let pattern = encode(self)?;
+        let x = options.x_dimension;
+        let (left, right) = quiet_zones(self.kind());
+
+        let bearer = bearer_bars(self.kind());
+        let bearer_width = bearer.map_or(0.0, |b| b.thickness * x);
+        let side = match bearer {
+            Some(b) if b.frame => bearer_width,
+            _ => 0.0,
+        };
+        // Symbols with bearer bars have no guards to extend
+        let extension = if bearer.is_some() {
+            0.0
+        } else {
+            GUARD_EXTENSION * x
+        };
+
+        let origin = side + left as f64 * x;
+        let top = bearer_width;
+        let symbol_bottom = top + options.bar_height;
+        let font_size = 8.0 * x;
+        let text_top = symbol_bottom + bearer_width + x;
+        let height = if options.show_text {
+            (text_top + font_size).max(symbol_bottom + bearer_width + extension)
+        } else {
+            symbol_bottom + bearer_width + extension
+        };
+        let width = 2.0 * side + (left + pattern.len() + right) as f64 * x;
+
+        let mut svg = String::new();
+        // Writing to a String cannot fail
+        let _ = write!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}mm" height="{h}mm" viewBox="0 0 {w} {h}">"#,
+            w = fmt(width),
+            h = fmt(height)
+        );
+        rect(&mut svg, 0.0, 0.0, width, height, "white");
+
+        for run in bar_runs(&pattern) {
+            let bottom = if run.guard || !options.show_text {
+                symbol_bottom + extension
+            } else {
+                symbol_bottom
+            };
+            rect(
+                &mut svg,
+                origin + run.start as f64 * x,
+                top,
+                run.width as f64 * x,
+                bottom - top,
+                "black",
+            );
+        }
+
+        if let Some(bearer) = bearer {
+            let outer = symbol_bottom + bearer_width;
+            rect(&mut svg, 0.0, 0.0, width, bearer_width, "black");
+            rect(&mut svg, 0.0, symbol_bottom, width, bearer_width, "black");
+            if bearer.frame {
+                rect(&mut svg, 0.0, 0.0, bearer_width, outer, "black");
+                rect(
+                    &mut svg,
+                    width - bearer_width,
+                    0.0,
+                    bearer_width,
+                    outer,
+                    "black",
+                );
+            }
+        }
+
+        if options.show_text {
+            let baseline = text_top + font_size * 0.8;
+            for item in hri_layout(self) {
+                let size = if item.small {
+                    font_size * 0.75
+                } else {
+                    font_size
+                };
+                let _ = write!(
+                    svg,
+                    r#"<text x="{}" y="{}" font-family="OCR-B, monospace" font-size="{}" text-anchor="middle">{}</text>"#,
+                    fmt(origin + item.center * x),
+                    fmt(baseline),
+                    fmt(size),
+                    item.text
+                );
+            }
+        }
+
+        svg.push_str("</svg>");
+        Some(svg)
+    }
+}
+
+fn rect(svg: &mut String, x: f64, y: f64, width: f64, height: f64, fill: &str) {
+    let _ = write!(
+        svg,
+        r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+        fmt(x),
+        fmt(y),
+        fmt(width),
+        fmt(height),
+        fill
+    );
+}
+
+/// Format a coordinate without trailing zeros
+fn fmt(value: f64) -> String {
+    let s = format!("{:.4}", value);
+    s.trim_end_matches('0').trim_end_matches('.').to_string()
+}
@@ -0,0 +1,258 @@
+//! One interface over the symbologies, so renderers can be written once for any of them:
+//! a `SymbolEncoder` gives its modules and size and draws its bars into a `RenderTarget`.
+//! Sizes and coordinates are in the unit of the X-dimension passed in, e.g. millimetres
+//! or pixels, with the origin at the top left of the left quiet zone.
+
+use super::{bar_runs, bearer_bars, quiet_zones, BearerBars, GUARD_EXTENSION};
+use crate::encode::databar::{encode_databar, DataBar};
+use crate::encode::{encode_ean13, encode_ean8, encode_itf14, encode_upca, encode_upce};
+use crate::encode::{Pattern, Symbology};
+use crate::{GtinKind, GTIN};
+
+/// Nominal EAN-13, UPC-A and UPC-E bar height in modules (22.85 mm at 0.33 mm)
+const EAN_UPC_BAR_HEIGHT: f64 = 22.85 / 0.33;
+/// Nominal EAN-8 bar height in modules (18.23 mm at 0.33 mm)
+const EAN8_BAR_HEIGHT: f64 = 18.23 / 0.33;
+/// Minimum ITF-14 bar height in modules (32 mm at 1.016 mm)
+const ITF14_BAR_HEIGHT: f64 = 32.0 / 1.016;
+
+/// Size of a symbol with its quiet zones and bearer bars
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Dimensions {
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Something a symbol can be drawn into, e.g. an SVG document or a bitmap
+pub trait RenderTarget {
+    /// Called once before any bars, with the size of the whole symbol
+    fn begin(&mut self, _dimensions: Dimensions) {}
+
+    /// A filled rectangle: a bar, or a side of the bearer bars
+    fn bar(&mut self, x: f64, y: f64, width: f64, height: f64);
+}
+
+/// A GTIN encoded in one symbology
+pub trait SymbolEncoder {
+    fn symbology(&self) -> Symbology;
+
+    fn modules(&self) -> &Pattern;
+
+    /// Minimum quiet zones (left, right) in modules
+    fn quiet_zones(&self) -> (usize, usize);
+
+    /// Height of the bars in modules, not counting guard bars extended below them
+    fn bar_height(&self) -> f64;
+
+    fn bearer_bars(&self) -> Option<BearerBars> {
+        None
+    }
+
+    /// Width and height at X-dimension `x_dim`
+    fn dimensions(&self, x_dim: f64) -> Dimensions {
+        let layout = Layout::of(self);
+        Dimensions {
+            width: layout.width * x_dim,
+            height: layout.height * x_dim,
+        }
+    }
+
+    /// Draw the bars, with guard bars extended and bearer bars around ITF-14
+    fn render(&self, target: &mut dyn RenderTarget, x_dim: f64) {
+        let layout = Layout::of(self);
+        target.begin(self.dimensions(x_dim));
+
+        for run in bar_runs(self.modules()) {
+            let extension = if run.guard { layout.extension } else { 0.0 };
+            target.bar(
+                (layout.origin + run.start as f64) * x_dim,
+                layout.bearer * x_dim,
+                run.width as f64 * x_dim,
+                (self.bar_height() + extension) * x_dim,
+            );
+        }
+
+        if let Some(bearer) = self.bearer_bars() {
+            let t = bearer.thickness;
+            let bottom = layout.height - t;
+            target.bar(0.0, 0.0, layout.width * x_dim, t * x_dim);
+            target.bar(0.0, bottom * x_dim, layout.width * x_dim, t * x_dim);
+            if bearer.frame {
+                let right = layout.width - t;
+                target.bar(0.0, 0.0, t * x_dim, layout.height * x_dim);
+                target.bar(right * x_dim, 0.0, t * x_dim, layout.height * x_dim);
+            }
+        }
+    }
+}
+
+/// Where the parts of a symbol go, in modules
+struct Layout {
+    origin: f64,    // left edge of the first module
+    bearer: f64,    // thickness of the top bearer bar
+    extension: f64, // how far guard bars extend below the others
+    width: f64,
+    height: f64,
+}
+
+impl Layout {
+    fn of<S: SymbolEncoder + ?Sized>(symbol: &S) -> Self {
+        let (left, right) = symbol.quiet_zones();
+        let bearer = symbol.bearer_bars();
+        let thickness = bearer.map_or(0.0, |b| b.thickness);
+        let side = match bearer {
+            Some(b) if b.frame => thickness,
+            _ => 0.0,
+        };
+        let pattern = symbol.modules();
+        let extension = if pattern.guards().contains(&true) {
+            GUARD_EXTENSION
+        } else {
+            0.0
+        };
+        Layout {
+            origin: side + left as f64,
+            bearer: thickness,
+            extension,
+            width: 2.0 * side + (left + pattern.len() + right) as f64,
+            height: 2.0 * thickness + symbol.bar_height() + extension,
+        }
+    }
+}
+
+/// EAN-13, UPC-A, UPC-E or EAN-8, whichever the GTIN is
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct EanUpcSymbol {
+    kind: GtinKind,
+    pattern: Pattern,
+}
+
+impl EanUpcSymbol {
+    /// None for GTIN-14, and for UPC-E outside number systems 0 and 1
+    pub fn new(gtin: &GTIN) -> Option<Self> {
+        let digits = gtin.digits();
+        let pattern = match gtin.kind() {
+            GtinKind::Ean13 => encode_ean13(digits.try_into().ok()?),
+            GtinKind::UpcA => encode_upca(digits.try_into().ok()?),
+            GtinKind::UpcE => encode_upce(digits.try_into().ok()?)?,
+            GtinKind::Ean8 => encode_ean8(digits.try_into().ok()?),
+            GtinKind::Gtin14 => return None,
+        };
+        Some(EanUpcSymbol {
+            kind: gtin.kind(),
+            pattern,
+        })
+    }
+}
+
+impl SymbolEncoder for EanUpcSymbol {
+    fn symbology(&self) -> Symbology {
+        match self.kind {
+            GtinKind::Ean13 => Symbology::Ean13,
+            GtinKind::UpcA => Symbology::UpcA,
+            GtinKind::UpcE => Symbology::UpcE,
+            GtinKind::Ean8 => Symbology::Ean8,
+            GtinKind::Gtin14 => unreachable!("GTIN-14 has no EAN/UPC symbol"),
+        }
+    }
+
+    fn modules(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    fn quiet_zones(&self) -> (usize, usize) {
+        quiet_zones(self.kind)
+    }
+
+    fn bar_height(&self) -> f64 {
+        match self.kind {
+            GtinKind::Ean8 => EAN8_BAR_HEIGHT,
+            _ => EAN_UPC_BAR_HEIGHT,
+        }
+    }
+}
+
+/// ITF-14 of the GTIN's 14-digit form, for outer cases
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Itf14Symbol {
+    pattern: Pattern,
+}
+
+impl Itf14Symbol {
+    pub fn new(gtin: &GTIN) -> Self {
+        let digits = crate::key_digits(gtin.canonical_key()).expect("keys have 14 digits");
+        Itf14Symbol {
+            pattern: encode_itf14(&digits),
+        }
+    }
+}
+
+impl SymbolEncoder for Itf14Symbol {
+    fn symbology(&self) -> Symbology {
+        Symbology::Itf14
+    }
+
+    fn modules(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    fn quiet_zones(&self) -> (usize, usize) {
+        quiet_zones(GtinKind::Gtin14)
+    }
+
+    fn bar_height(&self) -> f64 {
+        ITF14_BAR_HEIGHT
+    }
+
+    fn bearer_bars(&self) -> Option<BearerBars> {
+        bearer_bars(GtinKind::Gtin14)
+    }
+}
+
+/// A GS1 DataBar symbol. DataBar needs no quiet zones.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct DataBarSymbol {
+    variant: DataBar,
+    pattern: Pattern,
+}
+
+impl DataBarSymbol {
+    /// None for Limited when the indicator digit is not 0 or 1
+    pub fn new(gtin: &GTIN, variant: DataBar) -> Option<Self> {
+        Some(DataBarSymbol {
+            variant,
+            pattern: encode_databar(gtin, variant)?,
+        })
+    }
+}
+
+impl SymbolEncoder for DataBarSymbol {
+    fn symbology(&self) -> Symbology {
+        match self.variant {
+            DataBar::Omnidirectional => Symbology::DataBarOmnidirectional,
+            DataBar::Truncated => Symbology::DataBarTruncated,
+            DataBar::Limited => Symbology::DataBarLimited,
+        }
+    }
+
+    fn modules(&self) -> &Pattern {
+        &self.pattern
+    }
+
+    fn quiet_zones(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    fn bar_height(&self) -> f64 {
+        f64::from(self.variant.min_height())
+    }
+}
+
+/// The symbol the GTIN is normally printed with, as `encode` chooses: ITF-14 for GTIN-14,
+/// EAN/UPC otherwise
+pub fn symbol(gtin: &GTIN) -> Option<Box<dyn SymbolEncoder>> {
+    match gtin.kind() {
+        GtinKind::Gtin14 => Some(Box::new(Itf14Symbol::new(gtin))),
+        _ => Some(Box::new(EanUpcSymbol::new(gtin)?)),
+    }
+}
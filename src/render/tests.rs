@@ -0,0 +1,205 @@
+use super::addon::{Currency, PriceAddOn, PriceLocale};
+use super::symbol::{symbol, DataBarSymbol, Dimensions, RenderTarget, SymbolEncoder};
+use super::{bar_runs, hri_layout};
+use crate::encode::databar::DataBar;
+use crate::encode::{encode, Symbology};
+use crate::GTIN;
+
+#[test]
+fn merge_bar_runs() {
+    let pattern = encode(&GTIN::try_from("8595701 530526").unwrap()).unwrap();
+    let runs = bar_runs(&pattern);
+    // EAN-13 has 30 bars: 2 per digit plus 2 per guard pattern
+    assert_eq!(runs.len(), 30);
+    assert!(runs[0].guard && runs[0].width == 1);
+    assert_eq!(runs.iter().filter(|run| run.guard).count(), 6);
+}
+
+#[test]
+fn upca_text_layout() {
+    let layout = hri_layout(&GTIN::try_from("0 71720 53977 4").unwrap());
+    let texts: Vec<&str> = layout.iter().map(|item| item.text.as_str()).collect();
+    assert_eq!(texts, vec!["0", "71720", "53977", "4"]);
+    assert!(layout[0].small && layout[3].small);
+}
+
+#[test]
+fn hri_grouping() {
+    let cases = vec![
+        ("071720539774", "0 71720 53977 4"),
+        ("8595701530526", "8 595701 530526"),
+        ("05201341", "0520 1341"),
+        ("10000052", "1 000005 2"), // parses as UPC-E
+        ("00071720539774", "0 00 71720 53977 4"),
+    ];
+
+    for (input, expected) in cases {
+        assert_eq!(GTIN::try_from(input).unwrap().hri(), expected);
+    }
+}
+
+#[test]
+fn price_addon_caption() {
+    let price = PriceAddOn::parse("51995").unwrap();
+    assert_eq!(price.currency, Currency::Usd);
+    assert_eq!(price.caption(&PriceLocale::ENGLISH), "$19.95");
+    assert_eq!(price.caption(&PriceLocale::FRENCH), "19,95 $");
+    assert_eq!(price.caption(&PriceLocale::DUTCH), "$ 19,95");
+
+    let price = PriceAddOn::parse("60750").unwrap();
+    assert_eq!(price.caption(&PriceLocale::FRENCH), "7,50 C$");
+    assert_eq!(
+        PriceAddOn::parse("00899")
+            .unwrap()
+            .caption(&Default::default()),
+        "£8.99"
+    );
+
+    assert_eq!(PriceAddOn::parse("90000"), None); // no suggested price
+    assert_eq!(PriceAddOn::parse("5199"), None);
+}
+
+#[test]
+fn render_ascii_art() {
+    let art = GTIN::try_from("8595701 530526")
+        .unwrap()
+        .to_ascii_art(3)
+        .unwrap();
+    let lines: Vec<&str> = art.lines().collect();
+    assert_eq!(lines.len(), 4);
+    assert!(lines.iter().all(|line| line.chars().count() == 113));
+    assert_eq!(lines[0], lines[2]);
+
+    // The last row has the guard bars with the digits in between
+    let digits: String = lines[3].chars().filter(|c| c.is_ascii_digit()).collect();
+    assert_eq!(digits, "8595701530526");
+    assert_eq!(lines[3].matches('█').count(), 6);
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn render_svg() {
+    use super::svg::SvgOptions;
+
+    let gtin = GTIN::try_from("8595701 530526").unwrap();
+    let svg = gtin.to_svg(SvgOptions::default()).unwrap();
+    assert!(svg.starts_with("<svg"));
+    // 113 modules including quiet zones at 0.33mm
+    assert!(svg.contains(r#"width="37.29mm""#));
+    // Background plus one rect per bar
+    assert_eq!(svg.matches("<rect").count(), 31);
+    assert_eq!(svg.matches("<text").count(), 3);
+}
+
+#[cfg(feature = "svg")]
+#[test]
+fn render_svg_bearer_bars() {
+    use super::svg::SvgOptions;
+
+    let gtin = GTIN::try_from("0 0071720 53977 4").unwrap();
+    let svg = gtin.to_svg(SvgOptions::default()).unwrap();
+    // 155 modules including quiet zones plus a 4.8 module frame either side
+    assert!(svg.contains(r#"width="54.318mm""#));
+    // Background, 39 bars and four sides of the frame
+    assert_eq!(svg.matches("<rect").count(), 44);
+    // The digits are centred under the symbol
+    assert!(svg.contains(r#"<text x="27.159""#));
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_image() {
+    let gtin = GTIN::try_from("8595701 530526").unwrap();
+    let image = gtin.to_image(2, 100).unwrap();
+    // 113 modules including quiet zones, guard bars 5 modules longer than the others
+    assert_eq!(image.dimensions(), (226, 110));
+    assert_eq!(image.get_pixel(22, 0).0, [0]); // first bar of the start guard
+    assert_eq!(image.get_pixel(24, 0).0, [255]);
+    assert_eq!(image.get_pixel(22, 105).0, [0]); // guards are extended
+
+    let with_text = gtin.to_image_with_hri(2, 100).unwrap();
+    assert_eq!(with_text.dimensions(), (226, 118));
+    let ink = |image: &image::GrayImage| image.pixels().filter(|p| p.0 == [0]).count();
+    assert!(ink(&with_text) > ink(&image) - 2 * 100 * 5); // digits add ink below the bars
+}
+
+#[cfg(feature = "image")]
+#[test]
+fn render_image_bearer_bars() {
+    let gtin = GTIN::try_from("0 0071720 53977 4").unwrap();
+    let image = gtin.to_image(2, 100).unwrap();
+    // 10 pixel bearer bars framing 155 modules of quiet zones and bars
+    assert_eq!(image.dimensions(), (330, 120));
+    assert_eq!(image.get_pixel(5, 60).0, [0]); // left side of the frame
+    assert_eq!(image.get_pixel(25, 60).0, [255]); // quiet zone
+    assert_eq!(image.get_pixel(30, 60).0, [0]); // first bar of the start pattern
+    assert_eq!(image.get_pixel(30, 5).0, [0]); // top bearer
+    assert_eq!(image.get_pixel(32, 60).0, [255]);
+}
+
+#[test]
+fn hri_display() {
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(format!("[{}]", gtin.hri_display()), "[0 71720 53977 4]");
+    assert_eq!(format!("{}", gtin), "UPC-A: 071720539774");
+    assert_eq!(
+        format!("{:>14}", crate::util::Digits(gtin.digits())),
+        "  071720539774"
+    );
+}
+
+/// Collects the rectangles a symbol draws
+#[derive(Default)]
+struct Rects {
+    size: Option<Dimensions>,
+    bars: Vec<[f64; 4]>,
+}
+
+impl RenderTarget for Rects {
+    fn begin(&mut self, dimensions: Dimensions) {
+        self.size = Some(dimensions);
+    }
+
+    fn bar(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.bars.push([x, y, width, height]);
+    }
+}
+
+fn draw(symbol: &dyn SymbolEncoder, x_dim: f64) -> Rects {
+    let mut rects = Rects::default();
+    symbol.render(&mut rects, x_dim);
+    rects
+}
+
+#[test]
+fn symbol_encoders() {
+    let ean13 = symbol(&GTIN::try_from("8595701530526").unwrap()).unwrap();
+    assert_eq!(ean13.symbology(), Symbology::Ean13);
+    assert_eq!(ean13.modules().len(), 95);
+    let rects = draw(ean13.as_ref(), 1.0);
+    assert_eq!(rects.size, Some(ean13.dimensions(1.0)));
+    assert_eq!(rects.bars.len(), 30);
+    // The start guard follows the 11-module quiet zone and extends below the other bars
+    let [x, y, width, height] = rects.bars[0];
+    assert_eq!((x, y, width), (11.0, 0.0, 1.0));
+    assert_eq!(height, rects.size.unwrap().height);
+    assert!(rects.bars[2][3] < height);
+
+    let size = ean13.dimensions(0.33);
+    assert!((size.width - 37.29).abs() < 1e-9);
+    assert!((size.height - 24.5).abs() < 1e-9);
+
+    // ITF-14 is framed by bearer bars and has no guards
+    let itf14 = symbol(&GTIN::try_from("10071720539771").unwrap()).unwrap();
+    assert_eq!(itf14.symbology(), Symbology::Itf14);
+    let rects = draw(itf14.as_ref(), 1.0);
+    let size = rects.size.unwrap();
+    let bearers = &rects.bars[rects.bars.len() - 4..];
+    assert_eq!(bearers[0], [0.0, 0.0, size.width, 4.8]);
+    assert!(rects.bars[0][0] > 4.8 + 9.0);
+
+    let limited = DataBarSymbol::new(&GTIN::try_from("00071720539774").unwrap(), DataBar::Limited);
+    assert_eq!(limited.unwrap().symbology(), Symbology::DataBarLimited);
+    let limited = DataBarSymbol::new(&GTIN::try_from("20071720539778").unwrap(), DataBar::Limited);
+    assert!(limited.is_none());
+}
@@ -0,0 +1,148 @@
+use serde::Serialize;
+
+use crate::{ConversionError, GtinError, GtinKind, NumberSystem, PREFIX_DATA_VERSION};
+
+/// The crate's public data model, for generating matching types in other languages
+#[derive(Debug, PartialEq, Eq, Clone, Serialize)]
+pub struct Schema {
+    pub crate_version: &'static str,
+    pub prefix_data_version: &'static str,
+    pub kinds: Vec<KindSchema>,
+    pub number_systems: Vec<&'static str>,
+    pub errors: Vec<ErrorSchema>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct KindSchema {
+    pub name: &'static str, // as serialized
+    pub digit_count: usize,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize)]
+pub struct ErrorSchema {
+    pub error: &'static str, // the Rust error type, e.g. GtinError
+    pub code: &'static str,  // the variant
+    pub fields: &'static [&'static str],
+}
+
+impl Schema {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("schema serializes to JSON")
+    }
+}
+
+const KINDS: [GtinKind; 5] = [
+    GtinKind::UpcE,
+    GtinKind::UpcA,
+    GtinKind::Ean8,
+    GtinKind::Ean13,
+    GtinKind::Gtin14,
+];
+
+const NUMBER_SYSTEMS: [NumberSystem; 8] = [
+    NumberSystem::General,
+    NumberSystem::StoreUse,
+    NumberSystem::Coupon,
+    NumberSystem::Drug,
+    NumberSystem::Issn,
+    NumberSystem::Isbn,
+    NumberSystem::Refund,
+    NumberSystem::Unknown,
+];
+
+const GTIN_ERRORS: [GtinError; 5] = [
+    GtinError::InvalidChecksum,
+    GtinError::UnsupportedLength(0),
+    GtinError::InvalidLength {
+        kind: GtinKind::UpcE,
+        found: 0,
+    },
+    GtinError::InvalidDigit(0),
+    GtinError::InvalidPadding,
+];
+
+const CONVERSION_ERRORS: [ConversionError; 2] = [
+    ConversionError::NotRepresentable {
+        from: GtinKind::UpcE,
+        to: GtinKind::UpcE,
+    },
+    ConversionError::NonZeroIndicator(0),
+];
+
+// The matches below are exhaustive, so a new variant fails to compile until it is
+// described here. Nothing checks the lists above, which need the variant added by hand.
+
+pub(crate) fn kind_name(kind: GtinKind) -> &'static str {
+    match kind {
+        GtinKind::UpcE => "UpcE",
+        GtinKind::UpcA => "UpcA",
+        GtinKind::Ean8 => "Ean8",
+        GtinKind::Ean13 => "Ean13",
+        GtinKind::Gtin14 => "Gtin14",
+    }
+}
+
+pub(crate) fn number_system_name(number_system: NumberSystem) -> &'static str {
+    match number_system {
+        NumberSystem::General => "General",
+        NumberSystem::StoreUse => "StoreUse",
+        NumberSystem::Coupon => "Coupon",
+        NumberSystem::Drug => "Drug",
+        NumberSystem::Issn => "Issn",
+        NumberSystem::Isbn => "Isbn",
+        NumberSystem::Refund => "Refund",
+        NumberSystem::Unknown => "Unknown",
+    }
+}
+
+pub(crate) fn gtin_error_schema(error: &GtinError) -> ErrorSchema {
+    let (code, fields): (_, &[_]) = match error {
+        GtinError::InvalidChecksum => ("InvalidChecksum", &[]),
+        GtinError::UnsupportedLength(_) => ("UnsupportedLength", &["length"]),
+        GtinError::InvalidLength { .. } => ("InvalidLength", &["kind", "found"]),
+        GtinError::InvalidDigit(_) => ("InvalidDigit", &["digit"]),
+        GtinError::InvalidPadding => ("InvalidPadding", &[]),
+    };
+    ErrorSchema {
+        error: "GtinError",
+        code,
+        fields,
+    }
+}
+
+fn conversion_error_schema(error: &ConversionError) -> ErrorSchema {
+    let (code, fields): (_, &[_]) = match error {
+        ConversionError::NotRepresentable { .. } => ("NotRepresentable", &["from", "to"]),
+        ConversionError::NonZeroIndicator(_) => ("NonZeroIndicator", &["indicator"]),
+    };
+    ErrorSchema {
+        error: "ConversionError",
+        code,
+        fields,
+    }
+}
+
+/// Describe the GTIN kinds, number systems and errors of this build, with the prefix
+/// table version, so other languages can generate enums that stay in step with it
+pub fn schema() -> Schema {
+    Schema {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        prefix_data_version: PREFIX_DATA_VERSION,
+        kinds: KINDS
+            .iter()
+            .map(|&kind| KindSchema {
+                name: kind_name(kind),
+                digit_count: kind.digit_count(),
+            })
+            .collect(),
+        number_systems: NUMBER_SYSTEMS
+            .iter()
+            .map(|&number_system| number_system_name(number_system))
+            .collect(),
+        errors: GTIN_ERRORS
+            .iter()
+            .map(gtin_error_schema)
+            .chain(CONVERSION_ERRORS.iter().map(conversion_error_schema))
+            .collect(),
+    }
+}
@@ -0,0 +1,28 @@
+//! Serialize and deserialize a `GTIN` as an integer, for JSON APIs that encode GTINs as
+//! numbers. Leading zeros are restored the way `TryFrom<u64>` restores them.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Product {
+//!     #[serde(with = "gtin::serde::as_u64")]
+//!     gtin: gtin::GTIN,
+//! }
+//! ```
+
+use serde::{de, Deserialize, Deserializer, Serializer};
+
+use crate::GTIN;
+
+pub fn serialize<S>(gtin: &GTIN, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(gtin.to_u64())
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GTIN, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    GTIN::try_from(u64::deserialize(deserializer)?).map_err(de::Error::custom)
+}
@@ -0,0 +1,98 @@
+//! Serialize and deserialize GTINs so their kind survives the round trip. Eight digits can be
+//! read as UPC-E or EAN-8, so when the plain digits would parse as the other kind they are
+//! written with the AIM symbology identifier for their kind (`]E0` for UPC-E, `]E4` for
+//! EAN-8). Every other GTIN is written as its plain digit string.
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Catalogue {
+//!     #[serde(with = "gtin::serde::kind_tagged::vec")]
+//!     items: Vec<gtin::GTIN>,
+//!     #[serde(with = "gtin::serde::kind_tagged::map")]
+//!     by_sku: HashMap<String, gtin::GTIN>,
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::split::to_tagged_string;
+use crate::GTIN;
+
+struct Tagged<'a>(&'a GTIN);
+
+impl Serialize for Tagged<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&to_tagged_string(self.0))
+    }
+}
+
+pub fn serialize<S>(gtin: &GTIN, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    Tagged(gtin).serialize(serializer)
+}
+
+/// The parser already reads the identifier, so tagged and plain strings both deserialize
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GTIN, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    GTIN::deserialize(deserializer)
+}
+
+/// For sequences of GTINs, e.g. `Vec<GTIN>`
+pub mod vec {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::Tagged;
+    use crate::GTIN;
+
+    pub fn serialize<'a, C, S>(gtins: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a GTIN>,
+        S: Serializer,
+    {
+        serializer.collect_seq(gtins.into_iter().map(Tagged))
+    }
+
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<GTIN>,
+        D: Deserializer<'de>,
+    {
+        Vec::<GTIN>::deserialize(deserializer).map(|gtins| gtins.into_iter().collect())
+    }
+}
+
+/// For maps with GTIN values, e.g. `HashMap<String, GTIN>`
+pub mod map {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::Tagged;
+    use crate::GTIN;
+
+    pub fn serialize<'a, K, M, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        K: Serialize + 'a,
+        &'a M: IntoIterator<Item = (&'a K, &'a GTIN)>,
+        S: Serializer,
+    {
+        serializer.collect_map(map.into_iter().map(|(key, gtin)| (key, Tagged(gtin))))
+    }
+
+    pub fn deserialize<'de, K, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        K: Deserialize<'de> + Ord,
+        M: FromIterator<(K, GTIN)>,
+        D: Deserializer<'de>,
+    {
+        std::collections::BTreeMap::<K, GTIN>::deserialize(deserializer)
+            .map(|map| map.into_iter().collect())
+    }
+}
@@ -0,0 +1,62 @@
+//! Deserialize a `GTIN` from anything the parser can make sense of: strings with separators,
+//! identifiers or a missing leading zero, as `TryFrom<&str>` accepts, and integers, as
+//! `TryFrom<u64>` accepts. For feeds that send bare numbers; the integers need a
+//! self-describing format such as JSON. Serializes as the digit string.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Row {
+//!     #[serde(with = "gtin::serde::lenient")]
+//!     gtin: gtin::GTIN,
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::GTIN;
+
+pub fn serialize<S>(gtin: &GTIN, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    gtin.serialize(serializer)
+}
+
+struct LenientVisitor;
+
+impl Visitor<'_> for LenientVisitor {
+    type Value = GTIN;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a GTIN as a string or an unsigned integer")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        GTIN::try_from(value).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        GTIN::try_from(value).map_err(E::custom)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(de::Unexpected::Signed(value), &self)),
+        }
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GTIN, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // binary formats carry the packed form `GTIN` serializes to
+    if !deserializer.is_human_readable() {
+        return GTIN::deserialize(deserializer);
+    }
+    deserializer.deserialize_any(LenientVisitor)
+}
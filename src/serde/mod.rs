@@ -0,0 +1,45 @@
+//! Helpers for `#[serde(with = "...")]` attributes, for fields that need a representation
+//! other than the default digit string.
+
+pub mod as_u64;
+pub mod kind_tagged;
+pub mod lenient;
+pub mod option_empty_as_none;
+pub mod prefer_upc_a;
+pub mod strict;
+
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+
+use serde::de::{self, Visitor};
+use serde::Deserializer;
+
+/// Parse a string value, borrowing it from the input when the format allows, so reading a
+/// large file doesn't allocate a temporary string per value
+pub(crate) fn deserialize_str<'de, D, T, E>(
+    deserializer: D,
+    parse: fn(&str) -> Result<T, E>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    E: Display,
+{
+    deserializer.deserialize_str(StrVisitor(parse, PhantomData))
+}
+
+struct StrVisitor<T, E>(fn(&str) -> Result<T, E>, PhantomData<T>);
+
+impl<T, E: Display> Visitor<'_> for StrVisitor<T, E> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string")
+    }
+
+    fn visit_str<Er: de::Error>(self, value: &str) -> Result<T, Er> {
+        (self.0)(value).map_err(Er::custom)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
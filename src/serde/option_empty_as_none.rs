@@ -0,0 +1,73 @@
+//! Deserialize an `Option<GTIN>` that feeds leave empty for products without a barcode:
+//! null, a missing field (with `#[serde(default)]`), an empty string and a string of
+//! whitespace are all `None`, while anything else must be a valid GTIN. `None` serializes
+//! as null.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Product {
+//!     #[serde(default, with = "gtin::serde::option_empty_as_none")]
+//!     gtin: Option<gtin::GTIN>,
+//! }
+//! ```
+
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::GTIN;
+
+pub fn serialize<S>(gtin: &Option<GTIN>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    gtin.serialize(serializer)
+}
+
+struct OptionVisitor;
+
+impl<'de> Visitor<'de> for OptionVisitor {
+    type Value = Option<GTIN>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a GTIN, an empty string or null")
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(self)
+        } else {
+            GTIN::deserialize(deserializer).map(Some)
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        if value.trim().is_empty() {
+            return Ok(None);
+        }
+        GTIN::try_from(value).map(Some).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        GTIN::try_from(value).map(Some).map_err(E::custom)
+    }
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<GTIN>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_option(OptionVisitor)
+}
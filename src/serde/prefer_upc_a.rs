@@ -0,0 +1,28 @@
+//! Serialize and deserialize a `GTIN` normalized with `GTIN::prefer_upc_a`, so zero-padded
+//! UPC-A codes are written with 12 digits.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Product {
+//!     #[serde(with = "gtin::serde::prefer_upc_a")]
+//!     gtin: gtin::GTIN,
+//! }
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::GTIN;
+
+pub fn serialize<S>(gtin: &GTIN, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    gtin.prefer_upc_a().serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GTIN, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    GTIN::deserialize(deserializer).map(GTIN::prefer_upc_a)
+}
@@ -0,0 +1,37 @@
+//! Deserialize a `GTIN` only from exactly its digits: 8, 12, 13 or 14 ASCII digits with no
+//! separators, identifiers or missing leading zeros, for API boundaries that should reject
+//! anything the default lenient parsing would repair. Serializes as the digit string.
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Order {
+//!     #[serde(with = "gtin::serde::strict")]
+//!     gtin: gtin::GTIN,
+//! }
+//! ```
+
+use serde::{Deserializer, Serialize, Serializer};
+
+use crate::GTIN;
+
+pub fn serialize<S>(gtin: &GTIN, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    gtin.serialize(serializer)
+}
+
+pub fn deserialize<'de, D>(deserializer: D) -> Result<GTIN, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    crate::serde::deserialize_str(deserializer, parse_exact)
+}
+
+fn parse_exact(s: &str) -> Result<GTIN, String> {
+    let exact = matches!(s.len(), 8 | 12 | 13 | 14) && s.bytes().all(|b| b.is_ascii_digit());
+    if !exact {
+        return Err(format!("{:?} is not exactly the digits of a GTIN", s));
+    }
+    GTIN::try_from(s).map_err(|e| e.to_string())
+}
@@ -0,0 +1,274 @@
+use serde::{Deserialize, Serialize};
+
+use crate::GTIN;
+
+#[test]
+fn prefer_upc_a() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let cases = vec![
+        ("0071720539774", upca),  // zero-padded EAN-13
+        ("00071720539774", upca), // zero-padded GTIN-14
+        ("071720539774", upca),
+        (
+            "8595701530526",
+            GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        assert_eq!(
+            gtin.prefer_upc_a(),
+            expected,
+            "Failed to normalize {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn upce_round_trip() {
+    // UPC-E carries the check digit of its UPC-A expansion, not the flat one of its digits
+    for gtin in [
+        GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]),
+        GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]),
+    ] {
+        let json = serde_json::to_string(&gtin).unwrap();
+        assert_eq!(serde_json::from_str::<GTIN>(&json).unwrap(), gtin, "{}", json);
+    }
+}
+
+#[test]
+fn prefer_upc_a_adapter() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Product {
+        #[serde(with = "crate::serde::prefer_upc_a")]
+        gtin: GTIN,
+    }
+
+    let product: Product = serde_json::from_str(r#"{"gtin":"0071720539774"}"#).unwrap();
+    assert_eq!(
+        product.gtin,
+        GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])
+    );
+
+    let product = Product {
+        gtin: GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
+    };
+    assert_eq!(
+        serde_json::to_string(&product).unwrap(),
+        r#"{"gtin":"071720539774"}"#
+    );
+}
+
+#[test]
+fn kind_tagged_adapter() {
+    use std::collections::HashMap;
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Catalogue {
+        #[serde(with = "crate::serde::kind_tagged::vec")]
+        items: Vec<GTIN>,
+        #[serde(with = "crate::serde::kind_tagged::map")]
+        by_sku: HashMap<String, GTIN>,
+    }
+
+    let catalogue = Catalogue {
+        items: vec![
+            GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]),
+            GTIN::Ean8([1, 0, 0, 0, 0, 0, 5, 2]), // would read as UPC-E
+            GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]), // fails the EAN-8 check digit
+            GTIN::UpcE([0, 4, 1, 8, 2, 6, 5, 8]), // would read as EAN-8
+            GTIN::Ean8([0, 0, 1, 2, 3, 4, 5, 7]),
+            GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
+        ],
+        by_sku: [("a".to_string(), GTIN::Ean8([1, 0, 0, 0, 0, 0, 5, 2]))]
+            .into_iter()
+            .collect(),
+    };
+
+    let json = serde_json::to_string(&catalogue).unwrap();
+    assert_eq!(
+        json,
+        r#"{"items":["10000052","]E410000052","04182635","]E004182658","00123457","071720539774"],"by_sku":{"a":"]E410000052"}}"#
+    );
+    assert_eq!(serde_json::from_str::<Catalogue>(&json).unwrap(), catalogue);
+
+    // Plain strings only lose the kind of codes that are valid both ways
+    let plain = serde_json::to_string(&catalogue.items).unwrap();
+    let read = serde_json::from_str::<Vec<GTIN>>(&plain).unwrap();
+    assert_eq!(read[1], GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]));
+    assert_eq!(read[2], catalogue.items[2]);
+    assert_eq!(read[3], GTIN::Ean8([0, 4, 1, 8, 2, 6, 5, 8]));
+}
+
+#[test]
+fn as_u64_adapter() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Product {
+        #[serde(with = "crate::serde::as_u64")]
+        gtin: GTIN,
+    }
+
+    let product = Product {
+        gtin: GTIN::try_from("071720539774").unwrap(),
+    };
+    let json = serde_json::to_string(&product).unwrap();
+    assert_eq!(json, r#"{"gtin":71720539774}"#);
+    assert_eq!(serde_json::from_str::<Product>(&json).unwrap(), product);
+
+    let ean13: Product = serde_json::from_str(r#"{"gtin":4006381333931}"#).unwrap();
+    assert_eq!(ean13.gtin, GTIN::try_from("4006381333931").unwrap());
+
+    assert!(serde_json::from_str::<Product>(r#"{"gtin":71720539775}"#).is_err());
+    assert!(serde_json::from_str::<Product>(r#"{"gtin":"071720539774"}"#).is_err());
+}
+
+#[test]
+fn strict_and_lenient_adapters() {
+    #[derive(Deserialize, Debug, PartialEq)]
+    struct Product {
+        #[serde(with = "crate::serde::strict")]
+        strict: GTIN,
+        #[serde(with = "crate::serde::lenient")]
+        lenient: GTIN,
+    }
+
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    let product: Product =
+        serde_json::from_str(r#"{"strict":"071720539774","lenient":"0 71720-53977 4"}"#)
+            .unwrap();
+    assert_eq!(product.strict, gtin);
+    assert_eq!(product.lenient, gtin);
+
+    for lenient in [r#""71720539774""#, "71720539774", r#""]E0071720539774""#] {
+        let json = format!(r#"{{"strict":"4006381333931","lenient":{}}}"#, lenient);
+        let product: Product = serde_json::from_str(&json).unwrap();
+        assert_eq!(product.lenient, gtin, "{}", lenient);
+    }
+
+    for strict in [
+        r#""0 71720 53977 4""#,
+        r#""71720539774""#,
+        r#""]E0071720539774""#,
+        r#""071720539775""#,
+        r#""+71720539774""#,
+        "71720539774",
+    ] {
+        let json = format!(r#"{{"strict":{},"lenient":"071720539774"}}"#, strict);
+        assert!(serde_json::from_str::<Product>(&json).is_err(), "{}", strict);
+    }
+}
+
+#[test]
+fn lenient_json_numbers() {
+    #[derive(Deserialize)]
+    struct Product {
+        #[serde(with = "crate::serde::lenient")]
+        gtin: GTIN,
+    }
+
+    let cases = vec![
+        ("71720539774", "071720539774"),
+        ("4006381333931", "4006381333931"),
+        ("10071720539771", "10071720539771"),
+        ("96385074", "96385074"),
+    ];
+    for (json, expected) in cases {
+        let product: Product = serde_json::from_str(&format!(r#"{{"gtin":{}}}"#, json)).unwrap();
+        assert_eq!(product.gtin, GTIN::try_from(expected).unwrap(), "{}", json);
+    }
+
+    for json in ["71720539775", "-71720539774", "71720539774.0", "true"] {
+        let json = format!(r#"{{"gtin":{}}}"#, json);
+        assert!(serde_json::from_str::<Product>(&json).is_err(), "{}", json);
+    }
+}
+
+#[cfg(feature = "csv")]
+#[test]
+fn csv_keeps_leading_zeros() {
+    #[derive(Serialize, Deserialize)]
+    struct Row {
+        gtin: GTIN,
+    }
+
+    for input in ["071720539774", "0071720539774", "00071720539774"] {
+        let gtin = GTIN::try_from(input).unwrap();
+        let mut writer = csv::Writer::from_writer(vec![]);
+        writer.serialize(Row { gtin }).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut reader = csv::Reader::from_reader(data.as_slice());
+        let row: Row = reader.deserialize().next().unwrap().unwrap();
+        assert_eq!(row.gtin, gtin, "{}", input);
+    }
+}
+
+#[test]
+fn option_empty_as_none_adapter() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Product {
+        name: String,
+        #[serde(default, with = "crate::serde::option_empty_as_none")]
+        gtin: Option<GTIN>,
+    }
+
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    let cases = vec![
+        (r#"{"name":"Oreo","gtin":"071720539774"}"#, Some(gtin)),
+        (r#"{"name":"Oreo","gtin":"0 71720 53977 4"}"#, Some(gtin)),
+        (r#"{"name":"Oreo","gtin":71720539774}"#, Some(gtin)),
+        (r#"{"name":"Handmade","gtin":""}"#, None),
+        (r#"{"name":"Handmade","gtin":"   "}"#, None),
+        (r#"{"name":"Handmade","gtin":null}"#, None),
+        (r#"{"name":"Handmade"}"#, None),
+    ];
+    for (json, expected) in cases {
+        let product: Product = serde_json::from_str(json).unwrap();
+        assert_eq!(product.gtin, expected, "{}", json);
+    }
+
+    for json in [
+        r#"{"name":"Oreo","gtin":"071720539775"}"#,
+        r#"{"name":"Oreo","gtin":"N/A"}"#,
+    ] {
+        assert!(serde_json::from_str::<Product>(json).is_err(), "{}", json);
+    }
+
+    let product = Product {
+        name: "Handmade".to_string(),
+        gtin: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&product).unwrap(),
+        r#"{"name":"Handmade","gtin":null}"#
+    );
+}
+
+#[test]
+fn borrowed_and_escaped_strings() {
+    // An escape makes serde_json hand over an owned string instead of borrowing from the
+    // input, which the string visitors have to accept all the same
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    for json in [r#""071720539774""#, r#""\u003071720539774""#] {
+        assert_eq!(serde_json::from_str::<GTIN>(json).unwrap(), gtin, "{}", json);
+        let exact: crate::ExactGtin = serde_json::from_str(json).unwrap();
+        assert_eq!(exact.gtin(), &gtin, "{}", json);
+        let optional: crate::OptionalGtin = serde_json::from_str(json).unwrap();
+        assert_eq!(optional, crate::OptionalGtin::Present(gtin), "{}", json);
+
+        #[derive(Deserialize)]
+        struct Order {
+            #[serde(with = "crate::serde::strict")]
+            gtin: GTIN,
+        }
+        let order: Order = serde_json::from_str(&format!(r#"{{"gtin":{}}}"#, json)).unwrap();
+        assert_eq!(order.gtin, gtin, "{}", json);
+    }
+
+    let sscc = crate::Sscc::try_from("106141411234567897").unwrap();
+    for json in [r#""106141411234567897""#, r#""\u003106141411234567897""#] {
+        assert_eq!(serde_json::from_str::<crate::Sscc>(json).unwrap(), sscc);
+    }
+}
@@ -0,0 +1,119 @@
+//! A set of GTINs that treats the forms of one product as the same member, e.g. a UPC-A
+//! and its zero-padded EAN-13, for reconciling catalogs that store codes differently.
+
+use std::collections::btree_map::{self, BTreeMap};
+
+use crate::{GtinKey, GTIN};
+
+/// GTINs stored by `GTIN::canonical_key`, each as the form first inserted. Iteration is
+/// in canonical key order.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct GtinSet {
+    entries: BTreeMap<GtinKey, GTIN>,
+}
+
+impl GtinSet {
+    pub fn new() -> Self {
+        GtinSet::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add a GTIN, returning false and keeping the stored form if an equivalent one is
+    /// already present
+    pub fn insert(&mut self, gtin: GTIN) -> bool {
+        match self.entries.entry(gtin.canonical_key()) {
+            btree_map::Entry::Occupied(_) => false,
+            btree_map::Entry::Vacant(entry) => {
+                entry.insert(gtin);
+                true
+            }
+        }
+    }
+
+    /// Remove the member equivalent to `gtin`, returning its stored form
+    pub fn remove(&mut self, gtin: &GTIN) -> Option<GTIN> {
+        self.entries.remove(&gtin.canonical_key())
+    }
+
+    /// Whether any form of `gtin` is a member
+    pub fn contains_equivalent(&self, gtin: &GTIN) -> bool {
+        self.entries.contains_key(&gtin.canonical_key())
+    }
+
+    /// The stored form of the member equivalent to `gtin`
+    pub fn get(&self, gtin: &GTIN) -> Option<&GTIN> {
+        self.entries.get(&gtin.canonical_key())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &GTIN> + '_ {
+        self.entries.values()
+    }
+
+    /// Members of either set, in the form stored by `self` where both have one
+    pub fn union(&self, other: &GtinSet) -> GtinSet {
+        let mut union = self.clone();
+        union.extend(other.iter().copied());
+        union
+    }
+
+    /// Members of both sets, in the form stored by `self`
+    pub fn intersection(&self, other: &GtinSet) -> GtinSet {
+        self.filtered(|key| other.entries.contains_key(key))
+    }
+
+    /// Members of `self` with no equivalent in `other`
+    pub fn difference(&self, other: &GtinSet) -> GtinSet {
+        self.filtered(|key| !other.entries.contains_key(key))
+    }
+
+    fn filtered(&self, keep: impl Fn(&GtinKey) -> bool) -> GtinSet {
+        let entries = self.entries.iter().filter(|(key, _)| keep(key));
+        GtinSet {
+            entries: entries.map(|(&key, &gtin)| (key, gtin)).collect(),
+        }
+    }
+}
+
+impl Extend<GTIN> for GtinSet {
+    fn extend<I: IntoIterator<Item = GTIN>>(&mut self, iter: I) {
+        for gtin in iter {
+            self.insert(gtin);
+        }
+    }
+}
+
+impl FromIterator<GTIN> for GtinSet {
+    fn from_iter<I: IntoIterator<Item = GTIN>>(iter: I) -> Self {
+        let mut set = GtinSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl IntoIterator for GtinSet {
+    type Item = GTIN;
+    type IntoIter = btree_map::IntoValues<GtinKey, GTIN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_values()
+    }
+}
+
+impl<'a> IntoIterator for &'a GtinSet {
+    type Item = &'a GTIN;
+    type IntoIter = btree_map::Values<'a, GtinKey, GTIN>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.values()
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,62 @@
+use super::GtinSet;
+use crate::GTIN;
+
+fn gtin(value: &str) -> GTIN {
+    GTIN::try_from(value).unwrap()
+}
+
+#[test]
+fn equivalent_forms_dedupe() {
+    let upca = gtin("071720539774");
+    let ean13 = GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let gtin14 = GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+
+    let mut set = GtinSet::new();
+    assert!(set.insert(upca));
+    assert!(!set.insert(ean13));
+    assert!(!set.insert(gtin14));
+    assert_eq!(set.len(), 1);
+    assert!(set.contains_equivalent(&ean13));
+    assert_eq!(set.get(&gtin14), Some(&upca));
+
+    // UPC-E is a member through its UPC-A expansion
+    let upce = GTIN::UpcE([0, 0, 0, 0, 0, 0, 5, 5]);
+    set.insert(upce);
+    assert!(set.contains_equivalent(&gtin("000000000055")));
+
+    assert_eq!(set.remove(&ean13), Some(upca));
+    assert!(!set.contains_equivalent(&upca));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn set_operations_in_canonical_order() {
+    let ours: GtinSet = ["4006381333931", "071720539774", "96385074"]
+        .into_iter()
+        .map(gtin)
+        .collect();
+    let theirs: GtinSet = ["0071720539774", "10000052"]
+        .into_iter()
+        .map(gtin)
+        .collect();
+
+    let union: Vec<GTIN> = ours.union(&theirs).into_iter().collect();
+    assert_eq!(
+        union,
+        vec![
+            gtin("96385074"),
+            gtin("071720539774"),
+            gtin("10000052"),
+            gtin("4006381333931"),
+        ]
+    );
+
+    let intersection = ours.intersection(&theirs);
+    assert_eq!(
+        intersection.iter().collect::<Vec<_>>(),
+        vec![&gtin("071720539774")]
+    );
+    let difference = ours.difference(&theirs);
+    assert_eq!(difference.len(), 2);
+    assert!(!difference.contains_equivalent(&gtin("0071720539774")));
+}
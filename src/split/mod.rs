@@ -0,0 +1,130 @@
+//! Recovery of the individual codes from a scanner burst: several GTINs delivered in one
+//! message with no separator between them, as happens when a batch-mode scanner's suffix
+//! is misconfigured.
+
+use std::fmt::{Display, Formatter};
+
+use crate::util::{digits_to_string, validate_gtin, validate_upce};
+use crate::{GtinKind, GTIN};
+
+/// Lengths tried at each position, longest first
+const LENGTHS: [usize; 4] = [14, 13, 12, 8];
+
+/// Why a burst could not be split into GTINs
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SplitError {
+    Empty,
+    InvalidCharacter(char),
+    NoSegmentation { valid_prefix: usize }, // digits covered by the longest run of valid GTINs
+}
+
+impl Display for SplitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SplitError::Empty => write!(f, "Empty input"),
+            SplitError::InvalidCharacter(c) => write!(f, "Invalid character: {:?}", c),
+            SplitError::NoSegmentation { valid_prefix } => write!(
+                f,
+                "No sequence of valid GTINs covers the input (stuck after {} digits)",
+                valid_prefix
+            ),
+        }
+    }
+}
+
+impl std::error::Error for SplitError {}
+
+/// Split a run of concatenated GTINs, preferring the longest valid code at each position
+/// and backtracking when the rest of the input cannot be split. Whitespace is ignored.
+pub fn split_burst(input: &str) -> Result<Vec<GTIN>, SplitError> {
+    let mut digits = Vec::with_capacity(input.len());
+    for c in input.chars().filter(|c| !c.is_whitespace()) {
+        match c.to_digit(10) {
+            Some(d) if c.is_ascii_digit() => digits.push(d as u8),
+            _ => return Err(SplitError::InvalidCharacter(c)),
+        }
+    }
+    if digits.is_empty() {
+        return Err(SplitError::Empty);
+    }
+
+    let mut splitter = Splitter {
+        digits: &digits,
+        dead_ends: vec![false; digits.len()],
+        furthest: 0,
+    };
+    let mut gtins = Vec::new();
+    if splitter.split_from(0, &mut gtins) {
+        Ok(gtins)
+    } else {
+        Err(SplitError::NoSegmentation {
+            valid_prefix: splitter.furthest,
+        })
+    }
+}
+
+struct Splitter<'a> {
+    digits: &'a [u8],
+    dead_ends: Vec<bool>, // positions already known not to split, so backtracking stays linear
+    furthest: usize,
+}
+
+impl Splitter<'_> {
+    fn split_from(&mut self, start: usize, gtins: &mut Vec<GTIN>) -> bool {
+        if start == self.digits.len() {
+            return true;
+        }
+        if self.dead_ends[start] {
+            return false;
+        }
+        self.furthest = self.furthest.max(start);
+
+        for len in LENGTHS {
+            let Some(candidate) = self.digits.get(start..start + len) else {
+                continue;
+            };
+            let Some(gtin) = to_gtin(candidate) else {
+                continue;
+            };
+            gtins.push(gtin);
+            if self.split_from(start + len, gtins) {
+                return true;
+            }
+            gtins.pop();
+        }
+
+        self.dead_ends[start] = true;
+        false
+    }
+}
+
+pub(crate) fn to_gtin(digits: &[u8]) -> Option<GTIN> {
+    // Same heuristic as parsing: UPC-E rarely has a leading zero, unless the digits only
+    // pass its check
+    let flat = validate_gtin(digits);
+    let kind = match digits.len() {
+        8 if (!flat || digits[0] != 0) && validate_upce(digits) => GtinKind::UpcE,
+        _ if !flat => return None,
+        8 => GtinKind::Ean8,
+        12 => GtinKind::UpcA,
+        13 => GtinKind::Ean13,
+        _ => GtinKind::Gtin14,
+    };
+    GTIN::from_digits(kind, digits.iter().copied()).ok()
+}
+
+/// The digits, with the kind's identifier in front when the digits alone would read differently
+pub(crate) fn to_tagged_string(gtin: &GTIN) -> String {
+    let digits = digits_to_string(gtin.digits());
+    if to_gtin(gtin.digits()) == Some(*gtin) {
+        return digits;
+    }
+    match gtin.kind() {
+        GtinKind::UpcE => format!("]E0{}", digits),
+        GtinKind::Ean8 => format!("]E4{}", digits),
+        _ => digits,
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
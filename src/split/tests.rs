@@ -0,0 +1,44 @@
+use super::{split_burst, SplitError};
+use crate::GTIN;
+
+#[test]
+fn split_concatenated_codes() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let ean13 = GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+    let ean8 = GTIN::Ean8([0, 0, 1, 2, 3, 4, 5, 7]);
+
+    assert_eq!(split_burst("071720539774"), Ok(vec![upca]));
+    assert_eq!(
+        split_burst("0717205397748595701530526 00123457"),
+        Ok(vec![upca, ean13, ean8])
+    );
+    assert_eq!(
+        split_burst("8595701530526071720539774"),
+        Ok(vec![ean13, upca])
+    );
+}
+
+#[test]
+fn split_backtracks() {
+    // The first 13 digits happen to be a valid EAN-13, which leaves 11 digits over
+    let input = "625851781283657070499960";
+    assert!(GTIN::try_from(&input[..13]).is_ok());
+    let gtins = split_burst(input).unwrap();
+    assert_eq!(
+        gtins.iter().map(|g| g.digits().len()).collect::<Vec<_>>(),
+        vec![12, 12]
+    );
+}
+
+#[test]
+fn split_errors() {
+    assert_eq!(split_burst(" "), Err(SplitError::Empty));
+    assert_eq!(
+        split_burst("07172x"),
+        Err(SplitError::InvalidCharacter('x'))
+    );
+    assert_eq!(
+        split_burst("071720539774071720539775"),
+        Err(SplitError::NoSegmentation { valid_prefix: 12 })
+    );
+}
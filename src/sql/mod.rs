@@ -0,0 +1,52 @@
+//! `Type`, `Encode` and `Decode` for `GTIN`, so GTIN columns of any sqlx database map to
+//! the type directly. GTINs are bound as their digit string and read from any column the
+//! database's `String` reads, e.g. TEXT, VARCHAR or CHAR(14), through the lenient parsing
+//! of `GTIN::try_from`, which drops the trailing spaces CHAR pads values with.
+
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::{Database, Decode, Encode, Type};
+
+use crate::GTIN;
+
+impl<DB: Database> Type<DB> for GTIN
+where
+    String: Type<DB>,
+{
+    fn type_info() -> DB::TypeInfo {
+        <String as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <String as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for GTIN
+where
+    String: Encode<'q, DB>,
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        String::from(*self).encode(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.digits().len()
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for GTIN
+where
+    &'r str: Decode<'r, DB>,
+{
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let value = <&str as Decode<DB>>::decode(value)?;
+        Ok(GTIN::try_from(value)?)
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
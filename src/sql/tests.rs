@@ -0,0 +1,53 @@
+use futures_executor::block_on;
+use sqlx::{Connection, Row, SqliteConnection};
+
+use crate::{GtinError, GTIN};
+
+#[test]
+fn sqlite_round_trip() {
+    block_on(async {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE products (gtin TEXT NOT NULL, padded CHAR(14))")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let gtin = GTIN::try_from("071720539774").unwrap();
+        sqlx::query("INSERT INTO products VALUES (?, '071720539774  ')")
+            .bind(gtin)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let row = sqlx::query("SELECT gtin, padded FROM products")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(row.get::<&str, _>("gtin"), "071720539774");
+        assert_eq!(row.get::<GTIN, _>("gtin"), gtin);
+        assert_eq!(row.get::<GTIN, _>("padded"), gtin);
+
+        let (queried,): (GTIN,) = sqlx::query_as("SELECT gtin FROM products WHERE gtin = ?")
+            .bind(gtin)
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(queried, gtin);
+
+        // An invalid value fails to decode with the parse error
+        let row = sqlx::query("SELECT '071720539775' AS gtin")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        let error = row.try_get::<GTIN, _>("gtin").unwrap_err();
+        match error {
+            sqlx::Error::ColumnDecode { source, .. } => {
+                assert_eq!(
+                    source.downcast_ref::<GtinError>(),
+                    Some(&GtinError::InvalidChecksum)
+                );
+            }
+            error => panic!("unexpected error: {}", error),
+        }
+    });
+}
@@ -0,0 +1,30 @@
+//! rusqlite `ToSql` and `FromSql` for `GTIN`. GTINs are bound as their digit string and
+//! read from text, or from integers, since SQLite stores digit strings as integers in
+//! columns with numeric affinity, dropping leading zeros the way `TryFrom<u64>` expects.
+//! Invalid stored values fail with `FromSqlError::Other` holding the `GtinError`.
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+use crate::GTIN;
+
+impl ToSql for GTIN {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(String::from(*self)))
+    }
+}
+
+impl FromSql for GTIN {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let gtin = match value {
+            ValueRef::Integer(value) => {
+                let value = u64::try_from(value).map_err(|_| FromSqlError::OutOfRange(value))?;
+                GTIN::try_from(value)
+            }
+            value => GTIN::try_from(value.as_str()?),
+        };
+        gtin.map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
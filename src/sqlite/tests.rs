@@ -0,0 +1,53 @@
+use rusqlite::{params, Connection, Error};
+
+use crate::{GtinError, GTIN};
+
+#[test]
+fn rusqlite_round_trip() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("CREATE TABLE products (gtin TEXT NOT NULL, numeric INTEGER)")
+        .unwrap();
+
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    conn.execute("INSERT INTO products VALUES (?1, ?1)", params![gtin])
+        .unwrap();
+
+    let (text, stored, numeric): (String, GTIN, GTIN) = conn
+        .query_row("SELECT gtin, gtin, numeric FROM products", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .unwrap();
+    assert_eq!(text, "071720539774");
+    assert_eq!(stored, gtin);
+    assert_eq!(numeric, gtin); // stored as 71720539774
+
+    let found: i64 = conn
+        .query_row(
+            "SELECT count(*) FROM products WHERE gtin = ?1",
+            [gtin],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(found, 1);
+}
+
+#[test]
+fn rusqlite_conversion_errors() {
+    let conn = Connection::open_in_memory().unwrap();
+    let get = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, GTIN>(0));
+
+    match get("SELECT '071720539775'") {
+        Err(Error::FromSqlConversionFailure(0, _, e)) => {
+            assert_eq!(e.downcast_ref(), Some(&GtinError::InvalidChecksum));
+        }
+        result => panic!("unexpected result: {:?}", result),
+    }
+    assert!(matches!(
+        get("SELECT -1"),
+        Err(Error::IntegralValueOutOfRange(0, -1))
+    ));
+    assert!(matches!(
+        get("SELECT 1.5"),
+        Err(Error::InvalidColumnType(..))
+    ));
+}
@@ -0,0 +1,94 @@
+use std::fmt::{Display, Formatter};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::util::{extract_digits, validate_check_digit, Digits};
+
+/// Serial Shipping Container Code, the GS1 key for logistics units
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sscc([u8; 18]); // SSCC always has 18 digits
+
+impl Sscc {
+    pub const AI: &'static str = "00";
+
+    pub fn digits(&self) -> &[u8] {
+        &self.0
+    }
+
+    /// The extension digit, assigned freely by the company to increase capacity
+    pub fn extension_digit(&self) -> u8 {
+        self.0[0]
+    }
+
+    pub fn check_digit(&self) -> u8 {
+        self.0[17]
+    }
+
+    /// The GS1 Company Prefix, given its length (which cannot be derived from the code itself)
+    pub fn company_prefix(&self, prefix_len: usize) -> Option<&[u8]> {
+        if !(4..=12).contains(&prefix_len) {
+            return None;
+        }
+        Some(&self.0[1..1 + prefix_len])
+    }
+
+    /// The serial reference following a company prefix of the given length
+    pub fn serial_reference(&self, prefix_len: usize) -> Option<&[u8]> {
+        self.company_prefix(prefix_len)?;
+        Some(&self.0[1 + prefix_len..17])
+    }
+}
+
+impl Display for Sscc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SSCC: {}", Digits(&self.0))
+    }
+}
+
+impl std::convert::TryFrom<&str> for Sscc {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut digits = extract_digits(value);
+
+        // Accept element strings that still carry the (00) application identifier
+        if digits.len() == 20 && digits[..2] == [0, 0] {
+            digits.drain(..2);
+        }
+
+        if digits.len() != 18 {
+            return Err("Unsupported SSCC length".to_string());
+        }
+        if !validate_check_digit(&digits) {
+            return Err("Invalid SSCC checksum".to_string());
+        }
+
+        Ok(Sscc(digits.try_into().map_err(|_| {
+            "digits vector does not have exactly 18 elements".to_string()
+        })?))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Sscc {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&Digits(&self.0))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Sscc {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        crate::serde::deserialize_str(deserializer, |s| Sscc::try_from(s))
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
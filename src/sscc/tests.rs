@@ -0,0 +1,44 @@
+use super::Sscc;
+
+#[test]
+fn parse_sscc() {
+    let cases = vec![
+        ("106141411234567897", true),
+        ("1 0614141 123456789 7", true),
+        ("(00) 1 0614141 123456789 7", true), // with application identifier
+        ("106141411234567898", false),        // invalid check digit
+        ("10614141123456789", false),         // too short
+    ];
+
+    for (input, validity) in cases {
+        assert_eq!(
+            Sscc::try_from(input).is_ok(),
+            validity,
+            "Failed to match SSCC: {}",
+            input
+        );
+    }
+}
+
+#[test]
+fn sscc_accessors() {
+    let sscc = Sscc::try_from("106141411234567897").unwrap();
+    assert_eq!(sscc.extension_digit(), 1);
+    assert_eq!(sscc.check_digit(), 7);
+    assert_eq!(sscc.company_prefix(7), Some(&[0, 6, 1, 4, 1, 4, 1][..]));
+    assert_eq!(
+        sscc.serial_reference(7),
+        Some(&[1, 2, 3, 4, 5, 6, 7, 8, 9][..])
+    );
+    assert_eq!(sscc.company_prefix(13), None);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn sscc_round_trip_serialization() {
+    let sscc = Sscc::try_from("(00) 006141410000000012").unwrap();
+    let serialized = serde_json::to_string(&sscc).unwrap();
+    assert_eq!(serialized, "\"006141410000000012\"");
+    let deserialized: Sscc = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(sscc, deserialized);
+}
@@ -0,0 +1,141 @@
+//! Validate GTINs line by line from any `BufRead`, e.g. a multi-gigabyte export, without
+//! reading it into memory.
+
+use std::fmt::{Display, Formatter};
+use std::io::{self, BufRead};
+
+use crate::{GtinError, GTIN};
+
+/// Why a line did not yield a GTIN, with its 1-based line number
+#[derive(Debug)]
+pub enum LineError {
+    /// Reading failed, which ends the stream
+    Io {
+        line: usize,
+        error: io::Error,
+    },
+    NotUtf8 {
+        line: usize,
+    },
+    Invalid {
+        line: usize,
+        input: String, // the line without its line ending
+        error: GtinError,
+    },
+}
+
+impl LineError {
+    pub fn line(&self) -> usize {
+        match self {
+            LineError::Io { line, .. }
+            | LineError::NotUtf8 { line }
+            | LineError::Invalid { line, .. } => *line,
+        }
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineError::Io { line, error } => write!(f, "Line {}: {}", line, error),
+            LineError::NotUtf8 { line } => write!(f, "Line {}: not valid UTF-8", line),
+            LineError::Invalid { line, input, error } => {
+                write!(f, "Line {}: {:?}: {}", line, input, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LineError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            LineError::Io { error, .. } => Some(error),
+            LineError::NotUtf8 { .. } => None,
+            LineError::Invalid { error, .. } => Some(error),
+        }
+    }
+}
+
+/// An iterator over the GTINs of a reader, one per line. Blank lines and lines starting
+/// with the comment character (`#` unless changed) are skipped.
+#[derive(Debug)]
+pub struct GtinStream<R> {
+    reader: R,
+    comment: Option<char>,
+    line: usize,
+    buffer: Vec<u8>,
+    done: bool,
+}
+
+impl<R: BufRead> GtinStream<R> {
+    pub fn new(reader: R) -> Self {
+        GtinStream {
+            reader,
+            comment: Some('#'),
+            line: 0,
+            buffer: Vec::new(),
+            done: false,
+        }
+    }
+
+    /// The character that starts comment lines, or None to treat every line as data
+    pub fn with_comment(mut self, comment: Option<char>) -> Self {
+        self.comment = comment;
+        self
+    }
+
+    /// The line number of the last line read
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for GtinStream<R> {
+    type Item = Result<GTIN, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buffer.clear();
+            let read = self.reader.read_until(b'\n', &mut self.buffer);
+            self.line += 1;
+            match read {
+                Ok(0) => {
+                    self.line -= 1;
+                    self.done = true;
+                }
+                Ok(_) => {
+                    let Ok(text) = std::str::from_utf8(&self.buffer) else {
+                        return Some(Err(LineError::NotUtf8 { line: self.line }));
+                    };
+                    let input = text.trim_end_matches(['\n', '\r']);
+                    let trimmed = input.trim_start();
+                    let is_comment = self.comment.is_some_and(|c| trimmed.starts_with(c));
+                    if trimmed.is_empty() || is_comment {
+                        continue;
+                    }
+                    return Some(GTIN::try_from(input).map_err(|error| LineError::Invalid {
+                        line: self.line,
+                        input: input.to_string(),
+                        error,
+                    }));
+                }
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => self.line -= 1,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(LineError::Io {
+                        line: self.line,
+                        error,
+                    }));
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
@@ -0,0 +1,68 @@
+use std::io::{self, BufRead, BufReader, Read};
+
+use super::{GtinStream, LineError};
+use crate::{GtinError, GTIN};
+
+#[test]
+fn stream_lines() {
+    let text = "# export of 2024-05-01\n071720539774\r\n\n   \n  # indented comment\n071720539775\n4006381333931";
+    let mut stream = GtinStream::new(text.as_bytes());
+
+    assert_eq!(
+        stream.next().unwrap().unwrap(),
+        GTIN::try_from("071720539774").unwrap()
+    );
+    assert_eq!(stream.line(), 2);
+    match stream.next().unwrap() {
+        Err(LineError::Invalid { line, input, error }) => {
+            assert_eq!(line, 6);
+            assert_eq!(input, "071720539775");
+            assert_eq!(error, GtinError::InvalidChecksum);
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert_eq!(
+        stream.next().unwrap().unwrap(),
+        GTIN::try_from("4006381333931").unwrap()
+    );
+    assert!(stream.next().is_none());
+    assert_eq!(stream.line(), 7);
+    assert!(stream.next().is_none());
+
+    // Without comments, the comment lines are invalid
+    let errors: Vec<usize> = GtinStream::new(text.as_bytes())
+        .with_comment(None)
+        .filter_map(|result| result.err().map(|e| e.line()))
+        .collect();
+    assert_eq!(errors, vec![1, 5, 6]);
+}
+
+#[test]
+fn stream_non_utf8_and_io_errors() {
+    let bytes: &[u8] = b"071720539774\n\xff\xfe\n4006381333931\n";
+    let results: Vec<_> = GtinStream::new(bytes).collect();
+    assert_eq!(results.len(), 3);
+    assert!(matches!(results[1], Err(LineError::NotUtf8 { line: 2 })));
+    assert!(results[2].is_ok());
+
+    /// Fails after the first line
+    struct Failing(bool);
+    impl Read for Failing {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            if self.0 {
+                return Err(io::Error::other("disk on fire"));
+            }
+            self.0 = true;
+            let line = b"071720539774\n";
+            buf[..line.len()].copy_from_slice(line);
+            Ok(line.len())
+        }
+    }
+    let reader: Box<dyn BufRead> = Box::new(BufReader::new(Failing(false)));
+    let results: Vec<_> = GtinStream::new(reader).collect();
+    assert_eq!(results.len(), 2);
+    assert!(results[0].is_ok());
+    let error = results[1].as_ref().unwrap_err();
+    assert!(matches!(error, LineError::Io { line: 2, .. }));
+    assert_eq!(error.to_string(), "Line 2: disk on fire");
+}
@@ -0,0 +1,20 @@
+//! Data-quality counters for parser outcomes. With the `metrics` feature they are
+//! reported through the `metrics` crate facade to whatever recorder the host installs;
+//! without it they compile to nothing.
+
+/// Input parsed into a valid GTIN
+pub const PARSED_OK: &str = "gtin_parsed_ok";
+/// Input had a supported length but the check digit did not match
+pub const CHECKSUM_FAILED: &str = "gtin_checksum_failed";
+/// Input was accepted only after restoring digits a heuristic judged to be missing,
+/// such as the leading zero of an 11-digit UPC-A
+pub const HEURISTIC_PADDED: &str = "gtin_heuristic_padded";
+/// Input was accepted only after correcting characters that were not digits
+pub const REPAIRED: &str = "gtin_repaired";
+
+pub(crate) fn increment(name: &'static str) {
+    #[cfg(feature = "metrics")]
+    metrics::counter!(name).increment(1);
+    #[cfg(not(feature = "metrics"))]
+    let _ = name;
+}
@@ -49,6 +49,7 @@ fn determine_country_code() {
 
 // serde tests
 
+#[cfg(feature = "serde")]
 #[test]
 fn serialize_upca() {
     let gtin = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
@@ -56,26 +57,23 @@ fn serialize_upca() {
     assert_eq!(serialized, "\"071720539774\"");
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn deserialize_upca_with_spaces() {
     let data = "\"0 71720 53977 4\"";
     let deserialized: GTIN = serde_json::from_str(data).unwrap();
-    match deserialized {
-        GTIN::UpcA(digits) => assert_eq!(digits, [0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
-        _ => panic!("Deserialized to incorrect type"),
-    }
+    assert_eq!(deserialized, GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]));
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn deserialize_upca_with_spaces_and_missing_initial_zero() {
     let data = "\"71720 53977 4\"";
     let deserialized: GTIN = serde_json::from_str(data).unwrap();
-    match deserialized {
-        GTIN::UpcA(digits) => assert_eq!(digits, [0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
-        _ => panic!("Deserialized to incorrect type"),
-    }
+    assert_eq!(deserialized, GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]));
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn round_trip_serialization() {
     let gtin = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
@@ -84,6 +82,7 @@ fn round_trip_serialization() {
     assert_eq!(gtin, deserialized);
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn json_serialize_product() {
     use serde::{Deserialize, Serialize};
@@ -101,6 +100,7 @@ fn json_serialize_product() {
     assert_eq!(serialized, r#"{"name":"Oreo","gtin":"071720539774"}"#);
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn json_deserialize_product() {
     use serde::{Deserialize, Serialize};
@@ -119,6 +119,7 @@ fn json_deserialize_product() {
     assert_eq!(deserialized, expected);
 }
 
+#[cfg(feature = "serde")]
 #[test]
 fn deserialize_invalid_gtin() {
     use serde::{Deserialize, Serialize};
@@ -135,3 +136,765 @@ fn deserialize_invalid_gtin() {
         "Expected deserialization to fail with an invalid GTIN"
     );
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn reject_json_numbers() {
+    for json in ["71720539774", "4006381333931", "96385074"] {
+        assert!(serde_json::from_str::<GTIN>(json).is_err(), "{}", json);
+    }
+}
+
+#[test]
+fn construct_from_digits() {
+    use crate::{GtinError, GtinKind};
+
+    let gtin = GTIN::from_digits(GtinKind::UpcA, [0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]).unwrap();
+    assert_eq!(gtin, GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]));
+
+    // Digits can come from any iterator, e.g. unpacked BCD nibbles
+    let bcd: [u8; 7] = [0x85, 0x95, 0x70, 0x15, 0x30, 0x52, 0x6F];
+    let nibbles = bcd
+        .iter()
+        .flat_map(|byte| [byte >> 4, byte & 0x0F])
+        .take(13);
+    assert_eq!(
+        GTIN::from_digits(GtinKind::Ean13, nibbles),
+        Ok(GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]))
+    );
+
+    let cases = vec![
+        (
+            GtinKind::Ean8,
+            vec![5, 2, 0, 1, 3, 4, 8],
+            GtinError::InvalidLength {
+                kind: GtinKind::Ean8,
+                found: 7,
+            },
+        ),
+        (
+            GtinKind::Ean8,
+            vec![5, 2, 0, 1, 3, 4, 8, 5, 0],
+            GtinError::InvalidLength {
+                kind: GtinKind::Ean8,
+                found: 9,
+            },
+        ),
+        (
+            GtinKind::Ean8,
+            vec![5, 2, 0, 1, 3, 4, 8, 5, 0, 0, 0],
+            GtinError::InvalidLength {
+                kind: GtinKind::Ean8,
+                found: 9,
+            },
+        ),
+        (
+            GtinKind::Ean8,
+            vec![5, 2, 0, 1, 3, 4, 8, 10],
+            GtinError::InvalidDigit(10),
+        ),
+        (
+            GtinKind::Ean8,
+            vec![5, 2, 0, 1, 3, 4, 8, 6],
+            GtinError::InvalidChecksum,
+        ),
+    ];
+
+    for (kind, digits, error) in cases {
+        assert_eq!(GTIN::from_digits(kind, digits.clone()), Err(error), "{:?}", digits);
+    }
+
+    // Reading stops one digit past the kind's count
+    assert_eq!(
+        GTIN::from_digits(GtinKind::UpcA, core::iter::repeat(0)),
+        Err(GtinError::InvalidLength {
+            kind: GtinKind::UpcA,
+            found: 13,
+        })
+    );
+}
+
+#[test]
+fn parse_symbology_identifiers() {
+    use crate::GtinKind;
+
+    let cases = vec![
+        ("]E0 0 71720 53977 4", GtinKind::UpcA),
+        ("]E4 5201 3485", GtinKind::Ean8), // UPC-E without the identifier
+        ("]E0 0418 2635", GtinKind::UpcE), // fails the flat mod-10 check EAN-8 uses
+        ("0 418263 5", GtinKind::UpcE),     // the same without the identifier
+        ("]C1 01 0 0071720 53977 4", GtinKind::Gtin14),
+        ("]e0 0100071720539774", GtinKind::Gtin14),
+        ("]I1 0 0071720 53977 4", GtinKind::Gtin14),
+    ];
+
+    for (input, kind) in cases {
+        assert_eq!(GTIN::try_from(input).map(|g| g.kind()), Ok(kind), "{}", input);
+    }
+
+    // Only GS1 carriers have an AI to strip
+    assert!(GTIN::try_from("]E0 0100071720539774").is_err());
+    // An EAN-8 identifier rules out the UPC-E reading
+    assert!(GTIN::try_from("]E4 0418 2635").is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn report_capabilities() {
+    use crate::encode::Symbology;
+
+    let capabilities = crate::capabilities();
+    assert_eq!(capabilities.crate_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(capabilities.prefix_data_version, crate::PREFIX_DATA_VERSION);
+    assert!(capabilities.encoders.contains(&Symbology::Ean13));
+    assert_eq!(capabilities.svg, cfg!(feature = "svg"));
+    assert!(capabilities.serde);
+
+    let json = serde_json::to_value(capabilities).unwrap();
+    assert_eq!(json["encoders"][0], "Ean13");
+}
+
+#[cfg(not(feature = "serde"))]
+#[test]
+fn report_capabilities_without_serde() {
+    assert!(!crate::capabilities().serde);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn export_schema() {
+    use crate::GtinKind;
+
+    let schema = crate::schema();
+    assert_eq!(schema.prefix_data_version, crate::PREFIX_DATA_VERSION);
+    assert_eq!(schema.kinds.len(), 5);
+    assert_eq!(schema.kinds[4].digit_count, 14);
+    assert!(schema
+        .errors
+        .iter()
+        .any(|e| e.error == "GtinError" && e.code == "InvalidLength"));
+
+    // Names are the ones serde writes, so generated enums can read serialized values
+    let kinds: Vec<GtinKind> = schema
+        .kinds
+        .iter()
+        .map(|kind| serde_json::from_value(kind.name.into()).unwrap())
+        .collect();
+    assert_eq!(kinds[0], GtinKind::UpcE);
+    let number_systems: Vec<NumberSystem> = schema
+        .number_systems
+        .iter()
+        .map(|&name| serde_json::from_value(name.into()).unwrap())
+        .collect();
+    assert_eq!(number_systems[1], NumberSystem::StoreUse);
+
+    let json: serde_json::Value = serde_json::from_str(&schema.to_json()).unwrap();
+    assert_eq!(json["errors"][1]["fields"][0], "length");
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn count_parse_outcomes() {
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use metrics::{Counter, Gauge, Histogram, Key, KeyName, Metadata, Recorder, SharedString, Unit};
+
+    use crate::telemetry;
+
+    #[derive(Default)]
+    struct Counters(Mutex<HashMap<String, Arc<AtomicU64>>>);
+
+    impl Counters {
+        fn get(&self, name: &str) -> u64 {
+            let counters = self.0.lock().unwrap();
+            counters.get(name).map_or(0, |c| c.load(Ordering::Relaxed))
+        }
+    }
+
+    impl Recorder for Counters {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            let mut counters = self.0.lock().unwrap();
+            let counter = counters.entry(key.name().to_string()).or_default();
+            Counter::from_arc(counter.clone())
+        }
+
+        fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::noop()
+        }
+
+        fn register_histogram(&self, _: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    let recorder = Counters::default();
+    metrics::with_local_recorder(&recorder, || {
+        for input in ["071720539774", "71720539774", "071720539775", "12345"] {
+            let _ = GTIN::try_from(input);
+        }
+    });
+
+    assert_eq!(recorder.get(telemetry::PARSED_OK), 2);
+    assert_eq!(recorder.get(telemetry::HEURISTIC_PADDED), 1);
+    assert_eq!(recorder.get(telemetry::CHECKSUM_FAILED), 1);
+    assert_eq!(recorder.get(telemetry::REPAIRED), 0);
+}
+
+#[test]
+fn convert_to_ean13() {
+    use crate::{ConversionError, GtinKind};
+
+    let ean13 = GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let cases = vec![
+        ("071720539774", Ok(ean13)),
+        ("00071720539774", Ok(ean13)),
+        ("1 00 71720 53977 1", Err(ConversionError::NonZeroIndicator(1))),
+        (
+            "00123457",
+            Err(ConversionError::NotRepresentable {
+                from: GtinKind::Ean8,
+                to: GtinKind::Ean13,
+            }),
+        ),
+    ];
+
+    for (input, expected) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        assert_eq!(gtin.as_ean13(), expected, "{}", input);
+    }
+
+    let upce = GTIN::UpcE([0, 1, 2, 3, 4, 5, 0, 5]);
+    let expanded = GTIN::Ean13([0, 0, 1, 2, 0, 0, 0, 0, 0, 3, 4, 5, 5]);
+    assert_eq!(upce.as_ean13(), Ok(expanded));
+}
+
+#[test]
+fn convert_to() {
+    use crate::{ConversionError, GtinKind};
+
+    let cases = vec![
+        ("071720539774", GtinKind::Gtin14, Ok("00071720539774")),
+        ("00071720539774", GtinKind::UpcA, Ok("071720539774")),
+        ("]E010000052", GtinKind::UpcA, Ok("100000000052")),
+        ("00100000000052", GtinKind::UpcE, Ok("10000502")),
+        ("96385074", GtinKind::Gtin14, Ok("00000096385074")),
+        ("00000096385074", GtinKind::Ean8, Ok("96385074")),
+        ("96385074", GtinKind::Ean8, Ok("96385074")),
+        ("10071720539771", GtinKind::UpcA, Err(ConversionError::NonZeroIndicator(1))),
+    ];
+    for (input, kind, expected) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        let converted = gtin.convert_to(kind);
+        assert_eq!(converted.map(String::from), expected.map(String::from), "{}", input);
+        assert!(converted.map_or(true, |converted| converted.kind() == kind));
+    }
+
+    let ean8 = GTIN::try_from("96385074").unwrap();
+    for kind in [GtinKind::UpcA, GtinKind::UpcE, GtinKind::Ean13] {
+        assert_eq!(
+            ean8.convert_to(kind),
+            Err(ConversionError::NotRepresentable {
+                from: GtinKind::Ean8,
+                to: kind
+            })
+        );
+    }
+    let upca = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(
+        upca.convert_to(GtinKind::UpcE),
+        Err(ConversionError::NotRepresentable {
+            from: GtinKind::UpcA,
+            to: GtinKind::UpcE
+        })
+    );
+}
+
+#[test]
+fn key_ranges() {
+    use crate::GtinKind;
+
+    let cases = vec![
+        ("]E499999995", GtinKind::Ean8),
+        ("999999999993", GtinKind::UpcA),
+        ("9999999999994", GtinKind::Ean13),
+        ("99999999999997", GtinKind::Gtin14),
+    ];
+    for (input, kind) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        assert_eq!(gtin.kind(), kind);
+        assert_eq!(gtin.canonical_key(), kind.max(), "{}", input);
+        assert_eq!(kind.min(), 0);
+    }
+    // Expands to 1 99999 00009 9
+    let upce = GTIN::UpcE([1, 9, 9, 9, 9, 9, 9, 9]);
+    assert_eq!(upce.canonical_key(), GtinKind::UpcE.max());
+
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert!(GtinKind::Ean13.keys().contains(&upca.canonical_key()));
+}
+
+#[test]
+fn from_canonical_key() {
+    use crate::{GtinError, GtinKind};
+
+    let cases = vec![
+        "071720539774",
+        "0071720539774",
+        "00071720539774",
+        "10071720539771",
+        "96385074",
+        "]E010000502",
+        "]E004182635",
+    ];
+    for input in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        let key = gtin.canonical_key();
+        assert_eq!(GTIN::from_canonical_key(key, gtin.kind()), Ok(gtin), "{}", input);
+    }
+    // 0 120453 4 and 0 120450 4 both expand to 0 12000 00045 4
+    let upce = GTIN::UpcE([0, 1, 2, 0, 4, 5, 3, 4]);
+    let key = upce.canonical_key();
+    let compressed = GTIN::from_canonical_key(key, GtinKind::UpcE).unwrap();
+    assert_eq!(compressed, GTIN::UpcE([0, 1, 2, 0, 4, 5, 0, 4]));
+    assert_eq!(compressed.canonical_key(), key);
+
+    // 96385074 is an EAN-8, which has no 12- or 13-digit form
+    let ean8 = GTIN::try_from("96385074").unwrap().canonical_key();
+    for kind in [GtinKind::UpcE, GtinKind::UpcA, GtinKind::Ean13] {
+        assert_eq!(
+            GTIN::from_canonical_key(ean8, kind),
+            Err(GtinError::InvalidPadding),
+            "{:?}",
+            kind
+        );
+    }
+    assert_eq!(
+        GTIN::from_canonical_key(ean8, GtinKind::Gtin14),
+        Ok(GTIN::Gtin14([0, 0, 0, 0, 0, 0, 9, 6, 3, 8, 5, 0, 7, 4]))
+    );
+
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let key = upca.canonical_key();
+    assert_eq!(
+        GTIN::from_canonical_key(key, GtinKind::Ean13),
+        Ok(upca.as_ean13().unwrap())
+    );
+    assert_eq!(
+        GTIN::from_canonical_key(key, GtinKind::Ean8),
+        Err(GtinError::InvalidPadding)
+    );
+    // 071720539774 has no UPC-E form
+    assert_eq!(
+        GTIN::from_canonical_key(key, GtinKind::UpcE),
+        Err(GtinError::InvalidPadding)
+    );
+    assert_eq!(
+        GTIN::from_canonical_key(key + 1, GtinKind::UpcA),
+        Err(GtinError::InvalidChecksum)
+    );
+    assert_eq!(
+        GTIN::from_canonical_key(u64::MAX, GtinKind::Gtin14),
+        Err(GtinError::InvalidPadding)
+    );
+}
+
+#[test]
+fn numeric_round_trip() {
+    use crate::GtinError;
+
+    let cases = vec![
+        (71720539774, "071720539774"),
+        (4006381333931, "4006381333931"),
+        (10071720539771, "10071720539771"),
+        (96385074, "96385074"),
+        (55, "]E400000055"),
+        (10000052, "]E010000052"),
+    ];
+    for (value, input) in cases {
+        let gtin = GTIN::try_from(input).unwrap();
+        assert_eq!(GTIN::try_from(value), Ok(gtin), "{}", value);
+        assert_eq!(gtin.to_u64(), value);
+    }
+
+    // An EAN-13 with a leading zero comes back as the UPC-A it pads
+    let ean13 = GTIN::try_from("0071720539774").unwrap();
+    assert_eq!(
+        GTIN::try_from(ean13.to_u64()),
+        GTIN::try_from("071720539774")
+    );
+    assert_eq!(GTIN::try_from(71720539775), Err(GtinError::InvalidChecksum));
+    assert_eq!(
+        GTIN::try_from(123456789012345),
+        Err(GtinError::UnsupportedLength(15))
+    );
+}
+
+#[test]
+fn parse_ascii_bytes() {
+    let cases = vec![
+        "071720539774",
+        "0 71720 53977 4",
+        "0717-2053-9774",
+        "  071720539774\n",
+        "71720539774",
+        "071720539775",
+        "]E004182635",
+        "]E410000052",
+        "]C10100071720539774",
+        "",
+        "12345",
+    ];
+    for input in cases {
+        assert_eq!(
+            GTIN::try_from(input.as_bytes()),
+            GTIN::try_from(input),
+            "{:?}",
+            input
+        );
+    }
+
+    // Bytes that are not UTF-8 are skipped like any other non-digit
+    let bytes: &[u8] = b"\xff0717205\xfe39774";
+    assert_eq!(GTIN::try_from(bytes), GTIN::try_from("071720539774"));
+}
+
+#[test]
+fn string_and_byte_conversions() {
+    let gtin = GTIN::try_from("0 71720 53977 4").unwrap();
+    assert_eq!(String::from(gtin), "071720539774");
+    let owned: String = GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]).into();
+    assert_eq!(owned, "04182635");
+
+    fn digit_sum(value: impl AsRef<[u8]>) -> u32 {
+        value.as_ref().iter().map(|&d| u32::from(d)).sum()
+    }
+    assert_eq!(digit_sum(gtin), 52);
+    assert_eq!(gtin.as_bytes(), &[0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+}
+
+#[test]
+fn gtin_as_hash_map_key() {
+    use std::collections::HashMap;
+
+    let upca = GTIN::try_from("071720539774").unwrap();
+    let mut catalog = HashMap::new();
+    catalog.insert(upca, "widget");
+    catalog.insert(GTIN::try_from("4006381333931").unwrap(), "pen");
+
+    assert_eq!(catalog.get(&GTIN::try_from("0 71720 53977 4").unwrap()), Some(&"widget"));
+    assert_eq!(catalog.get(&upca.as_ean13().unwrap()), None);
+}
+
+#[test]
+fn canonical_ordering() {
+    let upca = GTIN::try_from("071720539774").unwrap();
+    let ean13 = upca.as_ean13().unwrap();
+    let gtin14 = GTIN::try_from("00071720539774").unwrap();
+    let ean8 = GTIN::try_from("96385074").unwrap();
+    let upce = GTIN::UpcE([0, 0, 0, 0, 0, 0, 5, 5]);
+    let upce_alternative = GTIN::UpcE([0, 0, 0, 0, 0, 5, 0, 5]);
+    let pen = GTIN::try_from("4006381333931").unwrap();
+
+    let mut gtins = vec![pen, gtin14, upce_alternative, ean13, ean8, upca, upce];
+    gtins.sort();
+    assert_eq!(
+        gtins,
+        vec![upce, upce_alternative, ean8, upca, ean13, gtin14, pen]
+    );
+    assert_eq!(gtins.binary_search(&ean13), Ok(4));
+    assert!(upce < upce_alternative && upce != upce_alternative);
+}
+
+#[test]
+fn same_product_across_forms() {
+    let upca = GTIN::try_from("071720539774").unwrap();
+    let ean13 = GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let gtin14 = GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert_ne!(upca, ean13);
+    assert!(upca.same_product(&ean13));
+    assert!(gtin14.same_product(&upca));
+
+    // A case of the product is a different trade item
+    let case = GTIN::try_from("10071720539771").unwrap();
+    assert!(!case.same_product(&upca));
+
+    let upce = GTIN::UpcE([0, 0, 0, 0, 0, 0, 5, 5]);
+    let upca = GTIN::try_from("000000000055").unwrap();
+    assert!(upce.same_product(&upca));
+    assert!(upce.same_product(&GTIN::UpcE([0, 0, 0, 0, 0, 5, 0, 5])));
+    assert!(!upce.same_product(&GTIN::try_from("96385074").unwrap()));
+}
+
+#[test]
+fn compare_with_digit_strings() {
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(gtin, "071720539774");
+    assert!(gtin == "0 71720 53977 4");
+    assert!("0717-2053-9774" == gtin);
+    assert!(gtin == *"071720539774");
+
+    assert!(gtin != "71720539774");
+    assert!(gtin != "0071720539774");
+    assert!(gtin != "07172053977");
+    assert!(gtin != "071720539774x");
+    assert!(gtin != "]E0071720539774");
+    assert!(gtin != "");
+}
+
+#[test]
+fn const_gtins() {
+    use crate::GtinError;
+
+    const OREO: GTIN = crate::gtin!("0 71720 53977 4");
+    assert_eq!(OREO, GTIN::try_from("071720539774").unwrap());
+    assert_eq!(crate::gtin!("10000052"), GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]));
+    assert_eq!(crate::gtin!("0000-0055"), GTIN::Ean8([0, 0, 0, 0, 0, 0, 5, 5]));
+    assert_eq!(crate::gtin!("10071720539771").kind(), crate::GtinKind::Gtin14);
+
+    let cases = vec![
+        "071720539774",
+        "4006381333931",
+        "96385074",
+        "10000052",
+        "00000055",
+        "10071720539771",
+        "071720539775",
+        "96385075",
+        "04182635",
+        "04182636",
+    ];
+    for input in cases {
+        assert_eq!(GTIN::parse_const(input), GTIN::try_from(input), "{}", input);
+    }
+    assert_eq!(
+        GTIN::parse_const("71720539774"),
+        Err(GtinError::UnsupportedLength(11))
+    );
+    assert_eq!(
+        GTIN::parse_const("]E0071720539774"),
+        Err(GtinError::InvalidDigit(b']'))
+    );
+    assert_eq!(
+        GTIN::parse_const("123456789012345"),
+        Err(GtinError::UnsupportedLength(15))
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn compact_binary_serialization() {
+    use serde_test::{assert_tokens, Compact, Configure, Token};
+
+    // Kind 1 (UPC-A) in the nibble above the 48-bit value
+    let upca = GTIN::try_from("071720539774").unwrap();
+    assert_tokens(&upca.compact(), &[Token::U64(1 << 48 | 71720539774)]);
+    assert_tokens(&upca.readable(), &[Token::Str("071720539774")]);
+
+    // The kind survives, unlike with the digit string
+    let upce = GTIN::UpcE([0, 0, 0, 0, 0, 0, 5, 5]);
+    assert_tokens(&upce.compact(), &[Token::U64(55)]);
+
+    serde_test::assert_de_tokens_error::<Compact<GTIN>>(
+        &[Token::U64(1 << 48 | 71720539775)],
+        "Invalid packed GTIN",
+    );
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_archive() {
+    let catalog = vec![
+        GTIN::try_from("071720539774").unwrap(),
+        GTIN::try_from("10000052").unwrap(),
+        GTIN::try_from("4006381333931").unwrap(),
+        GTIN::try_from("10071720539771").unwrap(),
+    ];
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&catalog).unwrap();
+
+    // Query the archive in place
+    let archived =
+        rkyv::access::<rkyv::Archived<Vec<GTIN>>, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), catalog.len());
+    for (archived, gtin) in archived.iter().zip(&catalog) {
+        assert_eq!(archived, gtin);
+        assert_eq!(GTIN::from(archived), *gtin);
+    }
+
+    let deserialized: Vec<GTIN> = rkyv::from_bytes::<_, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(deserialized, catalog);
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn rkyv_rejects_corrupted_archives() {
+    use rkyv::rancor::Error;
+
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    let bytes = rkyv::to_bytes::<Error>(&gtin).unwrap();
+    let start = bytes.windows(12).position(|w| w == gtin.digits()).unwrap();
+    assert_eq!(rkyv::from_bytes::<GTIN, Error>(&bytes).unwrap(), gtin);
+
+    // a digit out of range, a wrong check digit, and a digit past the kind's count
+    for (offset, value) in [(0, 200), (11, 5), (12, 1)] {
+        let mut corrupted = bytes.clone();
+        corrupted[start + offset] = value;
+        assert!(rkyv::from_bytes::<GTIN, Error>(&corrupted).is_err(), "{}", offset);
+        assert!(rkyv::access::<rkyv::Archived<GTIN>, Error>(&corrupted).is_err());
+    }
+}
+
+#[test]
+fn successor_and_predecessor() {
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(gtin.successor(6), GTIN::try_from("071720539781").ok());
+    assert_eq!(gtin.predecessor(6), GTIN::try_from("071720539767").ok());
+    assert_eq!(gtin.successor(6).unwrap().predecessor(6), Some(gtin));
+
+    // Carries and borrows ripple through the item reference only
+    let gtin = GTIN::try_from("4006381999991").unwrap();
+    assert_eq!(gtin.successor(7), None);
+    assert_eq!(gtin.successor(6), GTIN::try_from("4006382000009").ok());
+    let gtin = GTIN::try_from("4006381000000").unwrap();
+    assert_eq!(gtin.predecessor(7), None);
+    assert_eq!(gtin.predecessor(6), GTIN::try_from("4006380999992").ok());
+
+    // The indicator of a GTIN-14 is part of the fixed digits
+    let gtin = GTIN::try_from("10071720539771").unwrap();
+    assert_eq!(gtin.successor(7).unwrap().digits()[..7], gtin.digits()[..7]);
+
+    // No item reference left to count in
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    assert_eq!(gtin.successor(11), None);
+    assert_eq!(gtin.predecessor(12), None);
+    assert_eq!(GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]).successor(1), None);
+}
+
+#[test]
+fn format_styles() {
+    use crate::FormatStyle;
+
+    let upca = GTIN::try_from("071720539774").unwrap();
+    let cases = [
+        (FormatStyle::Plain, "071720539774"),
+        (FormatStyle::Labeled, "UPC-A: 071720539774"),
+        (FormatStyle::Spaced, "0 71720 53977 4"),
+        (FormatStyle::Hyphenated, "0-71720-53977-4"),
+    ];
+    for (style, expected) in cases {
+        assert_eq!(upca.format(style).to_string(), expected);
+    }
+    assert_eq!(upca.to_string(), upca.format(FormatStyle::Labeled).to_string());
+
+    let gtin14 = GTIN::try_from("10071720539771").unwrap();
+    assert_eq!(
+        gtin14.format(FormatStyle::Hyphenated).to_string(),
+        "1-00-71720-53977-1"
+    );
+    let upce = GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2]);
+    assert_eq!(upce.format(FormatStyle::Spaced).to_string(), "1 000005 2");
+}
+
+#[test]
+fn alternate_display() {
+    let gtin = GTIN::try_from("4006381333931").unwrap();
+    assert_eq!(format!("{}", gtin), "EAN-13: 4006381333931");
+    assert_eq!(format!("{:#}", gtin), "4006381333931");
+    assert_eq!(format!("{:#}", GTIN::UpcE([1, 0, 0, 0, 0, 0, 5, 2])), "10000052");
+}
+
+#[test]
+fn write_digits_without_allocating() {
+    let gtin = GTIN::try_from("071720539774").unwrap();
+    let mut out = String::new();
+    gtin.write_digits(&mut out).unwrap();
+    assert_eq!(out, "071720539774");
+
+    #[cfg(feature = "arrayvec")]
+    {
+        assert_eq!(gtin.to_array_string().as_str(), "071720539774");
+        let gtin14 = GTIN::try_from("10071720539771").unwrap();
+        assert_eq!(gtin14.to_array_string().as_str(), "10071720539771");
+    }
+}
+
+#[test]
+fn constructors_in_const_context() {
+    const UPCA: GTIN = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    const UPCE: GTIN = GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]);
+    assert_eq!(UPCA, GTIN::try_from("071720539774").unwrap());
+    assert_eq!(UPCE, GTIN::try_from("]E0 0418 2635").unwrap());
+}
+
+#[test]
+#[should_panic(expected = "Invalid GTIN checksum")]
+fn constructor_with_wrong_check_digit() {
+    GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 5]);
+}
+
+#[test]
+#[should_panic(expected = "Invalid GTIN checksum")]
+fn upce_constructor_with_flat_check_digit() {
+    // 04182634 passes the flat mod-10 check, not that of its UPC-A expansion
+    GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "GTIN digits must be 0 to 9")]
+fn constructor_with_digit_above_nine() {
+    GTIN::Ean8([9, 6, 3, 8, 5, 0, 7, 14]);
+}
+
+#[test]
+fn fixed_size_representation() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert_eq!(
+        format!("{:?}", upca),
+        "UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])"
+    );
+    assert_eq!(core::mem::size_of::<GTIN>(), 16);
+    assert_eq!(core::mem::size_of::<Option<GTIN>>(), 16);
+
+    // Conversions move the digits within the buffer without leaving stale ones behind
+    let ean13 = upca.as_ean13().unwrap();
+    assert_eq!(ean13, GTIN::try_from("0071720539774").unwrap());
+    assert_eq!(ean13.prefer_upc_a(), upca);
+    let gtin14 = GTIN::try_from("00071720539774").unwrap();
+    assert_eq!(gtin14.as_ean13(), Ok(ean13));
+    assert_eq!(gtin14.prefer_upc_a(), upca);
+}
+
+#[test]
+fn digits_by_kind() {
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert_eq!(
+        upca.as_upca_digits(),
+        Some([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])
+    );
+    assert_eq!(upca.as_ean13_digits(), None);
+    assert!(matches!(upca.as_upca_digits(), Some([0, 7, 1, 7, 2, ..])));
+
+    // Exactly one accessor gives the digits
+    let cases = vec![
+        GTIN::UpcE([0, 4, 1, 8, 2, 6, 3, 5]),
+        GTIN::Ean8([0, 5, 2, 0, 1, 3, 4, 1]),
+        GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]),
+        GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]),
+    ];
+    for gtin in cases {
+        let digits = [
+            gtin.as_upce_digits().map(|d| d.to_vec()),
+            gtin.as_upca_digits().map(|d| d.to_vec()),
+            gtin.as_ean8_digits().map(|d| d.to_vec()),
+            gtin.as_ean13_digits().map(|d| d.to_vec()),
+            gtin.as_gtin14_digits().map(|d| d.to_vec()),
+        ];
+        let found: Vec<&Vec<u8>> = digits.iter().flatten().collect();
+        assert_eq!(found, [&gtin.digits().to_vec()], "{:?}", gtin);
+    }
+}
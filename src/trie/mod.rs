@@ -0,0 +1,241 @@
+//! Digit tries over GTINs, for prefix queries over large catalogs. Paths are the 13 digits
+//! after the indicator of the canonical GTIN-14 (the EAN-13 form, with UPC-A zero-padded),
+//! where GS1 Company Prefixes sit, so a lookup takes one step per prefix digit.
+
+use crate::GTIN;
+
+const NONE: u32 = u32::MAX; // no child or no entry
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Node {
+    children: [u32; 10],
+    entry: u32,
+}
+
+impl Node {
+    const EMPTY: Node = Node {
+        children: [NONE; 10],
+        entry: NONE,
+    };
+}
+
+/// A trie over decimal digits, with its nodes and entries in arenas
+#[derive(Debug, PartialEq, Eq, Clone)]
+struct Trie<T> {
+    nodes: Vec<Node>,
+    entries: Vec<T>,
+}
+
+impl<T> Trie<T> {
+    fn new() -> Self {
+        Trie {
+            nodes: vec![Node::EMPTY],
+            entries: vec![],
+        }
+    }
+
+    fn child(&self, node: usize, digit: u8) -> Option<usize> {
+        match self.nodes[node].children[usize::from(digit)] {
+            NONE => None,
+            child => Some(child as usize),
+        }
+    }
+
+    fn entry(&self, node: usize) -> Option<&T> {
+        match self.nodes[node].entry {
+            NONE => None,
+            entry => Some(&self.entries[entry as usize]),
+        }
+    }
+
+    /// The node at the end of `path`
+    fn find(&self, path: &[u8]) -> Option<usize> {
+        path.iter()
+            .try_fold(0, |node, &digit| self.child(node, digit))
+    }
+
+    /// Set the entry at the end of `path`, returning the one it replaced
+    fn insert(&mut self, path: &[u8], entry: T) -> Option<T> {
+        let mut node = 0;
+        for &digit in path {
+            node = match self.child(node, digit) {
+                Some(child) => child,
+                None => {
+                    let child = self.nodes.len();
+                    self.nodes.push(Node::EMPTY);
+                    self.nodes[node].children[usize::from(digit)] = child as u32;
+                    child
+                }
+            };
+        }
+        match self.nodes[node].entry {
+            NONE => {
+                self.nodes[node].entry = self.entries.len() as u32;
+                self.entries.push(entry);
+                None
+            }
+            index => Some(std::mem::replace(&mut self.entries[index as usize], entry)),
+        }
+    }
+
+    /// The deepest entry along `path`, with its depth
+    fn longest(&self, path: &[u8]) -> Option<(usize, &T)> {
+        let mut longest = self.entry(0).map(|entry| (0, entry));
+        let mut node = 0;
+        for (depth, &digit) in path.iter().enumerate() {
+            match self.child(node, digit) {
+                Some(child) => node = child,
+                None => break,
+            }
+            if let Some(entry) = self.entry(node) {
+                longest = Some((depth + 1, entry));
+            }
+        }
+        longest
+    }
+
+    /// Entries under `node` in digit order, each before those it is a prefix of
+    fn descendants(&self, node: Option<usize>) -> impl Iterator<Item = &T> + '_ {
+        let mut stack: Vec<usize> = node.into_iter().collect();
+        std::iter::from_fn(move || {
+            while let Some(node) = stack.pop() {
+                let children = self.nodes[node].children.iter().rev();
+                stack.extend(children.filter(|&&c| c != NONE).map(|&c| c as usize));
+                if let Some(entry) = self.entry(node) {
+                    return Some(entry);
+                }
+            }
+            None
+        })
+    }
+}
+
+/// The trie path of a GTIN: the 13 digits after the indicator, then the indicator, so
+/// every indicator of a GTIN-14 sits under the same prefixes
+fn path(gtin: &GTIN) -> [u8; 14] {
+    let mut key = gtin.canonical_key();
+    let mut digits = [0; 14];
+    for digit in digits.iter_mut().rev() {
+        *digit = (key % 10) as u8;
+        key /= 10;
+    }
+    digits.rotate_left(1);
+    digits
+}
+
+fn assert_digits(prefix: &[u8]) {
+    assert!(prefix.len() <= 13, "prefixes have at most 13 digits");
+    assert!(
+        prefix.iter().all(|&d| d <= 9),
+        "prefixes are decimal digits"
+    );
+}
+
+/// GTINs with a value each, queried by prefix. Equivalent forms of a GTIN, such as a
+/// UPC-A and its EAN-13, share one entry.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GtinTrie<V> {
+    trie: Trie<(GTIN, V)>,
+}
+
+impl<V> Default for GtinTrie<V> {
+    fn default() -> Self {
+        GtinTrie::new()
+    }
+}
+
+impl<V> GtinTrie<V> {
+    pub fn new() -> Self {
+        GtinTrie { trie: Trie::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.trie.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trie.entries.is_empty()
+    }
+
+    /// Add a GTIN, returning the value it replaced for an equivalent GTIN
+    pub fn insert(&mut self, gtin: GTIN, value: V) -> Option<V> {
+        let replaced = self.trie.insert(&path(&gtin), (gtin, value));
+        replaced.map(|(_, value)| value)
+    }
+
+    pub fn get(&self, gtin: &GTIN) -> Option<&V> {
+        let node = self.trie.find(&path(gtin))?;
+        self.trie.entry(node).map(|(_, value)| value)
+    }
+
+    /// Every GTIN whose 13-digit form starts with `prefix`, e.g. `[0, 0, 7, 1, 7, 2, 0]`
+    /// for the UPC company prefix 071720, ordered by that form. Panics if `prefix` is longer
+    /// than 13 digits or holds a value over 9.
+    pub fn with_prefix(&self, prefix: &[u8]) -> impl Iterator<Item = (&GTIN, &V)> + '_ {
+        assert_digits(prefix);
+        let entries = self.trie.descendants(self.trie.find(prefix));
+        entries.map(|(gtin, value)| (gtin, value))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&GTIN, &V)> + '_ {
+        self.with_prefix(&[])
+    }
+}
+
+impl<V> FromIterator<(GTIN, V)> for GtinTrie<V> {
+    fn from_iter<I: IntoIterator<Item = (GTIN, V)>>(iter: I) -> Self {
+        let mut trie = GtinTrie::new();
+        for (gtin, value) in iter {
+            trie.insert(gtin, value);
+        }
+        trie
+    }
+}
+
+/// Values keyed by digit prefixes, such as GS1 Company Prefixes, resolved by longest
+/// match against a GTIN's 13-digit form
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct PrefixTrie<V> {
+    trie: Trie<V>,
+}
+
+impl<V> Default for PrefixTrie<V> {
+    fn default() -> Self {
+        PrefixTrie::new()
+    }
+}
+
+impl<V> PrefixTrie<V> {
+    pub fn new() -> Self {
+        PrefixTrie { trie: Trie::new() }
+    }
+
+    pub fn len(&self) -> usize {
+        self.trie.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trie.entries.is_empty()
+    }
+
+    /// Add a prefix, returning the value it replaced. Panics if `prefix` is longer than 13
+    /// digits or holds a value over 9.
+    pub fn insert(&mut self, prefix: &[u8], value: V) -> Option<V> {
+        assert_digits(prefix);
+        self.trie.insert(prefix, value)
+    }
+
+    /// The value of exactly this prefix
+    pub fn get(&self, prefix: &[u8]) -> Option<&V> {
+        assert_digits(prefix);
+        self.trie.entry(self.trie.find(prefix)?)
+    }
+
+    /// The longest prefix the GTIN's 13-digit form starts with, as its length and value
+    pub fn longest_match(&self, gtin: &GTIN) -> Option<(usize, &V)> {
+        self.trie.longest(&path(gtin)[..13])
+    }
+}
+
+#[cfg(test)]
+pub mod tests;
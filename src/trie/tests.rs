@@ -0,0 +1,75 @@
+use super::{GtinTrie, PrefixTrie};
+use crate::GTIN;
+
+fn gtin(value: &str) -> GTIN {
+    GTIN::try_from(value).unwrap()
+}
+
+#[test]
+fn gtins_by_prefix() {
+    let mut trie: GtinTrie<&str> = [
+        (gtin("071720539774"), "widget"),
+        (gtin("10071720539771"), "case of widgets"),
+        (gtin("4006381333931"), "pen"),
+        (gtin("00000055"), "upc-e"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(trie.len(), 4);
+
+    // The EAN-13 form replaces the UPC-A entry's value
+    let ean13 = GTIN::Ean13([0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    assert_eq!(trie.insert(ean13, "widget!"), Some("widget"));
+    assert_eq!(trie.get(&gtin("071720539774")), Some(&"widget!"));
+    assert_eq!(trie.len(), 4);
+
+    // Both indicators sit under the company prefix
+    let company: Vec<_> = trie
+        .with_prefix(&[0, 0, 7, 1, 7, 2, 0])
+        .map(|(_, value)| *value)
+        .collect();
+    assert_eq!(company, vec!["case of widgets", "widget!"]);
+
+    assert_eq!(trie.with_prefix(&[4, 0, 0, 6]).count(), 1);
+    assert_eq!(trie.with_prefix(&[5]).count(), 0);
+    let all: Vec<_> = trie.iter().map(|(_, value)| *value).collect();
+    assert_eq!(all, vec!["upc-e", "case of widgets", "widget!", "pen"]);
+}
+
+#[test]
+fn longest_prefix_match() {
+    let mut prefixes = PrefixTrie::new();
+    prefixes.insert(&[0], "US and Canada");
+    prefixes.insert(&[0, 0, 7, 1, 7, 2, 0], "Company A");
+    prefixes.insert(&[4, 0, 0, 6, 3, 8, 1], "Company B");
+    assert_eq!(
+        prefixes.insert(&[0], "United States"),
+        Some("US and Canada")
+    );
+    assert_eq!(prefixes.len(), 3);
+    assert_eq!(prefixes.get(&[0, 0, 7]), None);
+
+    assert_eq!(
+        prefixes.longest_match(&gtin("071720539774")),
+        Some((7, &"Company A"))
+    );
+    assert_eq!(
+        prefixes.longest_match(&gtin("10071720539771")),
+        Some((7, &"Company A"))
+    );
+    assert_eq!(
+        prefixes.longest_match(&gtin("036000291452")),
+        Some((1, &"United States"))
+    );
+    assert_eq!(
+        prefixes.longest_match(&gtin("96385074")),
+        Some((1, &"United States"))
+    );
+    assert_eq!(prefixes.longest_match(&gtin("5901234123457")), None);
+}
+
+#[test]
+#[should_panic(expected = "decimal digits")]
+fn prefix_digits_checked() {
+    PrefixTrie::new().insert(&[1, 10], ());
+}
@@ -0,0 +1,62 @@
+//! GTINs in web addresses: GS1 Digital Link URIs and the product pages and APIs of
+//! retailers, which carry them in path segments and query parameters.
+
+use std::collections::HashSet;
+
+use crate::split::to_gtin;
+use crate::GTIN;
+
+/// Digital Link primary keys for a GTIN, the AI and its short name
+const DIGITAL_LINK_KEYS: [&str; 2] = ["01", "gtin"];
+
+/// Query parameters retailers and feeds use for the product's GTIN
+const QUERY_KEYS: [&str; 8] = [
+    "gtin", "gtin14", "gtin13", "gtin12", "gtin8", "ean", "upc", "barcode",
+];
+
+impl GTIN {
+    /// The GTINs in a URL, most specific first: the Digital Link key, then recognised
+    /// query parameters, then any path segment that is a valid GTIN on its own. Values
+    /// must be digits only; each GTIN is listed once.
+    pub fn from_url(url: &str) -> Vec<GTIN> {
+        let url = url.trim();
+        let url = url.split('#').next().unwrap_or(url);
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        // Drop the scheme and host
+        let path = match path.split_once("://") {
+            Some((_, rest)) => rest.find('/').map_or("", |i| &rest[i..]),
+            None => path,
+        };
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        let digital_link = segments
+            .windows(2)
+            .filter(|pair| DIGITAL_LINK_KEYS.contains(&pair[0]))
+            .map(|pair| pair[1]);
+        let parameters = query.split('&').filter_map(|pair| {
+            let (key, value) = pair.split_once('=')?;
+            QUERY_KEYS
+                .contains(&key.to_ascii_lowercase().as_str())
+                .then_some(value)
+        });
+
+        let mut seen = HashSet::new();
+        digital_link
+            .chain(parameters)
+            .chain(segments.iter().copied())
+            .filter_map(parse_value)
+            .filter(|gtin| seen.insert(gtin.canonical_key()))
+            .collect()
+    }
+}
+
+fn parse_value(value: &str) -> Option<GTIN> {
+    if !value.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let digits: Vec<u8> = value.bytes().map(|b| b - b'0').collect();
+    to_gtin(&digits)
+}
+
+#[cfg(test)]
+pub mod tests;
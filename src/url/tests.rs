@@ -0,0 +1,30 @@
+use crate::GTIN;
+
+#[test]
+fn gtins_from_urls() {
+    let gtin14 = GTIN::Gtin14([0, 0, 0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let upca = GTIN::UpcA([0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4]);
+    let ean13 = GTIN::Ean13([8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6]);
+
+    let cases = vec![
+        ("https://id.gs1.org/01/00071720539774", vec![gtin14]),
+        (
+            "https://example.com/01/00071720539774/10/ABC123?17=251231",
+            vec![gtin14],
+        ),
+        ("https://www.example.com/dp/00071720539774/", vec![gtin14]),
+        (
+            "https://shop.example/item?id=42&UPC=071720539774#reviews",
+            vec![upca],
+        ),
+        (
+            "https://shop.example/p/8595701530526?gtin=071720539774",
+            vec![upca, ean13],
+        ),
+        ("/gtin/8595701530526", vec![ean13]),
+        ("https://shop.example/p/071720539775?ean=85957016", vec![]),
+    ];
+    for (url, expected) in cases {
+        assert_eq!(GTIN::from_url(url), expected, "{}", url);
+    }
+}
@@ -0,0 +1,102 @@
+//! Check digit validation over many codes at once. Codes are zero-padded to 14 digits, so
+//! every position has a fixed weight and each check is the same straight-line arithmetic,
+//! which the compiler can unroll and vectorize.
+
+/// Weights of the 14 positions of a zero-padded code, the check digit last
+const WEIGHTS: [u16; 14] = [3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1, 3, 1];
+
+/// Per length: all bits set at the positions before the code, which must be zero
+/// padding. Index 15 stands for every length over 14.
+const PADDING: [[u8; 14]; 16] = {
+    let mut masks = [[0; 14]; 16];
+    let mut len = 0;
+    while len <= 14 {
+        let mut i = 0;
+        while i < 14 - len {
+            masks[len][i] = u8::MAX;
+            i += 1;
+        }
+        len += 1;
+    }
+    masks
+};
+
+/// Per length: whether `validate_gtin` accepts it
+const SUPPORTED: [bool; 16] = {
+    let mut supported = [false; 16];
+    let mut len = 8;
+    while len <= 14 {
+        supported[len] = true;
+        len += 1;
+    }
+    supported
+};
+
+/// One bit per code, set if the code is valid
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ValidityMask {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl ValidityMask {
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Whether the code at `index` is valid, or None past the end
+    pub fn get(&self, index: usize) -> Option<bool> {
+        (index < self.len).then(|| self.words[index / 64] >> (index % 64) & 1 == 1)
+    }
+
+    pub fn count_valid(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|index| self.words[index / 64] >> (index % 64) & 1 == 1)
+    }
+}
+
+/// Whether a zero-padded code of `len` significant digits is a valid GTIN, without branches
+#[inline]
+fn is_valid(code: &[u8; 14], len: u8) -> bool {
+    let len = usize::from(len).min(15);
+    let mut sum = 0u16;
+    let mut padding = 0u8;
+    for ((&digit, &weight), &mask) in code.iter().zip(&WEIGHTS).zip(&PADDING[len]) {
+        sum += u16::from(digit) * weight;
+        padding |= digit & mask;
+    }
+    sum.is_multiple_of(10) & (padding == 0) & SUPPORTED[len]
+}
+
+/// Validate the check digits of zero-padded 14-digit codes, e.g. a column of a catalog,
+/// with `lengths` giving how many of each code's digits are significant. A code is valid
+/// if `validate_gtin` accepts its significant digits and the rest are zero padding.
+/// Panics if the slices differ in length.
+pub fn validate_batch(codes: &[[u8; 14]], lengths: &[u8]) -> ValidityMask {
+    assert_eq!(codes.len(), lengths.len(), "one length per code");
+
+    let words = codes
+        .chunks(64)
+        .zip(lengths.chunks(64))
+        .map(|(codes, lengths)| {
+            let bits = codes.iter().zip(lengths).enumerate();
+            bits.fold(0u64, |word, (bit, (code, &len))| {
+                word | u64::from(is_valid(code, len)) << bit
+            })
+        })
+        .collect();
+    ValidityMask {
+        words,
+        len: codes.len(),
+    }
+}
@@ -1,39 +1,70 @@
 use crate::GTIN;
 
+#[cfg(feature = "std")]
+mod batch;
+pub mod upce;
+
+#[cfg(feature = "std")]
+pub use batch::{validate_batch, ValidityMask};
+
+#[cfg(feature = "std")]
 pub fn digits_to_string(digits: &[u8]) -> String {
     digits.iter().map(|&d| (d + b'0') as char).collect()
 }
 
-pub fn calculate_checksum_digit(digits: &[u8]) -> u8 {
-    let sum: u32 = digits
-        .iter()
-        .rev()
-        .enumerate()
-        .map(
-            |(index, &digit)| {
-                if index % 2 == 0 {
-                    // Digit needs to be converted to u32 before multiplication to avoid overflow
-                    digit as u32 * 3
-                } else {
-                    digit as u32
-                }
-            },
-        )
-        .sum(); // This sum is now a u32 sum, which is less likely to overflow
-
-    (10 - (sum % 10) as u8) % 10 // Convert back to u8 for final calculation
+/// Formats digit values as text without allocating, e.g. `write!(f, "{}", Digits(&digits))`.
+/// Width and alignment are honored for anything up to SSCC length.
+pub struct Digits<'a>(pub &'a [u8]);
+
+impl core::fmt::Display for Digits<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buffer = [0u8; 18];
+        if self.0.len() > buffer.len() {
+            let mut chunks = self.0.chunks(buffer.len());
+            return chunks.try_for_each(|chunk| write!(f, "{}", Digits(chunk)));
+        }
+
+        let text = &mut buffer[..self.0.len()];
+        for (byte, &digit) in text.iter_mut().zip(self.0) {
+            *byte = digit + b'0';
+        }
+        // Only ASCII is written, so the text is always valid UTF-8
+        f.pad(core::str::from_utf8(text).map_err(|_| core::fmt::Error)?)
+    }
+}
+
+/// The mod-10 check digit for the digits before it. A const fn, so it can compute
+/// constants and lookup tables.
+pub const fn calculate_checksum_digit(digits: &[u8]) -> u8 {
+    let mut sum: u32 = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        // From the right, so every length weights the digit next to the check digit by 3
+        let digit = digits[digits.len() - 1 - index] as u32;
+        sum += if index % 2 == 0 { digit * 3 } else { digit };
+        index += 1;
+    }
+
+    (10 - (sum % 10) as u8) % 10
 }
 
-pub fn validate_gtin(digits: &[u8]) -> bool {
+pub const fn validate_gtin(digits: &[u8]) -> bool {
     if digits.len() < 8 || digits.len() > 14 {
         return false;
     }
 
-    let checksum_index = digits.len() - 1;
-    let checksum_digit = digits[checksum_index];
-    checksum_digit == calculate_checksum_digit(&digits[..checksum_index])
+    validate_check_digit(digits)
 }
 
+/// Validate the trailing mod-10 check digit of any GS1 key, regardless of length
+pub const fn validate_check_digit(digits: &[u8]) -> bool {
+    match digits.split_last() {
+        Some((&checksum_digit, body)) => checksum_digit == calculate_checksum_digit(body),
+        None => false,
+    }
+}
+
+#[cfg(feature = "std")]
 #[inline]
 pub fn extract_digits(input: &str) -> Vec<u8> {
     input
@@ -43,90 +74,113 @@ pub fn extract_digits(input: &str) -> Vec<u8> {
         .collect()
 }
 
-/// Convert UPC-E to UPC-A
-pub fn expand_upce_to_upca(upce: &[u8]) -> Result<GTIN, String> {
-    if upce.len() < 6 || upce.len() > 8 {
-        return Err("Invalid UPC-E length".to_string());
+/// The digits of a string, as `extract_digits` finds them, without allocating. Only the
+/// first `DigitBuffer::CAPACITY` are kept, which is more than any GTIN has.
+pub(crate) struct DigitBuffer {
+    digits: [u8; DigitBuffer::CAPACITY],
+    count: usize,
+}
+
+impl DigitBuffer {
+    const CAPACITY: usize = 18;
+
+    pub(crate) fn extract(input: &[u8]) -> Self {
+        let mut buffer = DigitBuffer {
+            digits: [0; DigitBuffer::CAPACITY],
+            count: 0,
+        };
+        for &digit in input.iter().filter(|b| b.is_ascii_digit()) {
+            if let Some(slot) = buffer.digits.get_mut(buffer.count) {
+                *slot = digit - b'0';
+            }
+            buffer.count += 1;
+        }
+        buffer
     }
 
-    // Extract middle digits based on length
-    let middle_digits = match upce.len() {
-        6 => &upce[..],
-        7 => &upce[..6],
-        8 => &upce[1..7],
-        _ => return Err("Invalid UPC-E length".to_string()),
-    };
+    /// The kept digits; longer input is cut to the capacity
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.digits[..self.count.min(DigitBuffer::CAPACITY)]
+    }
+}
+
+/// Whether an 8-digit UPC-E's check digit is that of its UPC-A expansion, which is what
+/// scanners verify. The flat mod-10 check over the 8 digits is a different calculation.
+pub fn validate_upce(upce: &[u8]) -> bool {
+    match (upce.len(), expand_upce_to_upca(upce)) {
+        (8, Ok(upca)) => upca.digits()[11] == upce[7],
+        _ => false,
+    }
+}
 
-    // Decode based on the last digit rules
-    let (manufacturer_number, item_number) = match middle_digits[5] {
-        0 |1 |2 => (
-            vec![
-                middle_digits[0],
-                middle_digits[1],
-                middle_digits[5],
-                0,
-                0,
-            ],
-            vec![
-                0,
-                0,
-                middle_digits[2],
-                middle_digits[3],
-                middle_digits[4],
-            ],
-        ),
-        3 => (
-            vec![
-                middle_digits[0],
-                middle_digits[1],
-                middle_digits[2],
-                0,
-                0,
-            ],
-            vec![0, 0, 0, middle_digits[3], middle_digits[4]],
-        ),
-        4 => (
-            vec![
-                middle_digits[0],
-                middle_digits[1],
-                middle_digits[2],
-                middle_digits[3],
-                0,
-            ],
-            vec![0, 0, 0, 0, middle_digits[4]],
-        ),
-        _ => (
-            vec![
-                middle_digits[0],
-                middle_digits[1],
-                middle_digits[2],
-                middle_digits[3],
-                middle_digits[4],
-            ],
-            vec![0, 0, 0, 0, middle_digits[5]],
-        ),
+/// Convert UPC-E to UPC-A. Eight digits carry the number system (0 or 1), which the
+/// expansion keeps; six or seven digits leave it out, and number system 0 is assumed.
+pub fn expand_upce_to_upca(upce: &[u8]) -> Result<GTIN, &'static str> {
+    // Extract number system and middle digits based on length
+    let (number_system, middle_digits) = match upce.len() {
+        6 => (0, upce),
+        7 => (0, &upce[..6]),
+        8 => (upce[0], &upce[1..7]),
+        _ => return Err("Invalid UPC-E length"),
     };
+    if upce.iter().any(|&digit| digit > 9) {
+        return Err("Digits must be 0 to 9");
+    }
+    if number_system > 1 {
+        return Err("Only number systems 0 and 1 can be zero-suppressed");
+    }
+    let body: &[u8; 6] = middle_digits
+        .try_into()
+        .map_err(|_| "Invalid UPC-E length")?;
+    Ok(GTIN::UpcA(upca_digits(number_system, body)))
+}
 
-    // Assemble the new UPC-A number
-    let mut new_upca_digits = vec![0]; // Start with number system digit
-    new_upca_digits.extend(manufacturer_number);
-    new_upca_digits.extend(item_number);
+/// The UPC-A digits for a number system and UPC-E body, with the check digit recalculated
+pub(crate) fn upca_digits(number_system: u8, body: &[u8; 6]) -> [u8; 12] {
+    let mut digits = [0u8; 12];
+    digits[0] = number_system;
+    digits[1..11].copy_from_slice(&upce::expand(body));
+    digits[11] = calculate_checksum_digit(&digits[..11]);
+    digits
+}
 
-    // Calculate the check digit
-    let check_digit = calculate_checksum_digit(&new_upca_digits);
-    new_upca_digits.push(check_digit);
+/// The UPC-E digits for a number system and body, with the check digit of their UPC-A
+/// expansion
+#[cfg(any(feature = "arbitrary", feature = "rand"))]
+pub(crate) fn upce_digits(number_system: u8, body: &[u8; 6]) -> [u8; 8] {
+    let mut digits = [number_system, 0, 0, 0, 0, 0, 0, 0];
+    digits[1..7].copy_from_slice(body);
+    digits[7] = upca_digits(number_system, body)[11];
+    digits
+}
 
-    // Ensure we have exactly 12 digits (exclude check digit for the enum)
-    if new_upca_digits.len() != 12 {
-        return Err("Failed to construct valid UPC-A".to_string());
+/// Convert UPC-A to UPC-E, if the code is one of those that can be zero-suppressed
+pub fn compress_upca_to_upce(upca: &[u8]) -> Result<GTIN, &'static str> {
+    if upca.len() != 12 {
+        return Err("Invalid UPC-A length");
+    }
+    if upca.iter().any(|&digit| digit > 9) {
+        return Err("Digits must be 0 to 9");
+    }
+    if !validate_check_digit(upca) {
+        return Err("Invalid UPC-A check digit");
+    }
+    if upca[0] > 1 {
+        return Err("Only number systems 0 and 1 can be zero-suppressed");
     }
 
-    let mut result = [0u8; 12];
-    result.copy_from_slice(&new_upca_digits[..12]);
-    Ok(GTIN::UpcA(result))
+    let digits: &[u8; 10] = upca[1..11]
+        .try_into()
+        .map_err(|_| "Invalid UPC-A length")?;
+    let (_, body) =
+        upce::compress(digits).ok_or("UPC-A cannot be zero-suppressed")?;
+
+    let mut result = [0u8; 8];
+    result[0] = upca[0];
+    result[1..7].copy_from_slice(&body);
+    result[7] = upca[11];
+    Ok(GTIN::UpcE(result))
 }
 
-
-
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 pub mod tests;
@@ -1,13 +1,18 @@
 use crate::util::validate_gtin;
 
+use super::compress_upca_to_upce;
 use super::expand_upce_to_upca;
 use super::extract_digits;
+use super::upce::{self, ZeroSuppression};
+use super::validate_batch;
 
 #[test]
 fn expand_upce() {
     let cases = vec![
         ("04182635", "041800000265"),
-        ("0 123450 5", "0 12000 00345 5")
+        ("0 123450 5", "0 12000 00345 5"),
+        ("1 123450 2", "1 12000 00345 2"), // number system 1 is kept
+        ("123450", "0 12000 00345 5"),
     ];
 
     for (upce_str, expected_upca_str) in cases {
@@ -24,6 +29,29 @@ fn expand_upce() {
             Err(e) => panic!("Failed to expand UPC-E {}: {}", upce_str, e),
         }
     }
+
+    assert!(expand_upce_to_upca(&extract_digits("2 123450 8")).is_err());
+}
+
+#[test]
+fn compress_upca() {
+    let cases = vec![
+        ("041800000265", Some("04182635")),
+        ("0 12000 00345 5", Some("0 123450 5")),
+        ("0 12345 00006 5", Some("0 123456 5")),
+        ("0 71720 53977 4", None),
+    ];
+
+    for (upca_str, expected_upce_str) in cases {
+        let upca_digits = extract_digits(upca_str);
+        let result = compress_upca_to_upce(&upca_digits).ok();
+        assert_eq!(
+            result.as_ref().map(|gtin| gtin.digits().to_vec()),
+            expected_upce_str.map(extract_digits),
+            "Failed to match UPC-A: {}",
+            upca_str
+        );
+    }
 }
 
 #[test]
@@ -66,4 +94,85 @@ fn handle_non_digit_characters() {
       let gtin = crate::GTIN::try_from(gtin_str);
         assert!(gtin.is_ok(), "Failed to parse GTIN: {}", gtin_str);
     }
-}
\ No newline at end of file
+}
+#[test]
+fn upce_expansion_is_inverse_of_compression() {
+    let mut canonical = 0;
+    for (body, digits) in upce::table() {
+        let (rule, compressed) = upce::compress(&digits).expect("every expansion compresses");
+        assert_eq!(upce::expand(&compressed), digits, "{:?}", body);
+        assert_eq!(Some(rule), upce::rule(compressed[5]));
+        if compressed == body {
+            canonical += 1;
+        }
+    }
+    // Bodies whose expansion also fits an earlier rule are not canonical: rule 3 with a
+    // third digit of 0-2, rule 4 with a zero fourth digit, rules 5-9 with a zero fifth
+    assert_eq!(canonical, 1_000_000 - 30_000 - 10_000 - 50_000);
+
+    assert_eq!(
+        upce::compress(&[1, 2, 0, 0, 0, 0, 0, 0, 4, 5]),
+        Some((ZeroSuppression::ThirdDigit(0), [1, 2, 0, 4, 5, 0]))
+    );
+    assert_eq!(upce::compress(&[7, 1, 7, 2, 0, 5, 3, 9, 7, 7]), None);
+}
+
+#[test]
+fn validate_batch_matches_per_code() {
+    // Every length, with each possible check digit, from a simple deterministic sequence
+    let mut codes = vec![];
+    let mut lengths = vec![];
+    let mut seed = 0x2545_f491u32;
+    for len in 0..=16u8 {
+        for check in 0..10 {
+            let mut code = [0u8; 14];
+            let significant = usize::from(len).min(14);
+            for digit in &mut code[14 - significant..] {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                *digit = (seed >> 16) as u8 % 10;
+            }
+            code[13] = check;
+            codes.push(code);
+            lengths.push(len);
+        }
+    }
+    // Non-zero digits where the padding should be
+    let ean13 = [0, 8, 5, 9, 5, 7, 0, 1, 5, 3, 0, 5, 2, 6];
+    codes.push(ean13);
+    lengths.push(13);
+    codes.push(ean13);
+    lengths.push(12);
+
+    let mask = validate_batch(&codes, &lengths);
+    assert_eq!(mask.len(), codes.len());
+    for (i, (code, &len)) in codes.iter().zip(&lengths).enumerate() {
+        let expected = len <= 14 && validate_gtin(&code[14 - usize::from(len)..]);
+        assert_eq!(mask.get(i), Some(expected), "{:?} ({} digits)", code, len);
+    }
+    assert_eq!(mask.get(codes.len()), None);
+    assert_eq!(mask.count_valid(), mask.iter().filter(|&valid| valid).count());
+    assert!(mask.get(codes.len() - 2).unwrap());
+    assert!(!mask.get(codes.len() - 1).unwrap());
+}
+
+#[test]
+fn const_check_digits() {
+    use super::{calculate_checksum_digit, validate_gtin};
+
+    // Check digits of UPC-A bodies 0 00000 00000 to 0 00000 00009, as a const table
+    const TABLE: [u8; 10] = {
+        let mut table = [0; 10];
+        let mut last = 0;
+        while last < 10 {
+            let mut body = [0u8; 11];
+            body[10] = last as u8;
+            table[last] = calculate_checksum_digit(&body);
+            last += 1;
+        }
+        table
+    };
+    assert_eq!(TABLE, [0, 7, 4, 1, 8, 5, 2, 9, 6, 3]);
+
+    const { assert!(validate_gtin(&[0, 7, 1, 7, 2, 0, 5, 3, 9, 7, 7, 4])) };
+    const { assert!(!validate_gtin(&[0, 0])) };
+}
@@ -0,0 +1,61 @@
+//! UPC-E zero suppression as data: the rule each final body digit selects, and the
+//! mapping between 6-digit UPC-E bodies and the ten manufacturer and item digits of UPC-A.
+//! Several bodies can expand to the same UPC-A; `compress` returns the canonical one.
+
+/// How a UPC-E body places its digits, selected by the body's final digit
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ZeroSuppression {
+    ThirdDigit(u8),         // 0-2: manufacturer `ab?00`, item `00cde`
+    ThreeDigitManufacturer, // 3: manufacturer `abc00`, item `000de`
+    FourDigitManufacturer,  // 4: manufacturer `abcd0`, item `0000e`
+    LastItemDigit(u8),      // 5-9: manufacturer `abcde`, item `0000?`
+}
+
+/// The rule a final body digit selects, or None if it is not a digit
+pub fn rule(final_digit: u8) -> Option<ZeroSuppression> {
+    match final_digit {
+        0..=2 => Some(ZeroSuppression::ThirdDigit(final_digit)),
+        3 => Some(ZeroSuppression::ThreeDigitManufacturer),
+        4 => Some(ZeroSuppression::FourDigitManufacturer),
+        5..=9 => Some(ZeroSuppression::LastItemDigit(final_digit)),
+        _ => None,
+    }
+}
+
+/// Expand a UPC-E body into the manufacturer and item digits of UPC-A
+pub const fn expand(body: &[u8; 6]) -> [u8; 10] {
+    let [a, b, c, d, e, f] = *body;
+    match f {
+        0..=2 => [a, b, f, 0, 0, 0, 0, c, d, e],
+        3 => [a, b, c, 0, 0, 0, 0, 0, d, e],
+        4 => [a, b, c, d, 0, 0, 0, 0, 0, e],
+        _ => [a, b, c, d, e, 0, 0, 0, 0, f],
+    }
+}
+
+/// The canonical UPC-E body for UPC-A manufacturer and item digits, with the rule it
+/// uses, if the digits have enough zeros to be suppressed
+pub fn compress(digits: &[u8; 10]) -> Option<(ZeroSuppression, [u8; 6])> {
+    // Try the rules in the order that yields the canonical UPC-E body
+    let body = match *digits {
+        [m1, m2, m3 @ 0..=2, 0, 0, 0, 0, i3, i4, i5] => [m1, m2, i3, i4, i5, m3],
+        [m1, m2, m3, 0, 0, 0, 0, 0, i4, i5] => [m1, m2, m3, i4, i5, 3],
+        [m1, m2, m3, m4, 0, 0, 0, 0, 0, i5] => [m1, m2, m3, m4, i5, 4],
+        [m1, m2, m3, m4, m5, 0, 0, 0, 0, i5 @ 5..=9] => [m1, m2, m3, m4, m5, i5],
+        _ => return None,
+    };
+    Some((rule(body[5])?, body))
+}
+
+/// Every UPC-E body with its expansion, in numeric order of the body
+pub fn table() -> impl Iterator<Item = ([u8; 6], [u8; 10])> {
+    (0..1_000_000u32).map(|n| {
+        let mut body = [0; 6];
+        let mut rest = n;
+        for digit in body.iter_mut().rev() {
+            *digit = (rest % 10) as u8;
+            rest /= 10;
+        }
+        (body, expand(&body))
+    })
+}
@@ -0,0 +1,46 @@
+//! WebAssembly bindings, enabled by the `wasm` feature. Codes are passed as strings, and
+//! invalid ones throw a JS `Error` with the message the Rust error displays.
+
+use wasm_bindgen::prelude::*;
+
+use crate::info::GtinInfo;
+use crate::{GtinKind, GTIN};
+
+/// Describe an input as a plain JS object with the fields of `GtinInfo`
+#[wasm_bindgen]
+pub fn classify(input: &str) -> JsValue {
+    serde_wasm_bindgen::to_value(&GtinInfo::classify(input)).unwrap_or(JsValue::NULL)
+}
+
+/// The digits of the GTIN the input parses as, e.g. `"071720539774"` for `"0 71720 53977 4"`
+#[wasm_bindgen]
+pub fn parse(input: &str) -> Result<String, JsError> {
+    Ok(String::from(GTIN::try_from(input)?))
+}
+
+/// Whether the input parses as a GTIN, for form validation
+#[wasm_bindgen]
+pub fn validate(input: &str) -> bool {
+    GTIN::try_from(input).is_ok()
+}
+
+/// The digits of the input as `"ean13"`, `"gtin14"`, `"upca"` or `"upce"`
+#[wasm_bindgen]
+pub fn convert(input: &str, to: &str) -> Result<String, JsError> {
+    let gtin = GTIN::try_from(input)?;
+    let kind = match to {
+        "ean13" => GtinKind::Ean13,
+        "gtin14" => GtinKind::Gtin14,
+        "upca" => GtinKind::UpcA,
+        "upce" => GtinKind::UpcE,
+        _ => return Err(JsError::new(&format!("Unknown conversion target: {}", to))),
+    };
+    Ok(String::from(gtin.convert_to(kind)?))
+}
+
+/// Country of the GS1 member organisation that issued the prefix, or `undefined` for
+/// prefixes without one
+#[wasm_bindgen]
+pub fn country_code(input: &str) -> Result<Option<String>, JsError> {
+    Ok(GTIN::try_from(input)?.country_code().map(String::from))
+}